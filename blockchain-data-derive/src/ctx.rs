@@ -0,0 +1,307 @@
+use darling::{ast, util, FromDeriveInput, FromField, FromMeta};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, Expr, GenericArgument, Path, PathArguments, Type};
+
+use crate::as_expr;
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(ctx), supports(struct_named))]
+pub(crate) struct CtxInfo {
+    ident: Ident,
+    data: ast::Data<util::Ignored, CtxField>,
+    module: String,
+
+    #[darling(default)]
+    finalize: Option<Path>,
+
+    #[darling(default)]
+    mamoru_path: Option<Expr>,
+}
+
+#[derive(FromField)]
+#[darling(attributes(ctx))]
+struct CtxField {
+    ident: Option<Ident>,
+    ty: Type,
+
+    #[darling(default)]
+    skip: bool,
+
+    #[darling(default)]
+    default: Option<Path>,
+
+    #[darling(default)]
+    with: Option<Path>,
+
+    #[darling(default)]
+    by_id: Option<ById>,
+}
+
+#[derive(FromMeta)]
+struct ById {
+    import: String,
+    key: Ident,
+    value: Ident,
+}
+
+impl CtxField {
+    fn ident(&self) -> &Ident {
+        self.ident
+            .as_ref()
+            .expect("BUG: the input is always struct.")
+    }
+
+    fn is_table(&self) -> bool {
+        !self.skip && self.default.is_none()
+    }
+}
+
+/// How a table is stored in the context.
+enum TableKind {
+    /// `Vec<T>`, multiple rows.
+    Many,
+    /// `Option<T>`, a single row, e.g. a block.
+    /// Serialized as `[T]` or `[null]`, the SDKs read the first item.
+    Single,
+}
+
+struct Table<'a> {
+    field: &'a CtxField,
+    kind: TableKind,
+    batch: Path,
+}
+
+impl<'a> Table<'a> {
+    fn new(field: &'a CtxField) -> syn::Result<Self> {
+        let error = || {
+            syn::Error::new(
+                field.ty.span(),
+                "A table must be `Vec<T>` or `Option<T>`, use `#[ctx(skip)]` for other fields.",
+            )
+        };
+
+        let segment = match &field.ty {
+            Type::Path(path) => path.path.segments.last().ok_or_else(error)?,
+            _ => return Err(error()),
+        };
+
+        let kind = match segment.ident.to_string().as_str() {
+            "Vec" => TableKind::Many,
+            "Option" => TableKind::Single,
+            _ => return Err(error()),
+        };
+
+        let item_path = match &segment.arguments {
+            PathArguments::AngleBracketed(args) => match args.args.first() {
+                Some(GenericArgument::Type(Type::Path(item))) => item.path.clone(),
+                _ => return Err(error()),
+            },
+            _ => return Err(error()),
+        };
+
+        let mut batch = item_path;
+        let last = batch.segments.last_mut().ok_or_else(error)?;
+        last.ident = format_ident!("{}Batch", last.ident);
+
+        Ok(Self { field, kind, batch })
+    }
+
+    /// `get_<field>` for `Vec<T>` and `get_<field>s` for `Option<T>`,
+    /// so a single row table, e.g. `block`, has the same import name as in the other chains.
+    fn import_name(&self) -> String {
+        match self.kind {
+            TableKind::Many => format!("get_{}", self.field.ident()),
+            TableKind::Single => format!("get_{}s", self.field.ident()),
+        }
+    }
+}
+
+pub(crate) struct CtxDeriveImpl {
+    info: CtxInfo,
+    mamoru_path: Expr,
+}
+
+impl CtxDeriveImpl {
+    pub(crate) fn new(info: CtxInfo) -> Self {
+        let mamoru_path = info
+            .mamoru_path
+            .clone()
+            .unwrap_or_else(|| as_expr("mamoru_core"));
+
+        Self { info, mamoru_path }
+    }
+
+    pub(crate) fn generate(&self) -> syn::Result<TokenStream> {
+        let mamoru_path = &self.mamoru_path;
+        let struct_name = &self.info.ident;
+        let module = &self.info.module;
+
+        let tables = self
+            .fields()
+            .iter()
+            .filter(|field| field.is_table())
+            .map(Table::new)
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let empty = self.generate_empty();
+        let imports = self.generate_imports(&tables);
+        let by_id_imports = self.generate_by_id_imports(&tables);
+        let as_tables = self.generate_as_tables(&tables);
+
+        let finalize = self.info.finalize.as_ref().map(|finalize| {
+            quote! {
+                fn finalize(&mut self) {
+                    #finalize(self)
+                }
+            }
+        });
+
+        Ok(quote! {
+            impl #mamoru_path::BlockchainCtx for #struct_name {
+                #empty
+
+                fn module() -> &'static str {
+                    #module
+                }
+
+                #imports
+                #by_id_imports
+                #as_tables
+                #finalize
+            }
+        })
+    }
+
+    fn generate_empty(&self) -> TokenStream {
+        let fields: Vec<TokenStream> = self
+            .fields()
+            .iter()
+            .map(|field| {
+                let ident = field.ident();
+
+                match &field.default {
+                    Some(default) => quote! { #ident: #default() },
+                    None => quote! { #ident: ::std::default::Default::default() },
+                }
+            })
+            .collect();
+
+        quote! {
+            fn empty() -> Self {
+                Self {
+                    #(#fields,)*
+                }
+            }
+        }
+    }
+
+    fn generate_imports(&self, tables: &[Table]) -> TokenStream {
+        let mamoru_path = &self.mamoru_path;
+        let mut imports = vec![];
+
+        for table in tables {
+            let ident = table.field.ident();
+            let name = table.import_name();
+
+            let data = match (&table.field.with, &table.kind) {
+                (Some(with), _) => quote! { &#with(ctx) },
+                (None, TableKind::Many) => quote! { &ctx.#ident },
+                (None, TableKind::Single) => quote! { &[&ctx.#ident] },
+            };
+
+            imports.push(quote! {
+                (#name, #mamoru_path::CtxImportFn::NoArgs(|ctx: &Self| -> Vec<u8> {
+                    #mamoru_path::serialize_data(#data)
+                }))
+            });
+
+            if let Some(ById { import, key, value }) = &table.field.by_id {
+                let error = format!("Failed to find {} by {}", ident, key);
+
+                imports.push(quote! {
+                    (#import, #mamoru_path::CtxImportFn::ById(|ctx: &Self, id: u64| -> Result<&[u8], #mamoru_path::CtxImportError> {
+                        ctx.#ident
+                            .iter()
+                            .find(|item| item.#key == id)
+                            .and_then(|item| #mamoru_path::ByIdValue::by_id_bytes(&item.#value))
+                            .ok_or_else(|| #mamoru_path::CtxImportError::ById(format!("{} {}", #error, id)))
+                    }))
+                });
+            }
+        }
+
+        quote! {
+            fn imports() -> #mamoru_path::BlockchainSpecificImports<Self> {
+                <#mamoru_path::BlockchainSpecificImports<Self>>::from([
+                    #(#imports,)*
+                ])
+            }
+        }
+    }
+
+    fn generate_by_id_imports(&self, tables: &[Table]) -> TokenStream {
+        let mamoru_path = &self.mamoru_path;
+
+        let by_id_imports: Vec<TokenStream> = tables
+            .iter()
+            .filter_map(|table| {
+                let ById { import, key, value } = table.field.by_id.as_ref()?;
+                let batch = &table.batch;
+                let (key, value) = (key.to_string(), value.to_string());
+
+                Some(quote! {
+                    #mamoru_path::ByIdImport {
+                        import: #import,
+                        table: #mamoru_path::BlockchainTableItem::table_name(&#batch::new(::std::vec::Vec::new())),
+                        key: #key,
+                        value: #value,
+                    }
+                })
+            })
+            .collect();
+
+        if by_id_imports.is_empty() {
+            return quote! {};
+        }
+
+        quote! {
+            fn by_id_imports() -> Vec<#mamoru_path::ByIdImport> {
+                vec![
+                    #(#by_id_imports,)*
+                ]
+            }
+        }
+    }
+
+    fn generate_as_tables(&self, tables: &[Table]) -> TokenStream {
+        let mamoru_path = &self.mamoru_path;
+
+        let batches: Vec<TokenStream> = tables
+            .iter()
+            .map(|table| {
+                let (ident, batch) = (table.field.ident(), &table.batch);
+
+                match &table.field.with {
+                    Some(with) => quote! { #batch::new(#with(self).into_iter().cloned()).boxed() },
+                    None => quote! { #batch::new(self.#ident.clone()).boxed() },
+                }
+            })
+            .collect();
+
+        quote! {
+            fn as_tables(&self) -> Vec<Box<dyn #mamoru_path::BlockchainTableItem>> {
+                vec![
+                    #(#batches,)*
+                ]
+            }
+        }
+    }
+
+    fn fields(&self) -> &[CtxField] {
+        match &self.info.data {
+            ast::Data::Struct(fields) => &fields.fields,
+            ast::Data::Enum(_) => panic!("BUG: the input is always struct."),
+        }
+    }
+}
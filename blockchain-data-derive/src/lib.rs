@@ -1,13 +1,31 @@
 /// This macro automatically implements [`mamoru_core::BlockchainTableItem`] trait:
 /// - puts a virtual table name
-/// - generates Apache Arrow Schema
+/// - generates Apache Arrow Schema, doc comments of the struct and its fields
+///   are stored in the schema metadata under `mamoru_core::SCHEMA_DOC_KEY`,
+///   the struct name under `mamoru_core::SCHEMA_STRUCT_KEY` and fields skipped by serde
+///   are marked with `mamoru_core::SCHEMA_SKIP_SERIALIZING_KEY`
+/// - stores the AssemblyScript SDK hints of a field, see `mamoru_core::generate_as_sdk`:
+///   `#[schema(as_alias = "...")]` under `mamoru_core::SCHEMA_AS_ALIAS_KEY`,
+///   `#[schema(as_type = "...", as_from = "...")]` under `mamoru_core::SCHEMA_AS_TYPE_KEY`
+///   and `mamoru_core::SCHEMA_AS_FROM_KEY`
 /// - generates a code to convert a list of the struct instances to RecordBatch
+///
+/// If `#[schema(type = ...)]` is omitted, the Arrow type is inferred with `mamoru_core::ArrowColumn`:
+/// `Vec<T>` is a `List` and another `#[derive(BlockchainData)]` struct is a `Struct`.
+/// `Option<T>` is always nullable, `#[schema(nullable = true)]` is not required.
+/// A struct without `table_name` is not a table, it only can be nested in other structs.
+use ctx::{CtxDeriveImpl, CtxInfo};
 use darling::{ast, util, FromDeriveInput, FromField, FromMeta};
 use maplit::hashmap;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use std::collections::HashMap;
-use syn::{parse_macro_input, spanned::Spanned, DeriveInput, Expr};
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, DeriveInput, Expr, Lit, Meta, MetaNameValue,
+    NestedMeta, Type,
+};
+
+mod ctx;
 
 #[proc_macro_derive(BlockchainData, attributes(schema))]
 pub fn derive_blockchain_data(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -20,30 +38,70 @@ pub fn derive_blockchain_data(tokens: proc_macro::TokenStream) -> proc_macro::To
         .into()
 }
 
+/// Implements [`mamoru_core::BlockchainCtx`] trait for a struct holding the tables:
+/// - `empty()` with empty tables, `#[ctx(skip)]` fields are `Default::default()`,
+///   `#[ctx(default = "path")]` fields are initialized with `path()`
+/// - `NoArgs` imports: `get_<field>` for `Vec<T>` and `get_<field>s` for `Option<T>`,
+///   the latter returns `[T]` or `[null]`
+/// - `ById` imports for fields with `#[ctx(by_id(import = "...", key = "seq", value = "..."))]`,
+///   described by `by_id_imports()`
+/// - `as_tables()`, the table is `<T>Batch` generated by `#[derive(BlockchainData)]`
+///
+/// `#[ctx(with = "path")]` reads a table with `path(&ctx)` instead of the field.
+/// The module name is set with `#[ctx(module = "...")]`, and `#[ctx(finalize = "path")]`
+/// overrides [`mamoru_core::BlockchainCtx::finalize`].
+#[proc_macro_derive(BlockchainCtx, attributes(ctx))]
+pub fn derive_blockchain_ctx(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+    let info = match CtxInfo::from_derive_input(&input) {
+        Ok(info) => info,
+        Err(err) => return err.write_errors().into(),
+    };
+
+    CtxDeriveImpl::new(info)
+        .generate()
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 #[derive(FromDeriveInput)]
-#[darling(attributes(schema), supports(struct_named))]
+#[darling(attributes(schema), supports(struct_named), forward_attrs(doc))]
 struct StructInfo {
     ident: Ident,
+    attrs: Vec<Attribute>,
     data: ast::Data<util::Ignored, Field>,
-    table_name: String,
+
+    #[darling(default)]
+    table_name: Option<String>,
 
     #[darling(default)]
     mamoru_path: Option<Expr>,
 }
 
 #[derive(FromField, Debug, Clone)]
-#[darling(attributes(schema))]
+#[darling(attributes(schema), forward_attrs(doc, serde))]
 struct Field {
     ident: Option<Ident>,
+    ty: Type,
+    attrs: Vec<Attribute>,
 
-    #[darling(rename = "type")]
-    arrow_type: Expr,
+    #[darling(default, rename = "type")]
+    arrow_type: Option<Expr>,
 
     #[darling(default)]
     rename: Option<String>,
 
     #[darling(default)]
     nullable: bool,
+
+    #[darling(default)]
+    as_alias: Option<String>,
+
+    #[darling(default)]
+    as_type: Option<String>,
+
+    #[darling(default)]
+    as_from: Option<String>,
 }
 
 impl Field {
@@ -52,38 +110,54 @@ impl Field {
             .as_ref()
             .expect("BUG: the input is always struct.")
     }
+
+    fn name(&self) -> String {
+        match &self.rename {
+            Some(name) => name.clone(),
+            None => self.ident().to_string(),
+        }
+    }
 }
 
 struct DeriveImpl {
     info: StructInfo,
     builders: Builders,
     arrow_path: Expr,
+    mamoru_path: Expr,
 }
 
 impl DeriveImpl {
     pub(crate) fn new(info: StructInfo) -> Self {
         let arrow_path = as_expr("::datafusion::arrow");
         let builders = Builders::new(&arrow_path);
+        let mamoru_path = info
+            .mamoru_path
+            .clone()
+            .unwrap_or_else(|| as_expr("mamoru_core"));
 
         Self {
             info,
             builders,
             arrow_path,
+            mamoru_path,
         }
     }
 
     pub(crate) fn generate(&self) -> syn::Result<TokenStream> {
+        let columns = self.columns()?;
+        let arrow_column = self.generate_arrow_column(&columns);
+
+        let table_name = match &self.info.table_name {
+            Some(table_name) => table_name,
+            None => return Ok(arrow_column),
+        };
+
         let struct_name = &self.info.ident;
         let batch_struct_name = format_ident!("{}Batch", &struct_name);
+        let mamoru_path = &self.mamoru_path;
 
-        let table_name = &self.info.table_name;
-        let schema = self.generate_schema()?;
-        let to_record_batch = self.generate_to_record_batch()?;
-        let mamoru_path = self
-            .info
-            .mamoru_path
-            .clone()
-            .unwrap_or_else(|| as_expr("mamoru_core"));
+        let schema = self.generate_schema(&columns);
+        let to_record_batch = self.generate_to_record_batch(&columns);
 
         Ok(quote! {
             pub struct #batch_struct_name(pub Vec<#struct_name>);
@@ -106,42 +180,39 @@ impl DeriveImpl {
                 #schema
                 #to_record_batch
             }
+
+            #arrow_column
         })
     }
 
-    fn generate_to_record_batch(&self) -> syn::Result<TokenStream> {
+    fn generate_to_record_batch(&self, columns: &[Column]) -> TokenStream {
         let arrow = &self.arrow_path;
 
         let mut init_builders = vec![];
         let mut appends = vec![];
         let mut finish_builders = vec![];
 
-        for field in self.fields() {
-            let ident = field.ident();
+        for column in columns {
+            let ident = column.field.ident();
 
             let builder_name = format_ident!("{}_builder", ident);
-            let builder_constructor = self.builders.find(&field.arrow_type)?;
+            let builder = column.builder(quote! { len });
+            let append = column.append(quote! { &mut #builder_name }, quote! { item.#ident });
 
             init_builders.push(quote! {
-                let mut #builder_name = (#builder_constructor)(len);
+                let mut #builder_name = #builder;
             });
 
-            appends.push(if field.nullable {
-                quote! {
-                    #builder_name.append_option(item.#ident);
-                }
-            } else {
-                quote! {
-                    #builder_name.append_value(item.#ident);
-                }
+            appends.push(quote! {
+                #append;
             });
 
             finish_builders.push(quote! {
-                std::sync::Arc::new(#builder_name.finish())
+                #arrow::array::ArrayBuilder::finish(&mut #builder_name)
             })
         }
 
-        Ok(quote! {
+        quote! {
             fn to_record_batch(self: Box<Self>) -> Result<#arrow::record_batch::RecordBatch, #arrow::error::ArrowError> {
                 let len = self.0.len();
                 let schema = self.schema();
@@ -156,40 +227,193 @@ impl DeriveImpl {
 
                 Ok(batch)
             }
-        })
+        }
     }
 
-    fn generate_schema(&self) -> syn::Result<TokenStream> {
+    fn generate_schema(&self, columns: &[Column]) -> TokenStream {
         let arrow = &self.arrow_path;
+        let schema_fields = self.generate_schema_fields(columns);
 
-        let schema_fields: Vec<TokenStream> = self
-            .fields()
-            .iter()
-            .map(|field| {
-                let (arrow_type, nullable) = (&field.arrow_type, field.nullable);
-
-                let name = match &field.rename {
-                    Some(name) => name.clone(),
-                    None => field
-                        .ident
-                        .as_ref()
-                        .map(|ident| ident.to_string())
-                        .expect("BUG: the input is always struct."),
-                };
+        let mamoru_path = &self.mamoru_path;
+        let struct_name = self.info.ident.to_string();
 
-                quote! { #arrow::datatypes::Field::new(#name, #arrow_type, #nullable) }
-            })
-            .collect();
+        let mut entries = vec![(quote! { #mamoru_path::SCHEMA_STRUCT_KEY }, struct_name)];
 
-        Ok(quote! {
+        if let Some(doc) = doc_string(&self.info.attrs) {
+            entries.push((quote! { #mamoru_path::SCHEMA_DOC_KEY }, doc));
+        }
+
+        let metadata = metadata(&entries);
+
+        quote! {
              fn schema(&self) -> ::std::sync::Arc<#arrow::datatypes::Schema> {
-                let schema = #arrow::datatypes::Schema::new(vec![
+                let schema = #arrow::datatypes::Schema::new_with_metadata(vec![
                     #(#schema_fields,)*
-                ]);
+                ], #metadata);
 
                 ::std::sync::Arc::new(schema)
             }
-        })
+        }
+    }
+
+    /// Implements `ArrowColumn` for the struct, so it can be used as a field of another struct.
+    fn generate_arrow_column(&self, columns: &[Column]) -> TokenStream {
+        let arrow = &self.arrow_path;
+        let mamoru_path = &self.mamoru_path;
+        let struct_name = &self.info.ident;
+        let schema_fields = self.generate_schema_fields(columns);
+
+        let mut builders = vec![];
+        let mut appends = vec![];
+        let mut append_nulls = vec![];
+
+        for (idx, column) in columns.iter().enumerate() {
+            let ident = column.field.ident();
+            let builder_type = column.builder_type();
+            let builder = column.builder(quote! { capacity });
+            let field_builder = quote! {
+                builder
+                    .field_builder::<#builder_type>(#idx)
+                    .expect("BUG: the builder type is always known.")
+            };
+
+            builders.push(quote! {
+                Box::new(#builder) as Box<dyn #arrow::array::ArrayBuilder>
+            });
+            appends.push(column.append(field_builder.clone(), quote! { value.#ident }));
+            append_nulls.push(column.append_null(field_builder));
+        }
+
+        let name = struct_name.to_string();
+
+        quote! {
+            impl #mamoru_path::ArrowColumn for #struct_name {
+                type Builder = #arrow::array::StructBuilder;
+
+                fn struct_name() -> Option<&'static str> {
+                    Some(#name)
+                }
+
+                fn data_type() -> #arrow::datatypes::DataType {
+                    #arrow::datatypes::DataType::Struct(vec![
+                        #(#schema_fields,)*
+                    ].into())
+                }
+
+                fn builder(capacity: usize) -> Self::Builder {
+                    let fields = match <Self as #mamoru_path::ArrowColumn>::data_type() {
+                        #arrow::datatypes::DataType::Struct(fields) => fields,
+                        _ => unreachable!("BUG: the data type is always struct."),
+                    };
+
+                    #arrow::array::StructBuilder::new(fields, vec![#(#builders,)*])
+                }
+
+                fn append(builder: &mut Self::Builder, value: Self) {
+                    #(#appends;)*
+                    builder.append(true);
+                }
+
+                fn append_null(builder: &mut Self::Builder) {
+                    #(#append_nulls;)*
+                    builder.append(false);
+                }
+            }
+
+            impl #mamoru_path::ArrowListItem for #struct_name {}
+        }
+    }
+
+    fn generate_schema_fields(&self, columns: &[Column]) -> Vec<TokenStream> {
+        let arrow = &self.arrow_path;
+        let mamoru_path = &self.mamoru_path;
+
+        columns
+            .iter()
+            .map(|column| {
+                let name = column.field.name();
+                let (data_type, nullable) = (column.data_type(), column.nullable());
+
+                let arrow_field =
+                    quote! { #arrow::datatypes::Field::new(#name, #data_type, #nullable) };
+
+                let mut entries = vec![];
+
+                if let Some(doc) = doc_string(&column.field.attrs) {
+                    entries.push((quote! { #mamoru_path::SCHEMA_DOC_KEY }, doc));
+                }
+
+                if skips_serializing(&column.field.attrs) {
+                    entries.push((
+                        quote! { #mamoru_path::SCHEMA_SKIP_SERIALIZING_KEY },
+                        "true".to_string(),
+                    ));
+                }
+
+                let as_hints = [
+                    (quote! { #mamoru_path::SCHEMA_AS_ALIAS_KEY }, &column.field.as_alias),
+                    (quote! { #mamoru_path::SCHEMA_AS_TYPE_KEY }, &column.field.as_type),
+                    (quote! { #mamoru_path::SCHEMA_AS_FROM_KEY }, &column.field.as_from),
+                ];
+
+                for (key, hint) in as_hints {
+                    if let Some(hint) = hint {
+                        entries.push((key, hint.clone()));
+                    }
+                }
+
+                // Only inferred columns can be structs.
+                if column.explicit.is_none() {
+                    let arrow_column = column.arrow_column();
+                    let metadata = metadata(&entries);
+
+                    return quote! {
+                        {
+                            let mut metadata = #metadata;
+
+                            if let Some(name) = #arrow_column::struct_name() {
+                                metadata.insert(#mamoru_path::SCHEMA_STRUCT_KEY.to_string(), name.to_string());
+                            }
+
+                            #arrow_field.with_metadata(metadata)
+                        }
+                    };
+                }
+
+                if entries.is_empty() {
+                    arrow_field
+                } else {
+                    let metadata = metadata(&entries);
+
+                    quote! { #arrow_field.with_metadata(#metadata) }
+                }
+            })
+            .collect()
+    }
+
+    fn columns(&self) -> syn::Result<Vec<Column>> {
+        self.fields()
+            .iter()
+            .map(|field| {
+                if field.as_type.is_some() != field.as_from.is_some() {
+                    return Err(syn::Error::new(
+                        field.ident().span(),
+                        "`as_type` and `as_from` must be set together.",
+                    ));
+                }
+
+                let explicit = match &field.arrow_type {
+                    Some(arrow_type) => Some(self.builders.find(arrow_type)?),
+                    None => None,
+                };
+
+                Ok(Column {
+                    field,
+                    explicit,
+                    mamoru_path: &self.mamoru_path,
+                })
+            })
+            .collect()
     }
 
     fn fields(&self) -> &[Field] {
@@ -200,29 +424,146 @@ impl DeriveImpl {
     }
 }
 
+/// The code to build an Arrow column for a struct field.
+///
+/// The field either has an explicit `#[schema(type = ...)]`,
+/// or its type is inferred with `ArrowColumn` trait.
+struct Column<'a> {
+    field: &'a Field,
+    explicit: Option<(TokenStream, TokenStream)>,
+    mamoru_path: &'a Expr,
+}
+
+impl Column<'_> {
+    fn data_type(&self) -> TokenStream {
+        match &self.field.arrow_type {
+            Some(arrow_type) => quote! { #arrow_type },
+            None => {
+                let column = self.arrow_column();
+
+                quote! { #column::data_type() }
+            }
+        }
+    }
+
+    fn nullable(&self) -> TokenStream {
+        let nullable = self.is_nullable();
+
+        match &self.explicit {
+            None if !nullable => {
+                let column = self.arrow_column();
+
+                quote! { #column::nullable() }
+            }
+            _ => quote! { #nullable },
+        }
+    }
+
+    /// `#[schema(nullable = true)]` or `Option<T>`, even if `#[schema(type = ...)]` is set.
+    fn is_nullable(&self) -> bool {
+        self.field.nullable || is_option(&self.field.ty)
+    }
+
+    fn builder_type(&self) -> TokenStream {
+        match &self.explicit {
+            Some((builder_type, _)) => builder_type.clone(),
+            None => {
+                let column = self.arrow_column();
+
+                quote! { #column::Builder }
+            }
+        }
+    }
+
+    fn builder(&self, capacity: TokenStream) -> TokenStream {
+        match &self.explicit {
+            Some((_, builder_constructor)) => quote! { (#builder_constructor)(#capacity) },
+            None => {
+                let column = self.arrow_column();
+
+                quote! { #column::builder(#capacity) }
+            }
+        }
+    }
+
+    fn append(&self, builder: TokenStream, value: TokenStream) -> TokenStream {
+        match &self.explicit {
+            Some(_) if self.is_nullable() => quote! { #builder.append_option(#value) },
+            Some(_) => quote! { #builder.append_value(#value) },
+            None => {
+                let column = self.arrow_column();
+
+                quote! { #column::append(#builder, #value) }
+            }
+        }
+    }
+
+    fn append_null(&self, builder: TokenStream) -> TokenStream {
+        match &self.explicit {
+            Some(_) => quote! { #builder.append_null() },
+            None => {
+                let column = self.arrow_column();
+
+                quote! { #column::append_null(#builder) }
+            }
+        }
+    }
+
+    fn arrow_column(&self) -> TokenStream {
+        let (ty, mamoru_path) = (&self.field.ty, self.mamoru_path);
+
+        quote! { <#ty as #mamoru_path::ArrowColumn> }
+    }
+}
+
+/// Maps explicit Arrow types to builder types and constructors.
 struct Builders {
-    map: HashMap<Expr, TokenStream>,
+    map: HashMap<Expr, (TokenStream, TokenStream)>,
 }
 
 impl Builders {
     fn new(arrow: &Expr) -> Self {
         let map = hashmap! {
-            as_expr("DataType::Binary") => quote!{ |len: usize| { #arrow::array::BinaryBuilder::with_capacity(len, len * 32) } },
-            as_expr("DataType::LargeBinary") => quote!{ |len: usize| { #arrow::array::LargeBinaryBuilder::with_capacity(len, len * 32) } },
-            as_expr("DataType::Utf8") => quote!{ |len: usize| { #arrow::array::StringBuilder::with_capacity(len, len * 32) } },
-            as_expr("DataType::UInt64") => quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::UInt64Type>::with_capacity },
-            as_expr("DataType::UInt32") => quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::UInt32Type>::with_capacity },
-            as_expr("DataType::UInt8") => quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::UInt8Type>::with_capacity },
-            as_expr("DataType::Float64") => quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::Float64Type>::with_capacity },
-            as_expr("DataType::Timestamp(TimeUnit::Second, None)") => quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::TimestampSecondType>::with_capacity },
+            as_expr("DataType::Binary") => (
+                quote!{ #arrow::array::BinaryBuilder },
+                quote!{ |len: usize| { #arrow::array::BinaryBuilder::with_capacity(len, len * 32) } },
+            ),
+            as_expr("DataType::LargeBinary") => (
+                quote!{ #arrow::array::LargeBinaryBuilder },
+                quote!{ |len: usize| { #arrow::array::LargeBinaryBuilder::with_capacity(len, len * 32) } },
+            ),
+            as_expr("DataType::Utf8") => (
+                quote!{ #arrow::array::StringBuilder },
+                quote!{ |len: usize| { #arrow::array::StringBuilder::with_capacity(len, len * 32) } },
+            ),
+            as_expr("DataType::UInt64") => (
+                quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::UInt64Type> },
+                quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::UInt64Type>::with_capacity },
+            ),
+            as_expr("DataType::UInt32") => (
+                quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::UInt32Type> },
+                quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::UInt32Type>::with_capacity },
+            ),
+            as_expr("DataType::UInt8") => (
+                quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::UInt8Type> },
+                quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::UInt8Type>::with_capacity },
+            ),
+            as_expr("DataType::Float64") => (
+                quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::Float64Type> },
+                quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::Float64Type>::with_capacity },
+            ),
+            as_expr("DataType::Timestamp(TimeUnit::Second, None)") => (
+                quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::TimestampSecondType> },
+                quote!{ #arrow::array::PrimitiveBuilder::<#arrow::datatypes::TimestampSecondType>::with_capacity },
+            ),
         };
 
         Self { map }
     }
 
-    fn find(&self, typ: &Expr) -> syn::Result<TokenStream> {
-        if let Some(init) = self.map.get(typ) {
-            Ok(init.clone())
+    fn find(&self, typ: &Expr) -> syn::Result<(TokenStream, TokenStream)> {
+        if let Some(builder) = self.map.get(typ) {
+            Ok(builder.clone())
         } else {
             Err(syn::Error::new(
                 typ.span(),
@@ -232,6 +573,66 @@ impl Builders {
     }
 }
 
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+/// Joins `///` lines into a single line, `None` if there are no doc comments.
+fn doc_string(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(doc), ..
+            })) => Some(doc.value().trim().to_string()),
+            _ => None,
+        })
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join(" "))
+    }
+}
+
+/// If the field has `#[serde(skip_serializing)]` or `#[serde(skip)]`,
+/// so it's not passed to WASM with the rest of the struct.
+fn skips_serializing(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("serde"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .any(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => {
+                path.is_ident("skip_serializing") || path.is_ident("skip")
+            }
+            _ => false,
+        })
+}
+
+fn metadata(entries: &[(TokenStream, String)]) -> TokenStream {
+    let entries = entries
+        .iter()
+        .map(|(key, value)| quote! { (#key.to_string(), #value.to_string()) });
+
+    quote! {
+        ::std::collections::HashMap::from([#(#entries,)*])
+    }
+}
+
 fn as_expr(expr: &str) -> Expr {
     Expr::from_string(expr).expect("BUG: Failed to parse expression in macros.")
 }
@@ -1,4 +1,4 @@
-pub use blockchain_data_derive::BlockchainData;
+pub use blockchain_data_derive::{BlockchainCtx, BlockchainData};
 
 pub mod vendor {
     pub use paste::paste;
@@ -0,0 +1,207 @@
+use datafusion::arrow::datatypes::DataType;
+use serde::Serialize;
+
+use blockchain_data_macro::{BlockchainCtx, BlockchainData};
+use expect_test::expect;
+use mamoru_core::{generate_as_sdk, ValueData};
+
+/// A token movement.
+#[derive(BlockchainData, Clone, Serialize)]
+#[schema(table_name = "transfers")]
+pub struct Transfer {
+    pub seq: u64,
+
+    /// The fee, if paid.
+    pub fee: Option<u64>,
+
+    #[schema(as_alias = "note")]
+    pub memo: Option<String>,
+
+    /// big-endian u256
+    #[schema(
+        type = "DataType::Binary",
+        as_type = "u256",
+        as_from = "u256.fromUint8ArrayBE"
+    )]
+    pub value: Vec<u8>,
+
+    pub tags: Vec<String>,
+
+    pub parties: Vec<Party>,
+
+    pub token: Option<Token>,
+
+    #[schema(as_alias = "data")]
+    #[serde(skip_serializing)]
+    pub payload: ValueData,
+}
+
+#[derive(BlockchainData, Clone, Serialize)]
+pub struct Party {
+    pub address: String,
+
+    pub amount: u64,
+}
+
+#[derive(BlockchainData, Clone, Serialize)]
+pub struct Token {
+    pub symbol: String,
+
+    pub decimals: u8,
+}
+
+#[derive(BlockchainCtx)]
+#[ctx(module = "mamoru_test")]
+pub struct CodegenCtx {
+    #[ctx(by_id(import = "get_transfer_payload_by_id", key = "seq", value = "payload"))]
+    pub transfers: Vec<Transfer>,
+}
+
+#[test]
+fn as_sdk_supports_nested_nullable_and_hinted_columns() {
+    let generated = generate_as_sdk::<CodegenCtx>().expect("Failed to generate AssemblyScript SDK");
+
+    expect![[r#"
+        // This file is generated from the Rust types by `mamoru_core::generate_as_sdk`, do not edit it.
+        // Run the SDK tests with `UPDATE_EXPECT=1` to update it.
+        import { _mamoru_get_transfers, _mamoru_get_transfer_payload_by_id } from "./imports";
+        import { msgPackReadUint8Array, readMemory, unpackValues } from "@mamoru-ai/mamoru-sdk-as/assembly/util";
+        import { Value } from "@mamoru-ai/mamoru-sdk-as/assembly";
+        import { u256 } from "./types";
+        import { Decoder } from "@wapc/as-msgpack/assembly";
+
+        // A token movement.
+        export class Transfer {
+            public readonly seq: u64
+            // The fee, if paid.
+            public readonly fee: Nullable<u64> | null
+            public readonly memo: string | null
+            // big-endian u256
+            public readonly value: u256
+            public readonly tags: string[]
+            public readonly parties: Party[]
+            public readonly token: Token | null
+
+            private _payload: Value | null = null
+
+            constructor(decoder: Decoder) {
+                // consume array size (we can't parse data otherwise)
+                let _ = decoder.readArraySize();
+
+                let seq = decoder.readUInt64();
+                let fee: Nullable<u64> | null = null;
+                if (!decoder.isNextNil()) {
+                    fee = new Nullable<u64>(decoder.readUInt64());
+                }
+                let memo: string | null = null;
+                if (!decoder.isNextNil()) {
+                    memo = decoder.readString();
+                }
+                let value = u256.fromUint8ArrayBE(msgPackReadUint8Array(decoder));
+                let tags = decoder.readArray<string>((decoder: Decoder) => decoder.readString());
+                let parties = decoder.readArray<Party>((decoder: Decoder) => Party.decode(decoder));
+                let token: Token | null = null;
+                if (!decoder.isNextNil()) {
+                    token = Token.decode(decoder);
+                }
+
+                this.seq = seq
+                this.fee = fee
+                this.memo = memo
+                this.value = value
+                this.tags = tags
+                this.parties = parties
+                this.token = token
+            }
+
+            public get payload(): Value {
+                if (this._payload == null) {
+                    const ptr_len = unpackValues(_mamoru_get_transfer_payload_by_id(this.seq));
+                    const buffer = readMemory(ptr_len[0], ptr_len[1]).buffer;
+
+                    this._payload = Value.fromBytes(buffer);
+                }
+
+                return this._payload!;
+            }
+
+            // Deprecated, use `payload` instead.
+            public get data(): Value {
+                return this.payload
+            }
+
+            // Deprecated, use `memo` instead.
+            public get note(): string | null {
+                return this.memo
+            }
+
+            public static loadAll(): Transfer[] {
+                let ptr_len = unpackValues(_mamoru_get_transfers());
+
+                return Transfer.fromHost(ptr_len[0], ptr_len[1]);
+            }
+
+            private static fromHost(ptr: u32, len: u32): Transfer[] {
+                const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+                return decoder.readArray<Transfer>((decoder: Decoder) => new Transfer(decoder));
+            }
+        }
+
+        export class Party {
+            public readonly address: string
+            public readonly amount: u64
+
+            private constructor(
+                address: string,
+                amount: u64
+            ) {
+                this.address = address
+                this.amount = amount
+            }
+
+            public static decode(decoder: Decoder): Party {
+                // consume array size (we can't parse data otherwise)
+                let _ = decoder.readArraySize();
+
+                let address = decoder.readString();
+                let amount = decoder.readUInt64();
+
+                return new Party(address, amount);
+            }
+        }
+
+        export class Token {
+            public readonly symbol: string
+            public readonly decimals: u8
+
+            private constructor(
+                symbol: string,
+                decimals: u8
+            ) {
+                this.symbol = symbol
+                this.decimals = decimals
+            }
+
+            public static decode(decoder: Decoder): Token {
+                // consume array size (we can't parse data otherwise)
+                let _ = decoder.readArraySize();
+
+                let symbol = decoder.readString();
+                let decimals = decoder.readUInt8();
+
+                return new Token(symbol, decimals);
+            }
+        }
+
+        // A nullable value type column, e.g. `Option<u64>`.
+        export class Nullable<T> {
+            public readonly value: T
+
+            constructor(value: T) {
+                this.value = value
+            }
+        }
+    "#]]
+    .assert_eq(&generated);
+}
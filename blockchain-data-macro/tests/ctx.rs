@@ -0,0 +1,153 @@
+use datafusion::arrow::datatypes::DataType;
+use serde::Serialize;
+
+use blockchain_data_macro::{BlockchainCtx, BlockchainData};
+use mamoru_core::{serialize_data, BlockchainCtx, ByIdImport, CtxImportFn};
+
+#[derive(BlockchainData, Clone, Serialize)]
+#[schema(table_name = "blocks")]
+pub struct Block {
+    #[schema(type = "DataType::UInt64")]
+    pub number: u64,
+}
+
+#[derive(BlockchainData, Clone, Serialize)]
+#[schema(table_name = "transactions")]
+pub struct Transaction {
+    pub seq: u64,
+
+    #[schema(type = "DataType::Binary")]
+    #[serde(skip_serializing)]
+    pub input: Vec<u8>,
+}
+
+#[derive(BlockchainCtx)]
+#[ctx(module = "mamoru_test")]
+pub struct TestCtx {
+    pub block: Option<Block>,
+    #[ctx(by_id(import = "get_transaction_input_by_id", key = "seq", value = "input"))]
+    pub transactions: Vec<Transaction>,
+    #[ctx(skip)]
+    pub flag: bool,
+    #[ctx(default = "default_limit")]
+    pub limit: u32,
+}
+
+fn default_limit() -> u32 {
+    42
+}
+
+fn call(ctx: &TestCtx, name: &str) -> Vec<u8> {
+    match TestCtx::imports().get(name) {
+        Some(CtxImportFn::NoArgs(f)) => f(ctx),
+        _ => panic!("{} is not a `NoArgs` import", name),
+    }
+}
+
+fn call_by_id(ctx: &TestCtx, name: &str, id: u64) -> Option<Vec<u8>> {
+    match TestCtx::imports().get(name) {
+        Some(CtxImportFn::ById(f)) => f(ctx, id).ok().map(<[u8]>::to_vec),
+        _ => panic!("{} is not a `ById` import", name),
+    }
+}
+
+#[test]
+fn empty_uses_defaults() {
+    let ctx = TestCtx::empty();
+
+    assert!(ctx.block.is_none());
+    assert!(ctx.transactions.is_empty());
+    assert!(!ctx.flag);
+    assert_eq!(ctx.limit, 42);
+    assert_eq!(TestCtx::module(), "mamoru_test");
+}
+
+#[test]
+fn imports_are_named_after_fields() {
+    let mut names: Vec<_> = TestCtx::imports().into_keys().collect();
+    names.sort();
+
+    assert_eq!(
+        names,
+        vec![
+            "get_blocks",
+            "get_transaction_input_by_id",
+            "get_transactions"
+        ]
+    );
+}
+
+#[test]
+fn by_id_imports_are_described() {
+    assert_eq!(
+        TestCtx::by_id_imports(),
+        vec![ByIdImport {
+            import: "get_transaction_input_by_id",
+            table: "transactions",
+            key: "seq",
+            value: "input",
+        }]
+    );
+}
+
+#[test]
+fn missing_single_row_is_serialized_as_null() {
+    let ctx = TestCtx::empty();
+
+    assert_eq!(call(&ctx, "get_blocks"), serialize_data(&[None::<&Block>]));
+}
+
+#[test]
+fn single_row_is_serialized_as_array() {
+    let mut ctx = TestCtx::empty();
+    let block = Block { number: 7 };
+    ctx.block = Some(block.clone());
+
+    assert_eq!(call(&ctx, "get_blocks"), serialize_data(&[&block]));
+}
+
+#[test]
+fn many_rows_are_serialized_as_array() {
+    let mut ctx = TestCtx::empty();
+    ctx.transactions = vec![
+        Transaction {
+            seq: 0,
+            input: vec![1],
+        },
+        Transaction {
+            seq: 1,
+            input: vec![2, 3],
+        },
+    ];
+
+    assert_eq!(
+        call(&ctx, "get_transactions"),
+        serialize_data(&ctx.transactions)
+    );
+    assert_eq!(
+        call_by_id(&ctx, "get_transaction_input_by_id", 1),
+        Some(vec![2, 3])
+    );
+    assert_eq!(call_by_id(&ctx, "get_transaction_input_by_id", 2), None);
+}
+
+#[test]
+fn tables_are_listed() {
+    let mut ctx = TestCtx::empty();
+    ctx.block = Some(Block { number: 7 });
+
+    let tables: Vec<_> = ctx
+        .as_tables()
+        .into_iter()
+        .map(|table| table.table_name())
+        .collect();
+
+    assert_eq!(tables, vec!["blocks", "transactions"]);
+
+    let names: Vec<_> = TestCtx::schema()
+        .into_iter()
+        .map(|table| table.name)
+        .collect();
+
+    assert_eq!(names, vec!["blocks", "transactions"]);
+}
@@ -14,7 +14,7 @@ pub struct Event {
     #[schema(type = "DataType::UInt64")]
     pub sequence_number: u64,
 
-    #[schema(type = "DataType::Utf8", rename = "type")]
+    #[schema(type = "DataType::Utf8", rename = "type", as_alias = "typ")]
     pub typ: String,
 
     #[schema(type = "DataType::LargeBinary")]
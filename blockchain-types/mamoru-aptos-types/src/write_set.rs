@@ -0,0 +1,98 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+use mamoru_core::{Value, ValueData};
+
+const COIN_STORE_PREFIX: &str = "0x1::coin::CoinStore<";
+
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "write_set_changes")]
+pub struct WriteSetChange {
+    #[schema(type = "DataType::UInt64")]
+    pub seq: u64,
+
+    #[schema(type = "DataType::UInt64")]
+    pub tx_seq: u64,
+
+    #[schema(type = "DataType::Utf8")]
+    pub address: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub state_key_hash: String,
+
+    /// one of `write_resource`, `delete_resource`, `write_module`, `delete_module`,
+    /// `write_table_item`, `delete_table_item`
+    #[schema(type = "DataType::Utf8")]
+    pub change_type: String,
+
+    /// null for module and table item changes
+    #[schema(type = "DataType::Utf8", nullable = true)]
+    pub resource_type: Option<String>,
+
+    /// null for deletions
+    #[schema(type = "DataType::LargeBinary", nullable = true)]
+    #[serde(skip_serializing)]
+    pub data: Option<ValueData>,
+}
+
+/// A `0x1::coin::CoinStore<T>` balance written by a transaction.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "coin_balance_changes")]
+pub struct CoinBalanceChange {
+    /// `seq` of the write set change it is decoded from
+    #[schema(type = "DataType::UInt64")]
+    pub write_set_change_seq: u64,
+
+    #[schema(type = "DataType::UInt64")]
+    pub tx_seq: u64,
+
+    #[schema(type = "DataType::Utf8")]
+    pub address: String,
+
+    /// `T` of the `CoinStore<T>`, e.g. `0x1::aptos_coin::AptosCoin`
+    #[schema(type = "DataType::Utf8")]
+    pub coin_type: String,
+
+    /// balance after the write
+    #[schema(type = "DataType::UInt64")]
+    pub balance: u64,
+}
+
+/// Decodes `CoinStore` resource writes into [`CoinBalanceChange`] rows.
+/// Changes that don't match the `CoinStore { coin: Coin { value } }` layout are skipped.
+pub(crate) fn derive_coin_balance_changes(changes: &[WriteSetChange]) -> Vec<CoinBalanceChange> {
+    changes.iter().filter_map(decode_coin_balance).collect()
+}
+
+fn decode_coin_balance(change: &WriteSetChange) -> Option<CoinBalanceChange> {
+    if change.change_type != "write_resource" {
+        return None;
+    }
+
+    let coin_type = change
+        .resource_type
+        .as_deref()?
+        .strip_prefix(COIN_STORE_PREFIX)?
+        .strip_suffix('>')?;
+
+    let value = change.data.as_ref()?.to_value().ok()?;
+    let balance = coin_value(&value)?;
+
+    Some(CoinBalanceChange {
+        write_set_change_seq: change.seq,
+        tx_seq: change.tx_seq,
+        address: change.address.clone(),
+        coin_type: coin_type.to_string(),
+        balance,
+    })
+}
+
+fn coin_value(coin_store: &Value) -> Option<u64> {
+    coin_store
+        .as_struct()?
+        .field("coin")?
+        .as_struct()?
+        .field("value")?
+        .as_u64()
+}
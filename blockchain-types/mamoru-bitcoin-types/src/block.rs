@@ -0,0 +1,39 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "blocks")]
+pub struct Block {
+    #[schema(type = "DataType::UInt64")]
+    pub height: u64,
+
+    #[schema(type = "DataType::Utf8")]
+    pub hash: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub prev_hash: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub merkle_root: String,
+
+    #[schema(type = "DataType::UInt32")]
+    pub version: u32,
+
+    /// unix timestamp in seconds
+    #[schema(type = "DataType::UInt64")]
+    pub timestamp: u64,
+
+    /// compact difficulty target
+    #[schema(type = "DataType::UInt32")]
+    pub bits: u32,
+
+    #[schema(type = "DataType::UInt32")]
+    pub nonce: u32,
+
+    #[schema(type = "DataType::UInt64")]
+    pub size: u64,
+
+    #[schema(type = "DataType::UInt64")]
+    pub weight: u64,
+}
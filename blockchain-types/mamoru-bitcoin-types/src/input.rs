@@ -0,0 +1,30 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+/// A transaction input spending an output of a previous transaction.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "inputs")]
+pub struct Input {
+    /// index of the spending transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub tx_index: u32,
+
+    /// index of the input in the transaction
+    #[schema(type = "DataType::UInt32")]
+    pub index: u32,
+
+    /// txid of the spent output, all zeros for the coinbase
+    #[schema(type = "DataType::Utf8")]
+    pub prev_txid: String,
+
+    /// index of the spent output in its transaction
+    #[schema(type = "DataType::UInt32")]
+    pub prev_vout: u32,
+
+    #[schema(type = "DataType::Binary")]
+    pub script_sig: Vec<u8>,
+
+    #[schema(type = "DataType::UInt32")]
+    pub sequence: u32,
+}
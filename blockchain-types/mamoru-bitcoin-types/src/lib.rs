@@ -0,0 +1,45 @@
+pub use block::*;
+use blockchain_data_macro::BlockchainCtx;
+pub use input::*;
+use mamoru_core::{classify_btc_script, derive_btc_address};
+pub use output::*;
+pub use transaction::*;
+
+mod block;
+mod input;
+mod output;
+mod transaction;
+
+#[derive(BlockchainCtx)]
+#[ctx(module = "mamoru_bitcoin", finalize = "Self::finalize_tables")]
+pub struct BitcoinCtx {
+    pub block: Option<Block>,
+    pub transactions: Vec<Transaction>,
+    pub inputs: Vec<Input>,
+    pub outputs: Vec<Output>,
+    #[ctx(default = "default_network")]
+    network: String,
+}
+
+impl BitcoinCtx {
+    pub fn set_block(&mut self, block: Block) {
+        self.block = Some(block);
+    }
+
+    /// The network used to derive output addresses:
+    /// `bitcoin` (default), `testnet`, `signet` or `regtest`.
+    pub fn set_network(&mut self, network: impl Into<String>) {
+        self.network = network.into();
+    }
+
+    fn finalize_tables(&mut self) {
+        for output in &mut self.outputs {
+            output.script_type = classify_btc_script(&output.script_pubkey).to_string();
+            output.address = derive_btc_address(&output.script_pubkey, &self.network);
+        }
+    }
+}
+
+fn default_network() -> String {
+    "bitcoin".to_string()
+}
@@ -0,0 +1,32 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "outputs")]
+pub struct Output {
+    /// index of the transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub tx_index: u32,
+
+    /// index of the output in the transaction (vout)
+    #[schema(type = "DataType::UInt32")]
+    pub index: u32,
+
+    /// in satoshis
+    #[schema(type = "DataType::UInt64")]
+    pub value: u64,
+
+    #[schema(type = "DataType::Binary")]
+    pub script_pubkey: Vec<u8>,
+
+    /// one of `p2pk`, `p2pkh`, `p2sh`, `p2wpkh`, `p2wsh`, `p2tr`, `multisig`, `op_return`, `nonstandard`,
+    /// filled from `script_pubkey` on build
+    #[schema(type = "DataType::Utf8")]
+    pub script_type: String,
+
+    /// null if the script has no address form,
+    /// filled from `script_pubkey` on build
+    #[schema(type = "DataType::Utf8", nullable = true)]
+    pub address: Option<String>,
+}
@@ -0,0 +1,37 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "transactions")]
+pub struct Transaction {
+    /// index of the transaction in the block, 0 is the coinbase
+    #[schema(type = "DataType::UInt32")]
+    pub index: u32,
+
+    #[schema(type = "DataType::Utf8")]
+    pub txid: String,
+
+    /// witness transaction id
+    #[schema(type = "DataType::Utf8")]
+    pub wtxid: String,
+
+    #[schema(type = "DataType::UInt32")]
+    pub version: u32,
+
+    #[schema(type = "DataType::UInt32")]
+    pub lock_time: u32,
+
+    #[schema(type = "DataType::UInt64")]
+    pub size: u64,
+
+    #[schema(type = "DataType::UInt64")]
+    pub vsize: u64,
+
+    #[schema(type = "DataType::UInt64")]
+    pub weight: u64,
+
+    /// in satoshis, 0 for the coinbase
+    #[schema(type = "DataType::UInt64")]
+    pub fee: u64,
+}
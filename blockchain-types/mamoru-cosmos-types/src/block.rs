@@ -0,0 +1,26 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "blocks")]
+pub struct Block {
+    #[schema(type = "DataType::UInt64")]
+    pub height: u64,
+
+    #[schema(type = "DataType::Utf8")]
+    pub hash: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub chain_id: String,
+
+    /// unix timestamp in seconds
+    #[schema(type = "DataType::UInt64")]
+    pub timestamp: u64,
+
+    #[schema(type = "DataType::Utf8")]
+    pub proposer_address: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub app_hash: String,
+}
@@ -0,0 +1,33 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+/// An ABCI event emitted by a transaction or by the block itself.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "events")]
+pub struct Event {
+    /// index of the event in the block
+    #[schema(type = "DataType::UInt32")]
+    pub seq: u32,
+
+    /// hash of the transaction, null for `BeginBlock`/`EndBlock` events
+    #[schema(type = "DataType::Utf8", nullable = true)]
+    pub tx_hash: Option<String>,
+
+    /// e.g. "transfer"
+    #[schema(type = "DataType::Utf8", rename = "type", as_alias = "typ")]
+    pub typ: String,
+}
+
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "event_attributes")]
+pub struct EventAttribute {
+    #[schema(type = "DataType::UInt32")]
+    pub event_seq: u32,
+
+    #[schema(type = "DataType::Utf8")]
+    pub key: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub value: String,
+}
@@ -0,0 +1,26 @@
+pub use block::*;
+use blockchain_data_macro::BlockchainCtx;
+pub use event::*;
+pub use message::*;
+pub use transaction::*;
+
+mod block;
+mod event;
+mod message;
+mod transaction;
+
+#[derive(BlockchainCtx)]
+#[ctx(module = "mamoru_cosmos")]
+pub struct CosmosCtx {
+    pub block: Option<Block>,
+    pub transactions: Vec<Transaction>,
+    pub messages: Vec<Message>,
+    pub events: Vec<Event>,
+    pub event_attributes: Vec<EventAttribute>,
+}
+
+impl CosmosCtx {
+    pub fn set_block(&mut self, block: Block) {
+        self.block = Some(block);
+    }
+}
@@ -0,0 +1,24 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+/// A message included into a transaction body.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "messages")]
+pub struct Message {
+    /// hash of the transaction
+    #[schema(type = "DataType::Utf8")]
+    pub tx_hash: String,
+
+    /// index of the message in the transaction
+    #[schema(type = "DataType::UInt32")]
+    pub index: u32,
+
+    /// protobuf type URL, e.g. "/cosmos.bank.v1beta1.MsgSend"
+    #[schema(type = "DataType::Utf8")]
+    pub type_url: String,
+
+    /// the message decoded to JSON
+    #[schema(type = "DataType::Utf8")]
+    pub json: String,
+}
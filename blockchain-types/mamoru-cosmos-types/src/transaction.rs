@@ -0,0 +1,38 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "transactions")]
+pub struct Transaction {
+    /// index of the transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub index: u32,
+
+    #[schema(type = "DataType::Utf8")]
+    pub hash: String,
+
+    #[schema(type = "DataType::UInt64")]
+    pub block_height: u64,
+
+    #[schema(type = "DataType::UInt64")]
+    pub gas_wanted: u64,
+
+    #[schema(type = "DataType::UInt64")]
+    pub gas_used: u64,
+
+    /// ABCI result code, 0 means success
+    #[schema(type = "DataType::UInt32")]
+    pub code: u32,
+
+    /// namespace of the `code`, empty on success
+    #[schema(type = "DataType::Utf8")]
+    pub codespace: String,
+
+    /// fee coins, e.g. "5000uatom"
+    #[schema(type = "DataType::Utf8")]
+    pub fee: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub memo: String,
+}
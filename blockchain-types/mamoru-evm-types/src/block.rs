@@ -17,9 +17,9 @@ pub struct Block {
     #[schema(type = "DataType::Utf8")]
     pub state_root: String,
 
-    // A nonce is a 64-bit hash which proves (combined with the
-    // mix-hash) that a sufficient amount of computation has been carried
-    // out on a block.
+    /// A nonce is a 64-bit hash which proves (combined with the
+    /// mix-hash) that a sufficient amount of computation has been carried
+    /// out on a block.
     #[schema(type = "DataType::UInt64")]
     pub nonce: u64,
 
@@ -17,7 +17,7 @@ pub struct CallTrace {
     #[schema(type = "DataType::UInt32")]
     pub depth: u32,
 
-    #[schema(type = "DataType::Utf8", rename = "type")]
+    #[schema(type = "DataType::Utf8", rename = "type", as_alias = "typ")]
     pub typ: String,
 
     #[schema(type = "DataType::Utf8")]
@@ -35,6 +35,23 @@ pub struct CallTrace {
     #[schema(type = "DataType::UInt64")]
     pub gas_used: u64,
 
-    #[schema(type = "DataType::LargeBinary")]
+    #[schema(
+        type = "DataType::LargeBinary",
+        as_type = "TxInput",
+        as_from = "new TxInput"
+    )]
     pub input: Vec<u8>,
+
+    /// data returned by the call, or the revert data if the call failed
+    #[schema(type = "DataType::LargeBinary")]
+    pub output: Vec<u8>,
+
+    /// error reported by the node, e.g. "execution reverted", null if the call succeeded
+    #[schema(type = "DataType::Utf8", nullable = true)]
+    pub error: Option<String>,
+
+    /// decoded `Error(string)` or `Panic(uint256)` from `output`,
+    /// filled on build if the call failed
+    #[schema(type = "DataType::Utf8", nullable = true)]
+    pub revert_reason: Option<String>,
 }
@@ -1,72 +1,57 @@
-use maplit::hashmap;
-
 pub use block::*;
+use blockchain_data_macro::BlockchainCtx;
 pub use call_trace::*;
 pub use event::*;
-use mamoru_core::{
-    serialize_data, BlockchainCtx, BlockchainSpecificImports, BlockchainTableItem, CtxImportFn,
-};
+use mamoru_core::decode_revert_reason;
+pub use state_change::*;
+pub use token_transfer::*;
 pub use transaction::*;
 
 mod block;
 mod call_trace;
 mod event;
+mod state_change;
+mod token_transfer;
 mod transaction;
 
+#[derive(BlockchainCtx)]
+#[ctx(module = "mamoru_evm", finalize = "Self::finalize_tables")]
 pub struct EvmCtx {
     pub block: Option<Block>,
     pub transactions: Vec<Transaction>,
     pub call_traces: Vec<CallTrace>,
     pub events: Vec<Event>,
+    pub token_transfers: Vec<TokenTransfer>,
+    pub approvals: Vec<Approval>,
+    pub storage_changes: Vec<StorageChange>,
+    pub balance_changes: Vec<BalanceChange>,
+    #[ctx(skip)]
+    derive_token_tables: bool,
 }
 
 impl EvmCtx {
     pub fn set_block(&mut self, block: Block) {
         self.block = Some(block);
     }
-}
-
-impl BlockchainCtx for EvmCtx {
-    fn empty() -> Self {
-        Self {
-            block: None,
-            transactions: vec![],
-            call_traces: vec![],
-            events: vec![],
-        }
-    }
 
-    fn module() -> &'static str {
-        "mamoru_evm"
+    /// Enables or disables filling `token_transfers` and `approvals`
+    /// from `events` on build. Disabled by default.
+    pub fn set_derive_token_tables(&mut self, enabled: bool) {
+        self.derive_token_tables = enabled;
     }
 
-    fn imports() -> BlockchainSpecificImports<Self> {
-        hashmap! {
-            "get_blocks" => CtxImportFn::NoArgs(|ctx: &Self| -> Vec<u8> {
-                serialize_data(&[&ctx.block])
-            }),
-            "get_transactions" => CtxImportFn::NoArgs(|ctx: &Self| -> Vec<u8> {
-                serialize_data(&ctx.transactions)
-            }),
-            "get_call_traces" => CtxImportFn::NoArgs(|ctx: &Self| -> Vec<u8> {
-                serialize_data(&ctx.call_traces)
-            }),
-            "get_events" => CtxImportFn::NoArgs(|ctx: &Self| -> Vec<u8> {
-                serialize_data(&ctx.events)
-            }),
+    fn finalize_tables(&mut self) {
+        for call_trace in &mut self.call_traces {
+            if call_trace.error.is_some() && call_trace.revert_reason.is_none() {
+                call_trace.revert_reason = decode_revert_reason(&call_trace.output);
+            }
         }
-    }
 
-    fn as_tables(&self) -> Vec<Box<dyn BlockchainTableItem>> {
-        vec![
-            BlockBatch::new(match &self.block {
-                Some(block) => vec![block.clone()],
-                None => vec![],
-            })
-            .boxed(),
-            TransactionBatch::new(self.transactions.clone()).boxed(),
-            CallTraceBatch::new(self.call_traces.clone()).boxed(),
-            EventBatch::new(self.events.clone()).boxed(),
-        ]
+        if self.derive_token_tables {
+            let (transfers, approvals) = derive_token_tables(&self.events);
+
+            self.token_transfers.extend(transfers);
+            self.approvals.extend(approvals);
+        }
     }
 }
@@ -0,0 +1,56 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+/// A contract storage slot write made by a transaction.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "storage_changes")]
+pub struct StorageChange {
+    /// index of the transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub tx_index: u32,
+
+    /// address of the contract which storage was changed
+    #[schema(type = "DataType::Utf8")]
+    pub address: String,
+
+    /// 32-byte storage key
+    #[schema(type = "DataType::Binary")]
+    pub slot: Vec<u8>,
+
+    /// 32-byte value before the transaction
+    #[schema(type = "DataType::Binary")]
+    pub previous_value: Vec<u8>,
+
+    /// 32-byte value after the transaction
+    #[schema(type = "DataType::Binary")]
+    pub new_value: Vec<u8>,
+}
+
+/// A native balance change of an account made by a transaction.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "balance_changes")]
+pub struct BalanceChange {
+    /// index of the transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub tx_index: u32,
+
+    #[schema(type = "DataType::Utf8")]
+    pub address: String,
+
+    /// big-endian u256, in wei
+    #[schema(
+        type = "DataType::Binary",
+        as_type = "u256",
+        as_from = "u256.fromUint8ArrayBE"
+    )]
+    pub previous_balance: Vec<u8>,
+
+    /// big-endian u256, in wei
+    #[schema(
+        type = "DataType::Binary",
+        as_type = "u256",
+        as_from = "u256.fromUint8ArrayBE"
+    )]
+    pub new_balance: Vec<u8>,
+}
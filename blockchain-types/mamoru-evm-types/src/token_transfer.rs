@@ -0,0 +1,489 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+use crate::Event;
+
+pub const TOKEN_STANDARD_ERC20: &str = "erc20";
+pub const TOKEN_STANDARD_ERC721: &str = "erc721";
+pub const TOKEN_STANDARD_ERC1155: &str = "erc1155";
+
+// keccak256("Transfer(address,address,uint256)")
+const TRANSFER_SIGNATURE: &str = "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+// keccak256("Approval(address,address,uint256)")
+const APPROVAL_SIGNATURE: &str = "8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925";
+
+// keccak256("TransferSingle(address,address,address,uint256,uint256)")
+const TRANSFER_SINGLE_SIGNATURE: &str =
+    "c3d58168c5ae7397731d063d5bbf3d657854427343f4c083240f7aacaa2d0f62";
+
+// keccak256("TransferBatch(address,address,address,uint256[],uint256[])")
+const TRANSFER_BATCH_SIGNATURE: &str =
+    "4a39dc06d4c0dbc64b70af90fd698a233a518aa5d07e595d983b8c0526c8f7fb";
+
+const WORD_SIZE: usize = 32;
+
+/// A token movement decoded from a standard ERC-20, ERC-721 or ERC-1155 event.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "token_transfers")]
+pub struct TokenTransfer {
+    /// index of the source event in the block
+    #[schema(type = "DataType::UInt32")]
+    pub log_index: u32,
+
+    /// index of the transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub tx_index: u32,
+
+    /// hash of the transaction
+    #[schema(type = "DataType::Utf8")]
+    pub tx_hash: String,
+
+    /// address of the token contract
+    #[schema(type = "DataType::Utf8")]
+    pub token: String,
+
+    /// one of "erc20", "erc721", "erc1155"
+    #[schema(type = "DataType::Utf8")]
+    pub standard: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub from: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub to: String,
+
+    /// big-endian u256, null for ERC-20
+    #[schema(
+        type = "DataType::Binary",
+        nullable = true,
+        as_type = "u256",
+        as_from = "u256.fromUint8ArrayBE"
+    )]
+    pub token_id: Option<Vec<u8>>,
+
+    /// big-endian u256, always 1 for ERC-721
+    #[schema(
+        type = "DataType::Binary",
+        as_type = "u256",
+        as_from = "u256.fromUint8ArrayBE"
+    )]
+    pub amount: Vec<u8>,
+}
+
+/// An allowance decoded from a standard ERC-20 or ERC-721 `Approval` event.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "approvals")]
+pub struct Approval {
+    /// index of the source event in the block
+    #[schema(type = "DataType::UInt32")]
+    pub log_index: u32,
+
+    /// index of the transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub tx_index: u32,
+
+    /// hash of the transaction
+    #[schema(type = "DataType::Utf8")]
+    pub tx_hash: String,
+
+    /// address of the token contract
+    #[schema(type = "DataType::Utf8")]
+    pub token: String,
+
+    /// one of "erc20", "erc721"
+    #[schema(type = "DataType::Utf8")]
+    pub standard: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub owner: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub spender: String,
+
+    /// big-endian u256, null for ERC-20
+    #[schema(
+        type = "DataType::Binary",
+        nullable = true,
+        as_type = "u256",
+        as_from = "u256.fromUint8ArrayBE"
+    )]
+    pub token_id: Option<Vec<u8>>,
+
+    /// big-endian u256, always 1 for ERC-721
+    #[schema(
+        type = "DataType::Binary",
+        as_type = "u256",
+        as_from = "u256.fromUint8ArrayBE"
+    )]
+    pub amount: Vec<u8>,
+}
+
+/// Decodes well-known token events into [`TokenTransfer`] and [`Approval`] rows.
+/// Events that don't match the standard layout are skipped.
+pub(crate) fn derive_token_tables(events: &[Event]) -> (Vec<TokenTransfer>, Vec<Approval>) {
+    let mut transfers = vec![];
+    let mut approvals = vec![];
+
+    for event in events {
+        match hex::encode(&event.topic0).as_str() {
+            TRANSFER_SIGNATURE => transfers.extend(decode_transfer(event)),
+            TRANSFER_SINGLE_SIGNATURE => transfers.extend(decode_transfer_single(event)),
+            TRANSFER_BATCH_SIGNATURE => transfers.extend(decode_transfer_batch(event)),
+            APPROVAL_SIGNATURE => approvals.extend(decode_approval(event)),
+            _ => {}
+        }
+    }
+
+    (transfers, approvals)
+}
+
+// ERC-20: Transfer(address indexed from, address indexed to, uint256 value)
+// ERC-721: Transfer(address indexed from, address indexed to, uint256 indexed tokenId)
+fn decode_transfer(event: &Event) -> Option<TokenTransfer> {
+    let from = topic_address(&event.topic1)?;
+    let to = topic_address(&event.topic2)?;
+    let (standard, token_id, amount) = erc20_or_erc721_value(event)?;
+
+    Some(TokenTransfer {
+        log_index: event.index,
+        tx_index: event.tx_index,
+        tx_hash: event.tx_hash.clone(),
+        token: event.address.clone(),
+        standard: standard.to_string(),
+        from,
+        to,
+        token_id,
+        amount,
+    })
+}
+
+// TransferSingle(address indexed operator, address indexed from, address indexed to, uint256 id, uint256 value)
+fn decode_transfer_single(event: &Event) -> Option<TokenTransfer> {
+    let from = topic_address(&event.topic2)?;
+    let to = topic_address(&event.topic3)?;
+    let token_id = data_word(&event.data, 0)?;
+    let amount = data_word(&event.data, 1)?;
+
+    Some(TokenTransfer {
+        log_index: event.index,
+        tx_index: event.tx_index,
+        tx_hash: event.tx_hash.clone(),
+        token: event.address.clone(),
+        standard: TOKEN_STANDARD_ERC1155.to_string(),
+        from,
+        to,
+        token_id: Some(token_id.to_vec()),
+        amount: amount.to_vec(),
+    })
+}
+
+// TransferBatch(address indexed operator, address indexed from, address indexed to, uint256[] ids, uint256[] values)
+fn decode_transfer_batch(event: &Event) -> Vec<TokenTransfer> {
+    let from = topic_address(&event.topic2);
+    let to = topic_address(&event.topic3);
+    let ids = data_word(&event.data, 0).and_then(|offset| data_u256_array(&event.data, offset));
+    let values = data_word(&event.data, 1).and_then(|offset| data_u256_array(&event.data, offset));
+
+    let (from, to, ids, values) = match (from, to, ids, values) {
+        (Some(from), Some(to), Some(ids), Some(values)) if ids.len() == values.len() => {
+            (from, to, ids, values)
+        }
+        _ => return vec![],
+    };
+
+    ids.into_iter()
+        .zip(values)
+        .map(|(token_id, amount)| TokenTransfer {
+            log_index: event.index,
+            tx_index: event.tx_index,
+            tx_hash: event.tx_hash.clone(),
+            token: event.address.clone(),
+            standard: TOKEN_STANDARD_ERC1155.to_string(),
+            from: from.clone(),
+            to: to.clone(),
+            token_id: Some(token_id.to_vec()),
+            amount: amount.to_vec(),
+        })
+        .collect()
+}
+
+// ERC-20: Approval(address indexed owner, address indexed spender, uint256 value)
+// ERC-721: Approval(address indexed owner, address indexed approved, uint256 indexed tokenId)
+fn decode_approval(event: &Event) -> Option<Approval> {
+    let owner = topic_address(&event.topic1)?;
+    let spender = topic_address(&event.topic2)?;
+    let (standard, token_id, amount) = erc20_or_erc721_value(event)?;
+
+    Some(Approval {
+        log_index: event.index,
+        tx_index: event.tx_index,
+        tx_hash: event.tx_hash.clone(),
+        token: event.address.clone(),
+        standard: standard.to_string(),
+        owner,
+        spender,
+        token_id,
+        amount,
+    })
+}
+
+// ERC-20 and ERC-721 share event signatures, the only difference is
+// whether the third parameter is indexed or not.
+fn erc20_or_erc721_value(event: &Event) -> Option<(&'static str, Option<Vec<u8>>, Vec<u8>)> {
+    if event.topic3.is_empty() {
+        let amount = data_word(&event.data, 0)?;
+
+        Some((TOKEN_STANDARD_ERC20, None, amount.to_vec()))
+    } else {
+        let token_id = as_word(&event.topic3)?;
+        let mut amount = vec![0; WORD_SIZE];
+        amount[WORD_SIZE - 1] = 1;
+
+        Some((TOKEN_STANDARD_ERC721, Some(token_id.to_vec()), amount))
+    }
+}
+
+fn as_word(bytes: &[u8]) -> Option<[u8; WORD_SIZE]> {
+    bytes.try_into().ok()
+}
+
+fn data_word(data: &[u8], index: usize) -> Option<[u8; WORD_SIZE]> {
+    let start = index.checked_mul(WORD_SIZE)?;
+
+    as_word(data.get(start..start.checked_add(WORD_SIZE)?)?)
+}
+
+// Reads a dynamic `uint256[]` located at the `offset` (in bytes) of the ABI-encoded `data`.
+fn data_u256_array(data: &[u8], offset: [u8; WORD_SIZE]) -> Option<Vec<[u8; WORD_SIZE]>> {
+    let offset = word_to_usize(&offset)?;
+
+    if offset % WORD_SIZE != 0 {
+        return None;
+    }
+
+    let start = offset / WORD_SIZE;
+    let len = word_to_usize(&data_word(data, start)?)?;
+
+    (0..len)
+        .map(|i| data_word(data, start.checked_add(1)?.checked_add(i)?))
+        .collect()
+}
+
+fn word_to_usize(word: &[u8; WORD_SIZE]) -> Option<usize> {
+    let (high, low) = word.split_at(WORD_SIZE - 8);
+
+    if high.iter().any(|b| *b != 0) {
+        return None;
+    }
+
+    usize::try_from(u64::from_be_bytes(low.try_into().ok()?)).ok()
+}
+
+// Indexed addresses are left-padded to 32 bytes.
+fn topic_address(topic: &[u8]) -> Option<String> {
+    let word = as_word(topic)?;
+    let (padding, address) = word.split_at(WORD_SIZE - 20);
+
+    if padding.iter().any(|b| *b != 0) {
+        return None;
+    }
+
+    Some(format!("0x{}", hex::encode(address)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FROM: u8 = 0xaa;
+    const TO: u8 = 0xbb;
+
+    fn word(value: u64) -> Vec<u8> {
+        let mut word = vec![0; WORD_SIZE];
+        word[WORD_SIZE - 8..].copy_from_slice(&value.to_be_bytes());
+
+        word
+    }
+
+    fn address_topic(byte: u8) -> Vec<u8> {
+        let mut topic = vec![0; WORD_SIZE];
+        topic[WORD_SIZE - 20..].fill(byte);
+
+        topic
+    }
+
+    fn address(byte: u8) -> String {
+        format!("0x{}", hex::encode([byte; 20]))
+    }
+
+    fn erc1155_event(signature: &str, data: Vec<Vec<u8>>) -> Event {
+        Event {
+            index: 1,
+            tx_index: 2,
+            tx_hash: "some-tx-hash".to_string(),
+            block_number: 0,
+            block_hash: "some-block-hash".to_string(),
+            address: "some-erc1155".to_string(),
+            topic0: hex::decode(signature).unwrap(),
+            topic1: address_topic(0x01),
+            topic2: address_topic(FROM),
+            topic3: address_topic(TO),
+            topic4: vec![],
+            data: data.concat(),
+        }
+    }
+
+    #[test]
+    fn transfer_single() {
+        let event = erc1155_event(TRANSFER_SINGLE_SIGNATURE, vec![word(7), word(100)]);
+        let transfer = decode_transfer_single(&event).unwrap();
+
+        assert_eq!(transfer.standard, TOKEN_STANDARD_ERC1155);
+        assert_eq!(transfer.from, address(FROM));
+        assert_eq!(transfer.to, address(TO));
+        assert_eq!(transfer.token_id, Some(word(7)));
+        assert_eq!(transfer.amount, word(100));
+    }
+
+    #[test]
+    fn transfer_single_short_data() {
+        let event = erc1155_event(TRANSFER_SINGLE_SIGNATURE, vec![word(7), vec![0; 31]]);
+
+        assert!(decode_transfer_single(&event).is_none());
+    }
+
+    #[test]
+    fn transfer_single_invalid_address() {
+        let mut event = erc1155_event(TRANSFER_SINGLE_SIGNATURE, vec![word(7), word(100)]);
+        event.topic2[0] = 1;
+
+        assert!(decode_transfer_single(&event).is_none());
+    }
+
+    #[test]
+    fn transfer_batch() {
+        let event = erc1155_event(
+            TRANSFER_BATCH_SIGNATURE,
+            vec![
+                // offsets of `ids` and `values`
+                word(64),
+                word(160),
+                // ids
+                word(2),
+                word(1),
+                word(2),
+                // values
+                word(2),
+                word(10),
+                word(20),
+            ],
+        );
+
+        let transfers = decode_transfer_batch(&event);
+
+        assert_eq!(transfers.len(), 2);
+        assert_eq!(transfers[0].token_id, Some(word(1)));
+        assert_eq!(transfers[0].amount, word(10));
+        assert_eq!(transfers[1].token_id, Some(word(2)));
+        assert_eq!(transfers[1].amount, word(20));
+        assert!(transfers
+            .iter()
+            .all(|transfer| transfer.from == address(FROM)));
+        assert!(transfers.iter().all(|transfer| transfer.to == address(TO)));
+    }
+
+    #[test]
+    fn transfer_batch_length_mismatch() {
+        let event = erc1155_event(
+            TRANSFER_BATCH_SIGNATURE,
+            vec![
+                word(64),
+                word(128),
+                word(1),
+                word(1),
+                word(2),
+                word(10),
+                word(20),
+            ],
+        );
+
+        assert!(decode_transfer_batch(&event).is_empty());
+    }
+
+    #[test]
+    fn transfer_batch_truncated_data() {
+        let event = erc1155_event(
+            TRANSFER_BATCH_SIGNATURE,
+            vec![
+                word(64),
+                word(160),
+                word(2),
+                word(1),
+                word(2),
+                word(2),
+                word(10),
+            ],
+        );
+
+        assert!(decode_transfer_batch(&event).is_empty());
+    }
+
+    #[test]
+    fn u256_array() {
+        let data = [word(32), word(2), word(3), word(4)].concat();
+        let offset = as_word(&word(32)).unwrap();
+
+        assert_eq!(
+            data_u256_array(&data, offset),
+            Some(vec![as_word(&word(3)).unwrap(), as_word(&word(4)).unwrap()])
+        );
+    }
+
+    #[test]
+    fn u256_array_empty() {
+        let data = [word(32), word(0)].concat();
+
+        assert_eq!(
+            data_u256_array(&data, as_word(&word(32)).unwrap()),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn u256_array_malformed() {
+        let data = [word(32), word(2), word(3), word(4)].concat();
+
+        // not aligned to a word
+        assert_eq!(data_u256_array(&data, as_word(&word(33)).unwrap()), None);
+
+        // out of `data`
+        assert_eq!(data_u256_array(&data, as_word(&word(128)).unwrap()), None);
+
+        // doesn't fit into `usize`
+        let mut huge = [0; WORD_SIZE];
+        huge[0] = 1;
+        assert_eq!(data_u256_array(&data, huge), None);
+
+        // the length is larger than `data`
+        let data = [word(32), word(u64::MAX), word(3)].concat();
+        assert_eq!(data_u256_array(&data, as_word(&word(32)).unwrap()), None);
+    }
+
+    #[test]
+    fn unknown_and_malformed_events_are_skipped() {
+        let mut unknown = erc1155_event(TRANSFER_SINGLE_SIGNATURE, vec![word(7), word(100)]);
+        unknown.topic0 = vec![0; WORD_SIZE];
+
+        let short = erc1155_event(TRANSFER_SINGLE_SIGNATURE, vec![]);
+
+        let mut erc20 = erc1155_event(TRANSFER_SIGNATURE, vec![vec![0; 16]]);
+        erc20.topic3 = vec![];
+
+        let (transfers, approvals) = derive_token_tables(&[unknown, short, erc20]);
+
+        assert!(transfers.is_empty());
+        assert!(approvals.is_empty());
+    }
+}
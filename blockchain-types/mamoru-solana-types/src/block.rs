@@ -0,0 +1,28 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "blocks")]
+pub struct Block {
+    #[schema(type = "DataType::UInt64")]
+    pub slot: u64,
+
+    /// base58
+    #[schema(type = "DataType::Utf8")]
+    pub blockhash: String,
+
+    /// base58
+    #[schema(type = "DataType::Utf8")]
+    pub previous_blockhash: String,
+
+    #[schema(type = "DataType::UInt64")]
+    pub parent_slot: u64,
+
+    #[schema(type = "DataType::UInt64")]
+    pub block_height: u64,
+
+    /// unix timestamp in seconds
+    #[schema(type = "DataType::UInt64")]
+    pub block_time: u64,
+}
@@ -0,0 +1,59 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+/// A top-level instruction of a transaction.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "instructions")]
+pub struct Instruction {
+    /// index of the transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub tx_index: u32,
+
+    /// index of the instruction in the transaction
+    #[schema(type = "DataType::UInt32")]
+    pub index: u32,
+
+    /// base58
+    #[schema(type = "DataType::Utf8")]
+    pub program_id: String,
+
+    /// comma-separated base58 account keys, in the order the program receives them
+    #[schema(type = "DataType::Utf8")]
+    pub accounts: String,
+
+    #[schema(type = "DataType::Binary")]
+    pub data: Vec<u8>,
+}
+
+/// An instruction invoked by a program via CPI.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "inner_instructions")]
+pub struct InnerInstruction {
+    /// index of the transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub tx_index: u32,
+
+    /// index of the top-level instruction this one was invoked from
+    #[schema(type = "DataType::UInt32")]
+    pub instruction_index: u32,
+
+    /// index among the inner instructions of the top-level instruction
+    #[schema(type = "DataType::UInt32")]
+    pub index: u32,
+
+    /// 2 for instructions invoked directly by a top-level instruction
+    #[schema(type = "DataType::UInt32")]
+    pub stack_height: u32,
+
+    /// base58
+    #[schema(type = "DataType::Utf8")]
+    pub program_id: String,
+
+    /// comma-separated base58 account keys, in the order the program receives them
+    #[schema(type = "DataType::Utf8")]
+    pub accounts: String,
+
+    #[schema(type = "DataType::Binary")]
+    pub data: Vec<u8>,
+}
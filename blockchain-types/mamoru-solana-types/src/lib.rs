@@ -0,0 +1,29 @@
+pub use block::*;
+use blockchain_data_macro::BlockchainCtx;
+pub use instruction::*;
+pub use log_message::*;
+pub use token_balance_change::*;
+pub use transaction::*;
+
+mod block;
+mod instruction;
+mod log_message;
+mod token_balance_change;
+mod transaction;
+
+#[derive(BlockchainCtx)]
+#[ctx(module = "mamoru_solana")]
+pub struct SolanaCtx {
+    pub block: Option<Block>,
+    pub transactions: Vec<Transaction>,
+    pub instructions: Vec<Instruction>,
+    pub inner_instructions: Vec<InnerInstruction>,
+    pub token_balance_changes: Vec<TokenBalanceChange>,
+    pub log_messages: Vec<LogMessage>,
+}
+
+impl SolanaCtx {
+    pub fn set_block(&mut self, block: Block) {
+        self.block = Some(block);
+    }
+}
@@ -0,0 +1,18 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "log_messages")]
+pub struct LogMessage {
+    /// index of the transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub tx_index: u32,
+
+    /// index of the message in the transaction logs
+    #[schema(type = "DataType::UInt32")]
+    pub index: u32,
+
+    #[schema(type = "DataType::Utf8")]
+    pub message: String,
+}
@@ -0,0 +1,35 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+/// An SPL token account balance changed by a transaction.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "token_balance_changes")]
+pub struct TokenBalanceChange {
+    /// index of the transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub tx_index: u32,
+
+    /// token account, base58
+    #[schema(type = "DataType::Utf8")]
+    pub account: String,
+
+    /// base58
+    #[schema(type = "DataType::Utf8")]
+    pub mint: String,
+
+    /// base58
+    #[schema(type = "DataType::Utf8")]
+    pub owner: String,
+
+    #[schema(type = "DataType::UInt8")]
+    pub decimals: u8,
+
+    /// raw amount before the transaction
+    #[schema(type = "DataType::UInt64")]
+    pub pre_amount: u64,
+
+    /// raw amount after the transaction
+    #[schema(type = "DataType::UInt64")]
+    pub post_amount: u64,
+}
@@ -0,0 +1,33 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "transactions")]
+pub struct Transaction {
+    /// index of the transaction in the block
+    #[schema(type = "DataType::UInt32")]
+    pub index: u32,
+
+    /// first signature of the transaction, base58
+    #[schema(type = "DataType::Utf8")]
+    pub signature: String,
+
+    #[schema(type = "DataType::UInt64")]
+    pub slot: u64,
+
+    /// base58
+    #[schema(type = "DataType::Utf8")]
+    pub fee_payer: String,
+
+    /// in lamports
+    #[schema(type = "DataType::UInt64")]
+    pub fee: u64,
+
+    #[schema(type = "DataType::UInt64")]
+    pub compute_units_consumed: u64,
+
+    /// null if the transaction succeeded
+    #[schema(type = "DataType::Utf8", nullable = true)]
+    pub error: Option<String>,
+}
@@ -25,7 +25,7 @@ pub struct CallTrace {
     #[schema(type = "DataType::Utf8", nullable = true)]
     pub transaction_module: Option<String>,
 
-    #[schema(type = "DataType::Utf8")]
+    #[schema(type = "DataType::Utf8", as_alias = "func")]
     pub function: String,
 }
 
@@ -51,7 +51,7 @@ pub struct CallTraceArg {
     #[schema(type = "DataType::UInt64")]
     pub call_trace_seq: u64,
 
-    #[schema(type = "DataType::LargeBinary")]
+    #[schema(type = "DataType::LargeBinary", as_alias = "value")]
     #[serde(skip_serializing)]
     pub arg: ValueData,
 }
@@ -0,0 +1,51 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::DataType;
+use serde::{Deserialize, Serialize};
+
+/// An object created, mutated or removed by the transaction.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "object_changes")]
+pub struct ObjectChange {
+    #[schema(type = "DataType::UInt64")]
+    pub tx_seq: u64,
+
+    #[schema(type = "DataType::Utf8")]
+    pub object_id: String,
+
+    #[schema(type = "DataType::Utf8", rename = "type", as_alias = "typ")]
+    pub typ: String,
+
+    /// null if the object is created by the transaction
+    #[schema(type = "DataType::Utf8", nullable = true)]
+    pub owner_before: Option<String>,
+
+    /// null if the object is deleted or wrapped by the transaction
+    #[schema(type = "DataType::Utf8", nullable = true)]
+    pub owner_after: Option<String>,
+
+    /// object version after the transaction
+    #[schema(type = "DataType::UInt64")]
+    pub version: u64,
+
+    /// one of `created`, `mutated`, `transferred`, `deleted`, `wrapped`, `unwrapped`, `published`
+    #[schema(type = "DataType::Utf8")]
+    pub kind: String,
+}
+
+/// A coin balance change of an owner made by the transaction.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "balance_changes")]
+pub struct BalanceChange {
+    #[schema(type = "DataType::UInt64")]
+    pub tx_seq: u64,
+
+    #[schema(type = "DataType::Utf8")]
+    pub owner: String,
+
+    #[schema(type = "DataType::Utf8")]
+    pub coin_type: String,
+
+    /// negative if the owner spent the coins
+    #[schema(as_type = "i128", as_from = "i128FromBE")]
+    pub amount: i128,
+}
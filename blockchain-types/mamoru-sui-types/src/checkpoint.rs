@@ -0,0 +1,27 @@
+use blockchain_data_macro::BlockchainData;
+use datafusion::arrow::datatypes::{DataType, TimeUnit};
+use serde::{Deserialize, Serialize};
+
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "checkpoints")]
+pub struct Checkpoint {
+    #[schema(type = "DataType::UInt64")]
+    pub seq: u64,
+
+    #[schema(type = "DataType::Utf8")]
+    pub digest: String,
+
+    /// null for the genesis checkpoint
+    #[schema(type = "DataType::Utf8", nullable = true)]
+    pub previous_digest: Option<String>,
+
+    #[schema(type = "DataType::UInt64")]
+    pub epoch: u64,
+
+    #[schema(type = "DataType::Timestamp(TimeUnit::Second, None)")]
+    pub time: i64,
+
+    /// total number of transactions committed on the network, including this checkpoint
+    #[schema(type = "DataType::UInt64")]
+    pub network_total_transactions: u64,
+}
@@ -17,7 +17,7 @@ pub struct Event {
     #[schema(type = "DataType::Utf8")]
     pub sender: String,
 
-    #[schema(type = "DataType::Utf8", rename = "type")]
+    #[schema(type = "DataType::Utf8", rename = "type", as_alias = "typ")]
     pub typ: String,
 
     #[schema(type = "DataType::LargeBinary")]
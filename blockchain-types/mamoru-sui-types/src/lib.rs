@@ -1,85 +1,58 @@
-use maplit::hashmap;
-
+use blockchain_data_macro::BlockchainCtx;
 pub use call_trace::*;
+pub use changes::*;
+pub use checkpoint::*;
 pub use events::*;
-use mamoru_core::{
-    serialize_data, BlockchainCtx, BlockchainSpecificImports, BlockchainTableItem, CtxImportError,
-    CtxImportFn,
-};
 pub use transaction::*;
 
 mod call_trace;
+mod changes;
+mod checkpoint;
 mod events;
 mod transaction;
 
+/// Holds either a single transaction (see [`SuiCtx::set_tx`]), e.g. from the mempool,
+/// or a whole checkpoint with all its transactions (see [`SuiCtx::set_checkpoint`]).
+/// Events, call traces and changes are linked to their transaction by `tx_seq`.
+#[derive(BlockchainCtx)]
+#[ctx(module = "mamoru_sui")]
 pub struct SuiCtx {
+    #[ctx(skip)]
     pub tx: Option<Transaction>,
+    #[ctx(with = "Self::checkpoints")]
+    pub checkpoint: Option<Checkpoint>,
+    #[ctx(with = "Self::all_transactions")]
+    pub transactions: Vec<Transaction>,
     pub call_traces: Vec<CallTrace>,
     pub call_trace_type_args: Vec<CallTraceTypeArg>,
+    #[ctx(by_id(import = "get_call_trace_arg_by_id", key = "seq", value = "arg"))]
     pub call_trace_args: Vec<CallTraceArg>,
     pub events: Vec<Event>,
+    pub object_changes: Vec<ObjectChange>,
+    pub balance_changes: Vec<BalanceChange>,
 }
 
 impl SuiCtx {
+    /// Sets a single transaction, clears the checkpoint set by [`SuiCtx::set_checkpoint`].
     pub fn set_tx(&mut self, tx: Transaction) {
         self.tx = Some(tx);
+        self.checkpoint = None;
+        self.transactions.clear();
     }
-}
 
-impl BlockchainCtx for SuiCtx {
-    fn empty() -> Self {
-        Self {
-            tx: None,
-            call_traces: Vec::new(),
-            call_trace_type_args: Vec::new(),
-            call_trace_args: Vec::new(),
-            events: Vec::new(),
-        }
+    /// Sets a whole checkpoint, clears the transaction set by [`SuiCtx::set_tx`].
+    pub fn set_checkpoint(&mut self, checkpoint: Checkpoint, transactions: Vec<Transaction>) {
+        self.tx = None;
+        self.checkpoint = Some(checkpoint);
+        self.transactions = transactions;
     }
 
-    fn module() -> &'static str {
-        "mamoru_sui"
-    }
-
-    fn imports() -> BlockchainSpecificImports<Self> {
-        hashmap! {
-            "get_transactions" => CtxImportFn::NoArgs(|ctx: &Self| -> Vec<u8> {
-                serialize_data(&[&ctx.tx])
-            }),
-            "get_events" => CtxImportFn::NoArgs(|ctx: &Self| -> Vec<u8> {
-                serialize_data(&ctx.events)
-            }),
-            "get_call_traces" => CtxImportFn::NoArgs(|ctx: &Self| -> Vec<u8> {
-                serialize_data(&ctx.call_traces)
-            }),
-            "get_call_trace_type_args" => CtxImportFn::NoArgs(|ctx: &Self| -> Vec<u8> {
-                serialize_data(&ctx.call_trace_type_args)
-            }),
-            "get_call_trace_args" => CtxImportFn::NoArgs(|ctx: &Self| -> Vec<u8> {
-                serialize_data(&ctx.call_trace_args)
-            }),
-            "get_call_trace_arg_by_id" => CtxImportFn::ById(|ctx: &Self, seq: u64| -> Result<&[u8], CtxImportError> {
-                let arg = ctx.call_trace_args
-                    .iter()
-                    .find(|arg| arg.seq == seq)
-                    .ok_or_else(|| CtxImportError::ById("Failed to find call trace arg".to_string()))?;
-
-                Ok(arg.arg.as_ref())
-            }),
-        }
+    /// The SDK expects an empty array when there is no checkpoint, e.g. for a single transaction.
+    fn checkpoints(&self) -> Vec<&Checkpoint> {
+        self.checkpoint.iter().collect()
     }
 
-    fn as_tables(&self) -> Vec<Box<dyn BlockchainTableItem>> {
-        vec![
-            TransactionBatch::new(match &self.tx {
-                Some(tx) => vec![tx.clone()],
-                None => vec![],
-            })
-            .boxed(),
-            CallTraceBatch::new(self.call_traces.clone()).boxed(),
-            CallTraceTypeArgBatch::new(self.call_trace_type_args.clone()).boxed(),
-            CallTraceArgBatch::new(self.call_trace_args.clone()).boxed(),
-            EventBatch::new(self.events.clone()).boxed(),
-        ]
+    fn all_transactions(&self) -> Vec<&Transaction> {
+        self.tx.iter().chain(&self.transactions).collect()
     }
 }
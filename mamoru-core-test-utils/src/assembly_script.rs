@@ -31,6 +31,12 @@ pub const AS_APTOS_SDK_PATH: &str = concat!(
     "/../sdk-as/mamoru-aptos-sdk-as"
 );
 
+pub const AS_COSMOS_SDK_PATH: &str = concat!(
+    "file:",
+    env!("CARGO_MANIFEST_DIR"),
+    "/../sdk-as/mamoru-cosmos-sdk-as"
+);
+
 /// Setups AssemblyScript project for tests.
 pub struct AssemblyScriptModule {
     _dir_guard: TempDir,
@@ -67,6 +67,31 @@ pub fn data_ctx(tx_hash: impl Into<String>) -> BlockchainData<TestCtx> {
         },
     ]);
 
+    data.events.extend(vec![
+        TestEvent {
+            seq: 0,
+            tx_seq,
+            topics: vec![hex::decode(TEST_ETH_TOPIC).unwrap()],
+            tags: vec!["transfer".to_string(), "erc20".to_string()],
+            memo: None,
+            transfer: Some(TestTransfer {
+                from: "alice".to_string(),
+                to: "bob".to_string(),
+                amount: 100,
+            }),
+            label: Some("erc20 transfer".to_string()),
+        },
+        TestEvent {
+            seq: 1,
+            tx_seq,
+            topics: vec![],
+            tags: vec![],
+            memo: Some("approve".to_string()),
+            transfer: None,
+            label: None,
+        },
+    ]);
+
     builder.set_statistics(1231, 2341, 3451, 4561);
     builder.set_tx_data(format!("{}", tx_seq), digest.clone());
     builder.set_block_data(format!("{}", tx_seq), digest);
@@ -122,10 +147,42 @@ pub struct TestCallTraceArg {
     pub arg: ValueData,
 }
 
+/// The Arrow types are inferred from the field types.
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+#[schema(table_name = "events")]
+pub struct TestEvent {
+    pub seq: u64,
+
+    pub tx_seq: u64,
+
+    pub topics: Vec<Vec<u8>>,
+
+    pub tags: Vec<String>,
+
+    pub memo: Option<String>,
+
+    pub transfer: Option<TestTransfer>,
+
+    /// Explicit type, but still nullable as `Option<T>`.
+    #[schema(type = "DataType::Utf8")]
+    pub label: Option<String>,
+}
+
+/// Not a table, stored as Arrow `Struct` in [`TestEvent`].
+#[derive(BlockchainData, Clone, Serialize, Deserialize)]
+pub struct TestTransfer {
+    pub from: String,
+
+    pub to: String,
+
+    pub amount: u64,
+}
+
 pub struct TestCtx {
     pub tx: Option<TestTransaction>,
     pub call_traces: Vec<TestCallTrace>,
     pub call_trace_args: Vec<TestCallTraceArg>,
+    pub events: Vec<TestEvent>,
 }
 
 impl TestCtx {
@@ -140,6 +197,7 @@ impl BlockchainCtx for TestCtx {
             tx: None,
             call_traces: vec![],
             call_trace_args: vec![],
+            events: vec![],
         }
     }
 
@@ -160,6 +218,7 @@ impl BlockchainCtx for TestCtx {
             .boxed(),
             TestCallTraceBatch::new(self.call_traces.clone()).boxed(),
             TestCallTraceArgBatch::new(self.call_trace_args.clone()).boxed(),
+            TestEventBatch::new(self.events.clone()).boxed(),
         ]
     }
 }
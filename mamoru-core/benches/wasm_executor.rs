@@ -0,0 +1,59 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use mamoru_core::assembly_script::{AssemblyScriptExecutor, ExecutorOptions, ModuleCache};
+use mamoru_core::IncidentLimit;
+use mamoru_core_test_utils::test_blockchain_data::data_ctx;
+
+/// A plain ABI module with a non-trivial memory and a few mutable globals to restore.
+const DAEMON_WAT: &str = r#"(module
+    (import "mamoru" "query" (func $query (param i32 i32) (result i64)))
+    (memory (export "memory") 4)
+    (global $heap (mut i32) (i32.const 65536))
+    (global $runs (mut i32) (i32.const 0))
+    (data (i32.const 0) "SELECT t.seq FROM transactions t")
+
+    (func (export "mamoru_alloc") (param $size i32) (result i32)
+        (local $ptr i32)
+        (local.set $ptr (global.get $heap))
+        (global.set $heap (i32.add (global.get $heap) (local.get $size)))
+        (local.get $ptr))
+
+    (func (export "main")
+        (global.set $runs (i32.add (global.get $runs) (i32.const 1)))
+        (drop (call $query (i32.const 0) (i32.const 32)))))"#;
+
+fn execute(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let wasm = wat::parse_str(DAEMON_WAT).unwrap();
+    let ctx = data_ctx("DUMMY_HASH");
+
+    let mut group = c.benchmark_group("wasm_executor");
+
+    for (mode, pool_size) in [("fresh_instance", 0), ("pooled_instance", 1)] {
+        let executor = AssemblyScriptExecutor::with_options(
+            &wasm,
+            Default::default(),
+            Default::default(),
+            ModuleCache::global(),
+            ExecutorOptions {
+                pool_size,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        group.bench_function(BenchmarkId::new("execute", mode), |b| {
+            b.to_async(&runtime).iter(|| async {
+                executor
+                    .execute(&ctx, IncidentLimit::default())
+                    .await
+                    .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, execute);
+criterion_main!(benches);
@@ -0,0 +1,621 @@
+use std::fmt::Write;
+
+use datafusion::arrow::datatypes::{DataType, Field, Fields, Schema, TimeUnit};
+
+use crate::{
+    BlockchainCtx, ByIdImport, CodegenError, SCHEMA_AS_ALIAS_KEY, SCHEMA_AS_FROM_KEY,
+    SCHEMA_AS_TYPE_KEY, SCHEMA_DOC_KEY, SCHEMA_SKIP_SERIALIZING_KEY, SCHEMA_STRUCT_KEY,
+};
+
+/// AssemblyScript reserved words that can't be used as local variable names.
+const RESERVED_WORDS: &[&str] = &[
+    "class", "const", "default", "function", "in", "let", "new", "return", "type", "var",
+];
+
+/// Wraps nullable value types, AssemblyScript `u64 | null` is not allowed.
+const NULLABLE_CLASS: &str = "// A nullable value type column, e.g. `Option<u64>`.
+export class Nullable<T> {
+    public readonly value: T
+
+    constructor(value: T) {
+        this.value = value
+    }
+}
+";
+
+/// Generates AssemblyScript classes decoding the tables of `T` passed to WASM via `get_<table>` imports.
+///
+/// The classes mirror the Rust structs: the struct name, field order and doc comments are taken from
+/// the schema metadata generated by `blockchain-data-derive`, the fields skipped by serde are omitted
+/// unless they are read with a by-id import, see [`BlockchainCtx::by_id_imports`].
+/// Nested structs become classes too, their names are taken from the field metadata.
+///
+/// The table classes are decoded by their constructors, so an SDK can extend them with methods.
+/// The column hints set with `#[schema(...)]` keep the SDK API stable:
+/// - `as_type = "u256", as_from = "u256.fromUint8ArrayBE"` converts the decoded column,
+///   both names are imported from the SDK `types` module
+/// - `as_alias = "typ"` adds a deprecated getter with the old property name
+pub fn generate_as_sdk<T: BlockchainCtx>() -> Result<String, CodegenError> {
+    let tables: Vec<_> = T::empty()
+        .as_tables()
+        .iter()
+        .map(|table| (table.table_name(), table.schema()))
+        .collect();
+    let by_id_imports = T::by_id_imports();
+
+    let mut codegen = Codegen::default();
+    let mut classes = vec![];
+    let mut imports = vec![];
+
+    for (table_name, schema) in &tables {
+        let by_id: Vec<_> = by_id_imports
+            .iter()
+            .filter(|by_id| by_id.table == *table_name)
+            .collect();
+
+        classes.push(codegen.table_class(table_name, schema, &by_id)?);
+        imports.push(format!("_mamoru_get_{}", table_name));
+        imports.extend(
+            by_id
+                .iter()
+                .map(|by_id| format!("_mamoru_{}", by_id.import)),
+        );
+    }
+
+    let mut out = String::new();
+
+    out.push_str("// This file is generated from the Rust types by `mamoru_core::generate_as_sdk`, do not edit it.\n");
+    out.push_str("// Run the SDK tests with `UPDATE_EXPECT=1` to update it.\n");
+    writeln!(
+        out,
+        "import {{ {} }} from \"./imports\";",
+        imports.join(", ")
+    )
+    .unwrap();
+    out.push_str("import { msgPackReadUint8Array, readMemory, unpackValues } from \"@mamoru-ai/mamoru-sdk-as/assembly/util\";\n");
+
+    if !by_id_imports.is_empty() {
+        out.push_str("import { Value } from \"@mamoru-ai/mamoru-sdk-as/assembly\";\n");
+    }
+
+    if !codegen.as_imports.is_empty() {
+        writeln!(
+            out,
+            "import {{ {} }} from \"./types\";",
+            codegen.as_imports.join(", ")
+        )
+        .unwrap();
+    }
+
+    out.push_str("import { Decoder } from \"@wapc/as-msgpack/assembly\";\n");
+
+    for class in classes
+        .iter()
+        .chain(codegen.nested.iter().map(|(_, class)| class))
+    {
+        out.push('\n');
+        out.push_str(class);
+    }
+
+    if codegen.uses_nullable {
+        out.push('\n');
+        out.push_str(NULLABLE_CLASS);
+    }
+
+    Ok(out)
+}
+
+/// A column serialized for WASM.
+struct Column<'a> {
+    field: &'a Field,
+    /// AssemblyScript type, `| null` included.
+    ts_type: String,
+    /// The expression reading a non-null value.
+    read: String,
+}
+
+impl Column<'_> {
+    fn property(&self) -> String {
+        property(self.field.name())
+    }
+
+    fn alias(&self) -> Option<&String> {
+        self.field.metadata().get(SCHEMA_AS_ALIAS_KEY)
+    }
+
+    fn local(&self) -> String {
+        let name = self.field.name();
+
+        if RESERVED_WORDS.contains(&name.as_str()) {
+            format!("{}_", name)
+        } else {
+            name.to_string()
+        }
+    }
+}
+
+/// A column skipped by serde and read with a by-id import.
+struct ByIdColumn<'a> {
+    field: &'a Field,
+    by_id: &'a ByIdImport,
+}
+
+impl ByIdColumn<'_> {
+    fn alias(&self) -> Option<&String> {
+        self.field.metadata().get(SCHEMA_AS_ALIAS_KEY)
+    }
+
+    fn ts_type(&self) -> &'static str {
+        if self.field.is_nullable() {
+            "Value | null"
+        } else {
+            "Value"
+        }
+    }
+}
+
+#[derive(Default)]
+struct Codegen {
+    /// The classes of nested structs by name, in the order they are found.
+    nested: Vec<(String, String)>,
+
+    /// If [`NULLABLE_CLASS`] is used.
+    uses_nullable: bool,
+
+    /// The names imported from the SDK `types` module for `as_type` columns.
+    as_imports: Vec<String>,
+}
+
+impl Codegen {
+    fn table_class(
+        &mut self,
+        table_name: &str,
+        schema: &Schema,
+        by_id: &[&ByIdImport],
+    ) -> Result<String, CodegenError> {
+        let class = schema.metadata().get(SCHEMA_STRUCT_KEY).ok_or_else(|| {
+            CodegenError::MissingStructName {
+                table: table_name.to_string(),
+            }
+        })?;
+
+        let columns = self.columns(table_name, schema.fields())?;
+        let by_id_columns: Vec<_> = schema
+            .fields()
+            .iter()
+            .filter(|field| field.metadata().contains_key(SCHEMA_SKIP_SERIALIZING_KEY))
+            .filter_map(|field| {
+                let by_id = by_id
+                    .iter()
+                    .find(|by_id| by_id.value == field.name().as_str())?;
+
+                Some(ByIdColumn {
+                    field,
+                    by_id: *by_id,
+                })
+            })
+            .collect();
+
+        let mut out = String::new();
+
+        if let Some(doc) = schema.metadata().get(SCHEMA_DOC_KEY) {
+            writeln!(out, "// {}", doc).unwrap();
+        }
+
+        writeln!(out, "export class {} {{", class).unwrap();
+        write_properties(&mut out, &columns);
+
+        if !by_id_columns.is_empty() {
+            out.push('\n');
+        }
+
+        for column in &by_id_columns {
+            writeln!(
+                out,
+                "    private _{}: Value | null = null",
+                property(column.field.name())
+            )
+            .unwrap();
+        }
+
+        write_decoding_constructor(&mut out, &columns);
+
+        for column in &by_id_columns {
+            write_by_id_getter(&mut out, column);
+        }
+
+        for column in &by_id_columns {
+            if let Some(alias) = column.alias() {
+                write_alias(&mut out, alias, column.field.name(), column.ts_type());
+            }
+        }
+
+        write_aliases(&mut out, &columns);
+
+        writeln!(out, "    public static loadAll(): {}[] {{", class).unwrap();
+        writeln!(
+            out,
+            "        let ptr_len = unpackValues(_mamoru_get_{}());\n",
+            table_name
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        return {}.fromHost(ptr_len[0], ptr_len[1]);",
+            class
+        )
+        .unwrap();
+        out.push_str("    }\n\n");
+
+        writeln!(
+            out,
+            "    private static fromHost(ptr: u32, len: u32): {}[] {{",
+            class
+        )
+        .unwrap();
+        out.push_str("        const decoder = new Decoder(readMemory(ptr, len).buffer);\n\n");
+        writeln!(
+            out,
+            "        return decoder.readArray<{}>((decoder: Decoder) => new {}(decoder));",
+            class, class
+        )
+        .unwrap();
+        out.push_str("    }\n");
+        out.push_str("}\n");
+
+        Ok(out)
+    }
+
+    /// Generates a class for a nested struct once, it's decoded in place with `decode()`.
+    fn nested_class(
+        &mut self,
+        table_name: &str,
+        class: &str,
+        fields: &Fields,
+    ) -> Result<(), CodegenError> {
+        if self.nested.iter().any(|(name, _)| name == class) {
+            return Ok(());
+        }
+
+        // Reserves the name, so recursive structs are not generated twice.
+        self.nested.push((class.to_string(), String::new()));
+
+        let columns = self.columns(table_name, fields)?;
+        let mut out = String::new();
+
+        writeln!(out, "export class {} {{", class).unwrap();
+        write_properties(&mut out, &columns);
+        write_constructor(&mut out, &columns);
+        write_aliases(&mut out, &columns);
+
+        writeln!(
+            out,
+            "    public static decode(decoder: Decoder): {} {{",
+            class
+        )
+        .unwrap();
+        write_reads(&mut out, &columns, "        ");
+
+        let args: Vec<String> = columns.iter().map(|column| column.local()).collect();
+
+        writeln!(out, "\n        return new {}({});", class, args.join(", ")).unwrap();
+        out.push_str("    }\n");
+        out.push_str("}\n");
+
+        let entry = self
+            .nested
+            .iter_mut()
+            .find(|(name, _)| name == class)
+            .expect("BUG: the class name is reserved above.");
+        entry.1 = out;
+
+        Ok(())
+    }
+
+    fn columns<'a>(
+        &mut self,
+        table_name: &str,
+        fields: &'a Fields,
+    ) -> Result<Vec<Column<'a>>, CodegenError> {
+        fields
+            .iter()
+            .filter(|field| !field.metadata().contains_key(SCHEMA_SKIP_SERIALIZING_KEY))
+            .map(|field| self.column(table_name, field))
+            .collect()
+    }
+
+    fn column<'a>(
+        &mut self,
+        table_name: &str,
+        field: &'a Field,
+    ) -> Result<Column<'a>, CodegenError> {
+        let (ts_type, read, is_reference) = match (
+            field.metadata().get(SCHEMA_AS_TYPE_KEY),
+            field.metadata().get(SCHEMA_AS_FROM_KEY),
+        ) {
+            (Some(as_type), Some(as_from)) => {
+                let (_, read, _) = self.value_type(table_name, field, field.data_type())?;
+                let from_name = as_from.trim_start_matches("new ").split('.').next();
+
+                for name in [Some(as_type.as_str()), from_name].into_iter().flatten() {
+                    if !self.as_imports.iter().any(|import| import == name) {
+                        self.as_imports.push(name.to_string());
+                    }
+                }
+
+                // The SDK types are classes.
+                (as_type.clone(), format!("{}({})", as_from, read), true)
+            }
+            _ => self.value_type(table_name, field, field.data_type())?,
+        };
+
+        let (ts_type, read) = match (field.is_nullable(), is_reference) {
+            (false, _) => (ts_type, read),
+            (true, true) => (format!("{} | null", ts_type), read),
+            // AssemblyScript value types can't be null
+            (true, false) => {
+                self.uses_nullable = true;
+
+                (
+                    format!("Nullable<{}> | null", ts_type),
+                    format!("new Nullable<{}>({})", ts_type, read),
+                )
+            }
+        };
+
+        Ok(Column {
+            field,
+            ts_type,
+            read,
+        })
+    }
+
+    /// The AssemblyScript type, the expression reading it and if it's a reference type.
+    fn value_type(
+        &mut self,
+        table_name: &str,
+        field: &Field,
+        data_type: &DataType,
+    ) -> Result<(String, String, bool), CodegenError> {
+        let primitive = |ts_type: &str, read: &str| -> Result<_, CodegenError> {
+            Ok((ts_type.to_string(), read.to_string(), false))
+        };
+
+        match data_type {
+            DataType::Boolean => primitive("bool", "decoder.readBool()"),
+            DataType::UInt8 => primitive("u8", "decoder.readUInt8()"),
+            DataType::UInt16 => primitive("u16", "decoder.readUInt16()"),
+            DataType::UInt32 => primitive("u32", "decoder.readUInt32()"),
+            DataType::UInt64 => primitive("u64", "decoder.readUInt64()"),
+            DataType::Int8 => primitive("i8", "decoder.readInt8()"),
+            DataType::Int16 => primitive("i16", "decoder.readInt16()"),
+            DataType::Int32 => primitive("i32", "decoder.readInt32()"),
+            DataType::Int64 | DataType::Timestamp(TimeUnit::Second, None) => {
+                primitive("i64", "decoder.readInt64()")
+            }
+            DataType::Float32 => primitive("f32", "decoder.readFloat32()"),
+            DataType::Float64 => primitive("f64", "decoder.readFloat64()"),
+            DataType::Utf8 => Ok((
+                "string".to_string(),
+                "decoder.readString()".to_string(),
+                true,
+            )),
+            DataType::Binary | DataType::LargeBinary => Ok((
+                "Uint8Array".to_string(),
+                "msgPackReadUint8Array(decoder)".to_string(),
+                true,
+            )),
+            // `i128` columns, rmp-serde serializes them as 16 big-endian bytes
+            DataType::Decimal128(_, 0) => Ok((
+                "Uint8Array".to_string(),
+                "msgPackReadUint8Array(decoder)".to_string(),
+                true,
+            )),
+            // The items are always marked as nullable by `ListBuilder`, `Vec<Option<T>>` is not supported.
+            DataType::List(item) => {
+                let (item_type, item_read, _) =
+                    self.value_type(table_name, field, item.data_type())?;
+
+                Ok((
+                    format!("{}[]", item_type),
+                    format!(
+                        "decoder.readArray<{}>((decoder: Decoder) => {})",
+                        item_type, item_read
+                    ),
+                    true,
+                ))
+            }
+            DataType::Struct(fields) => {
+                let class = field
+                    .metadata()
+                    .get(SCHEMA_STRUCT_KEY)
+                    .ok_or_else(|| unsupported(table_name, field))?;
+
+                self.nested_class(table_name, class, fields)?;
+
+                Ok((class.clone(), format!("{}.decode(decoder)", class), true))
+            }
+            _ => Err(unsupported(table_name, field)),
+        }
+    }
+}
+
+fn unsupported(table_name: &str, field: &Field) -> CodegenError {
+    CodegenError::UnsupportedType {
+        table: table_name.to_string(),
+        column: field.name().to_string(),
+        data_type: field.data_type().to_string(),
+    }
+}
+
+/// `snake_case` column name to `camelCase` property name.
+fn property(name: &str) -> String {
+    let mut property = String::new();
+    let mut upper = false;
+
+    for ch in name.chars() {
+        if ch == '_' {
+            upper = true;
+        } else if upper {
+            property.extend(ch.to_uppercase());
+            upper = false;
+        } else {
+            property.push(ch);
+        }
+    }
+
+    property
+}
+
+fn write_properties(out: &mut String, columns: &[Column]) {
+    for column in columns {
+        if let Some(doc) = column.field.metadata().get(SCHEMA_DOC_KEY) {
+            writeln!(out, "    // {}", doc).unwrap();
+        }
+
+        writeln!(
+            out,
+            "    public readonly {}: {}",
+            column.property(),
+            column.ts_type
+        )
+        .unwrap();
+    }
+}
+
+fn write_constructor(out: &mut String, columns: &[Column]) {
+    out.push_str("\n    private constructor(\n");
+
+    let params: Vec<String> = columns
+        .iter()
+        .map(|column| format!("        {}: {}", column.local(), column.ts_type))
+        .collect();
+
+    writeln!(out, "{}", params.join(",\n")).unwrap();
+    out.push_str("    ) {\n");
+
+    for column in columns {
+        writeln!(
+            out,
+            "        this.{} = {}",
+            column.property(),
+            column.local()
+        )
+        .unwrap();
+    }
+
+    out.push_str("    }\n\n");
+}
+
+/// The constructor of a table class, the SDK classes extending it call `super(decoder)`.
+fn write_decoding_constructor(out: &mut String, columns: &[Column]) {
+    out.push_str("\n    constructor(decoder: Decoder) {\n");
+    write_reads(out, columns, "        ");
+    out.push('\n');
+
+    for column in columns {
+        writeln!(
+            out,
+            "        this.{} = {}",
+            column.property(),
+            column.local()
+        )
+        .unwrap();
+    }
+
+    out.push_str("    }\n\n");
+}
+
+fn write_aliases(out: &mut String, columns: &[Column]) {
+    for column in columns {
+        if let Some(alias) = column.alias() {
+            write_alias(out, alias, column.field.name(), &column.ts_type);
+        }
+    }
+}
+
+/// A deprecated getter keeping the old property name of a column.
+fn write_alias(out: &mut String, alias: &str, name: &str, ts_type: &str) {
+    let property = property(name);
+
+    writeln!(out, "    // Deprecated, use `{}` instead.", property).unwrap();
+    writeln!(out, "    public get {}(): {} {{", alias, ts_type).unwrap();
+    writeln!(out, "        return this.{}", property).unwrap();
+    out.push_str("    }\n\n");
+}
+
+fn write_by_id_getter(out: &mut String, column: &ByIdColumn) {
+    let property = property(column.field.name());
+
+    if let Some(doc) = column.field.metadata().get(SCHEMA_DOC_KEY) {
+        writeln!(out, "    // {}", doc).unwrap();
+    }
+
+    writeln!(
+        out,
+        "    public get {}(): {} {{",
+        property,
+        column.ts_type()
+    )
+    .unwrap();
+    writeln!(out, "        if (this._{} == null) {{", property).unwrap();
+    writeln!(
+        out,
+        "            const ptr_len = unpackValues(_mamoru_{}(this.{}));",
+        column.by_id.import,
+        self::property(column.by_id.key)
+    )
+    .unwrap();
+    out.push_str("            const buffer = readMemory(ptr_len[0], ptr_len[1]).buffer;\n\n");
+
+    if column.field.is_nullable() {
+        out.push_str("            if (new Decoder(buffer).isNextNil()) {\n");
+        out.push_str("                return null;\n");
+        out.push_str("            }\n\n");
+    }
+
+    writeln!(
+        out,
+        "            this._{} = Value.fromBytes(buffer);",
+        property
+    )
+    .unwrap();
+    out.push_str("        }\n\n");
+
+    if column.field.is_nullable() {
+        writeln!(out, "        return this._{};", property).unwrap();
+    } else {
+        writeln!(out, "        return this._{}!;", property).unwrap();
+    }
+
+    out.push_str("    }\n\n");
+}
+
+/// Decodes a single row into locals named with [`Column::local`], `indent` is the body indentation.
+fn write_reads(out: &mut String, columns: &[Column], indent: &str) {
+    writeln!(
+        out,
+        "{}// consume array size (we can't parse data otherwise)",
+        indent
+    )
+    .unwrap();
+    writeln!(out, "{}let _ = decoder.readArraySize();\n", indent).unwrap();
+
+    for column in columns {
+        if column.field.is_nullable() {
+            writeln!(
+                out,
+                "{}let {}: {} = null;",
+                indent,
+                column.local(),
+                column.ts_type
+            )
+            .unwrap();
+            writeln!(out, "{}if (!decoder.isNextNil()) {{", indent).unwrap();
+            writeln!(out, "{}    {} = {};", indent, column.local(), column.read).unwrap();
+            writeln!(out, "{}}}", indent).unwrap();
+        } else {
+            writeln!(out, "{}let {} = {};", indent, column.local(), column.read).unwrap();
+        }
+    }
+}
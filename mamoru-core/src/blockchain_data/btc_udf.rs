@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use datafusion::arrow::array::{BinaryArray, StringArray};
+use datafusion::arrow::datatypes::DataType;
+
+use crate::blockchain_data::btc_value::{classify_btc_script, derive_btc_address};
+use crate::udf;
+
+udf!(
+    btc_script_type,
+    (StringArray DataType::Utf8),
+    [
+        0 => script: (BinaryArray DataType::Binary),
+    ],
+    |script| {
+        Some(classify_btc_script(script))
+    }
+);
+
+udf!(
+    btc_script_to_address,
+    (StringArray DataType::Utf8),
+    [
+        0 => script: (BinaryArray DataType::Binary),
+        1 => network: (StringArray DataType::Utf8),
+    ],
+    |script, network| {
+        derive_btc_address(script, network)
+    }
+);
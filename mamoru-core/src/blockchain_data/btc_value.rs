@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use bitcoin::{Address, Network, Script};
+
+/// Classifies a Bitcoin output script.
+///
+/// Returns one of `p2pk`, `p2pkh`, `p2sh`, `p2wpkh`, `p2wsh`, `p2tr`,
+/// `multisig`, `op_return` or `nonstandard`.
+pub fn classify_btc_script(script: &[u8]) -> &'static str {
+    let script = Script::from_bytes(script);
+
+    if script.is_p2pkh() {
+        "p2pkh"
+    } else if script.is_p2sh() {
+        "p2sh"
+    } else if script.is_v0_p2wpkh() {
+        "p2wpkh"
+    } else if script.is_v0_p2wsh() {
+        "p2wsh"
+    } else if script.is_v1_p2tr() {
+        "p2tr"
+    } else if script.is_p2pk() {
+        "p2pk"
+    } else if is_multisig(script.as_bytes()) {
+        "multisig"
+    } else if script.is_op_return() {
+        "op_return"
+    } else {
+        "nonstandard"
+    }
+}
+
+// `OP_M <pubkeys> OP_N OP_CHECKMULTISIG`
+fn is_multisig(script: &[u8]) -> bool {
+    const OP_1: u8 = 0x51;
+    const OP_16: u8 = 0x60;
+    const OP_CHECKMULTISIG: u8 = 0xae;
+
+    match script {
+        [m, .., n, OP_CHECKMULTISIG] => (OP_1..=OP_16).contains(m) && (OP_1..=OP_16).contains(n),
+        _ => false,
+    }
+}
+
+/// Derives the address of a Bitcoin output script.
+/// The `network` is one of `bitcoin`, `testnet`, `signet` or `regtest`.
+///
+/// Returns `None` if the script has no address form or the network is unknown.
+pub fn derive_btc_address(script: &[u8], network: &str) -> Option<String> {
+    let network = Network::from_str(network).ok()?;
+    let script = Script::from_bytes(script);
+
+    Address::from_script(script, network)
+        .ok()
+        .map(|address| address.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify_btc_script, derive_btc_address};
+
+    #[test]
+    fn classify_btc_script_ok() {
+        let cases = [
+            (
+                "76a91462e907b15cbf27d5425399ebf6f0fb50ebb88f1888ac",
+                "p2pkh",
+            ),
+            ("a914748284390f9e263a4b766a75d0633c50426eb87587", "p2sh"),
+            ("0014751e76e8199196d454941c45d1b3a323f1433bd6", "p2wpkh"),
+            (
+                "00201863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262",
+                "p2wsh",
+            ),
+            (
+                "5120a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c",
+                "p2tr",
+            ),
+            ("6a0548656c6c6f", "op_return"),
+            (
+                "5121020000000000000000000000000000000000000000000000000000000000000001\
+                 21020000000000000000000000000000000000000000000000000000000000000002\
+                 52ae",
+                "multisig",
+            ),
+            ("51", "nonstandard"),
+        ];
+
+        for (script, expected) in cases {
+            assert_eq!(classify_btc_script(&hex::decode(script).unwrap()), expected);
+        }
+    }
+
+    #[test]
+    fn derive_btc_address_ok() {
+        let p2pkh = hex::decode("76a91462e907b15cbf27d5425399ebf6f0fb50ebb88f1888ac").unwrap();
+        let p2wpkh = hex::decode("0014751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        let op_return = hex::decode("6a0548656c6c6f").unwrap();
+
+        assert_eq!(
+            derive_btc_address(&p2pkh, "bitcoin"),
+            Some("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".to_string())
+        );
+        assert_eq!(
+            derive_btc_address(&p2wpkh, "bitcoin"),
+            Some("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4".to_string())
+        );
+        assert_eq!(
+            derive_btc_address(&p2wpkh, "testnet"),
+            Some("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".to_string())
+        );
+        assert_eq!(derive_btc_address(&op_return, "bitcoin"), None);
+        assert_eq!(derive_btc_address(&p2pkh, "unknown-network"), None);
+    }
+}
@@ -0,0 +1,270 @@
+use datafusion::arrow::{
+    array::{
+        ArrayBuilder, BinaryBuilder, BooleanBuilder, Decimal128Builder, LargeBinaryBuilder,
+        ListBuilder, PrimitiveBuilder, StringBuilder,
+    },
+    datatypes::{
+        DataType, Field, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+        UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+    },
+};
+
+use crate::ValueData;
+
+/// A Rust type that can be stored in an Arrow column.
+///
+/// Used by `blockchain-data-derive` when `#[schema(type = ...)]` is omitted,
+/// the derive macro also implements it for structs, so they can be nested as Arrow `Struct`.
+pub trait ArrowColumn: Sized {
+    type Builder: ArrayBuilder;
+
+    /// The Arrow type of the column.
+    fn data_type() -> DataType;
+
+    /// If the column may contain nulls.
+    fn nullable() -> bool {
+        false
+    }
+
+    /// The struct name of a nested `Struct` column, or of the items of a `List` column,
+    /// stored in the field metadata under [`crate::SCHEMA_STRUCT_KEY`] for the SDK generators.
+    fn struct_name() -> Option<&'static str> {
+        None
+    }
+
+    fn builder(capacity: usize) -> Self::Builder;
+
+    fn append(builder: &mut Self::Builder, value: Self);
+
+    fn append_null(builder: &mut Self::Builder);
+}
+
+/// Marks types that can be stored in an Arrow `List` column, see `impl ArrowColumn for Vec<T>`.
+///
+/// Not implemented for `u8`, as `Vec<u8>` is stored as `Binary`.
+pub trait ArrowListItem: ArrowColumn {}
+
+macro_rules! primitive_column {
+    ($($rust:ty => $arrow:ty, $data_type:expr;)*) => {
+        $(
+            impl ArrowColumn for $rust {
+                type Builder = PrimitiveBuilder<$arrow>;
+
+                fn data_type() -> DataType {
+                    $data_type
+                }
+
+                fn builder(capacity: usize) -> Self::Builder {
+                    PrimitiveBuilder::with_capacity(capacity)
+                }
+
+                fn append(builder: &mut Self::Builder, value: Self) {
+                    builder.append_value(value);
+                }
+
+                fn append_null(builder: &mut Self::Builder) {
+                    builder.append_null();
+                }
+            }
+        )*
+    };
+}
+
+primitive_column! {
+    u8 => UInt8Type, DataType::UInt8;
+    u16 => UInt16Type, DataType::UInt16;
+    u32 => UInt32Type, DataType::UInt32;
+    u64 => UInt64Type, DataType::UInt64;
+    i8 => Int8Type, DataType::Int8;
+    i16 => Int16Type, DataType::Int16;
+    i32 => Int32Type, DataType::Int32;
+    i64 => Int64Type, DataType::Int64;
+    f32 => Float32Type, DataType::Float32;
+    f64 => Float64Type, DataType::Float64;
+}
+
+impl ArrowListItem for u16 {}
+impl ArrowListItem for u32 {}
+impl ArrowListItem for u64 {}
+impl ArrowListItem for i8 {}
+impl ArrowListItem for i16 {}
+impl ArrowListItem for i32 {}
+impl ArrowListItem for i64 {}
+impl ArrowListItem for f32 {}
+impl ArrowListItem for f64 {}
+
+/// `Decimal128(38, 0)`, so SQL queries compare it as a number.
+impl ArrowColumn for i128 {
+    type Builder = Decimal128Builder;
+
+    fn data_type() -> DataType {
+        DataType::Decimal128(38, 0)
+    }
+
+    fn builder(capacity: usize) -> Self::Builder {
+        Decimal128Builder::with_capacity(capacity).with_data_type(Self::data_type())
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) {
+        builder.append_value(value);
+    }
+
+    fn append_null(builder: &mut Self::Builder) {
+        builder.append_null();
+    }
+}
+
+impl ArrowListItem for i128 {}
+
+impl ArrowColumn for bool {
+    type Builder = BooleanBuilder;
+
+    fn data_type() -> DataType {
+        DataType::Boolean
+    }
+
+    fn builder(capacity: usize) -> Self::Builder {
+        BooleanBuilder::with_capacity(capacity)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) {
+        builder.append_value(value);
+    }
+
+    fn append_null(builder: &mut Self::Builder) {
+        builder.append_null();
+    }
+}
+
+impl ArrowListItem for bool {}
+
+impl ArrowColumn for String {
+    type Builder = StringBuilder;
+
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+
+    fn builder(capacity: usize) -> Self::Builder {
+        StringBuilder::with_capacity(capacity, capacity * 32)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) {
+        builder.append_value(value);
+    }
+
+    fn append_null(builder: &mut Self::Builder) {
+        builder.append_null();
+    }
+}
+
+impl ArrowListItem for String {}
+
+impl ArrowColumn for Vec<u8> {
+    type Builder = BinaryBuilder;
+
+    fn data_type() -> DataType {
+        DataType::Binary
+    }
+
+    fn builder(capacity: usize) -> Self::Builder {
+        BinaryBuilder::with_capacity(capacity, capacity * 32)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) {
+        builder.append_value(value);
+    }
+
+    fn append_null(builder: &mut Self::Builder) {
+        builder.append_null();
+    }
+}
+
+impl ArrowListItem for Vec<u8> {}
+
+/// `LargeBinary`, the same as the explicit `#[schema(type = "DataType::LargeBinary")]` columns.
+impl ArrowColumn for ValueData {
+    type Builder = LargeBinaryBuilder;
+
+    fn data_type() -> DataType {
+        DataType::LargeBinary
+    }
+
+    fn builder(capacity: usize) -> Self::Builder {
+        LargeBinaryBuilder::with_capacity(capacity, capacity * 32)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) {
+        builder.append_value(value);
+    }
+
+    fn append_null(builder: &mut Self::Builder) {
+        builder.append_null();
+    }
+}
+
+impl ArrowListItem for ValueData {}
+
+impl<T: ArrowColumn> ArrowColumn for Option<T> {
+    type Builder = T::Builder;
+
+    fn data_type() -> DataType {
+        T::data_type()
+    }
+
+    fn nullable() -> bool {
+        true
+    }
+
+    fn struct_name() -> Option<&'static str> {
+        T::struct_name()
+    }
+
+    fn builder(capacity: usize) -> Self::Builder {
+        T::builder(capacity)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) {
+        match value {
+            Some(value) => T::append(builder, value),
+            None => T::append_null(builder),
+        }
+    }
+
+    fn append_null(builder: &mut Self::Builder) {
+        T::append_null(builder);
+    }
+}
+
+impl<T: ArrowListItem> ArrowListItem for Option<T> {}
+
+impl<T: ArrowListItem> ArrowColumn for Vec<T> {
+    type Builder = ListBuilder<T::Builder>;
+
+    fn data_type() -> DataType {
+        // `ListBuilder` always marks the items as nullable
+        DataType::List(Field::new("item", T::data_type(), true).into())
+    }
+
+    fn struct_name() -> Option<&'static str> {
+        T::struct_name()
+    }
+
+    fn builder(capacity: usize) -> Self::Builder {
+        ListBuilder::with_capacity(T::builder(capacity), capacity)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) {
+        for item in value {
+            T::append(builder.values(), item);
+        }
+
+        builder.append(true);
+    }
+
+    fn append_null(builder: &mut Self::Builder) {
+        builder.append(false);
+    }
+}
+
+impl<T: ArrowListItem> ArrowListItem for Vec<T> {}
@@ -0,0 +1,167 @@
+use blake2::{digest::consts::U32, Blake2b};
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use k256::elliptic_curve::scalar::IsHigh;
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+
+/// EVM address size in bytes.
+const ADDRESS_SIZE: usize = 20;
+
+/// EVM function selector size in bytes.
+const SELECTOR_SIZE: usize = 4;
+
+pub(crate) fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+pub(crate) fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+/// BLAKE2b with 32 bytes output, as used for Sui addresses.
+pub(crate) fn blake2b(data: &[u8]) -> [u8; 32] {
+    Blake2b::<U32>::digest(data).into()
+}
+
+/// Recovers the EVM address that signed the 32 bytes `hash`.
+/// The signature is 65 bytes `r || s || v`, `v` is either `0/1` or `27/28`.
+/// High-S signatures are accepted, same as the EVM `ecrecover` precompile does.
+///
+/// Returns `None` if the signature is invalid.
+pub(crate) fn ecrecover(hash: &[u8], signature: &[u8]) -> Option<[u8; ADDRESS_SIZE]> {
+    if hash.len() != 32 || signature.len() != 65 {
+        return None;
+    }
+
+    let v = signature[64];
+    let mut recovery_id = RecoveryId::from_byte(if v >= 27 { v - 27 } else { v })?;
+    let mut signature = Signature::from_slice(&signature[..64]).ok()?;
+
+    // `recover_from_prehash` accepts low-S signatures only.
+    // `(r, n - s)` is a signature of the same key with the opposite `R.y` parity.
+    let (r, s) = signature.split_scalars();
+
+    if bool::from(s.is_high()) {
+        signature = Signature::from_scalars(r, -s).ok()?;
+        recovery_id = RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced());
+    }
+
+    let key = VerifyingKey::recover_from_prehash(hash, &signature, recovery_id).ok()?;
+    let point = key.to_encoded_point(false);
+
+    // Skip the `0x04` uncompressed point tag
+    let hash = keccak256(&point.as_bytes()[1..]);
+    let mut address = [0; ADDRESS_SIZE];
+    address.copy_from_slice(&hash[hash.len() - ADDRESS_SIZE..]);
+
+    Some(address)
+}
+
+/// The first 4 bytes of the function signature hash, e.g. `transfer(address,uint256)`.
+pub(crate) fn evm_selector(signature: &str) -> [u8; SELECTOR_SIZE] {
+    let signature: String = signature.chars().filter(|c| !c.is_whitespace()).collect();
+    let hash = keccak256(signature.as_bytes());
+
+    let mut selector = [0; SELECTOR_SIZE];
+    selector.copy_from_slice(&hash[..SELECTOR_SIZE]);
+
+    selector
+}
+
+/// Formats an EVM address as EIP-55 mixed-case checksum string.
+///
+/// Returns `None` if `address` is not a hex string of 20 bytes.
+pub(crate) fn evm_checksum_address(address: &str) -> Option<String> {
+    let address = address.trim_start_matches("0x").to_lowercase();
+
+    if address.len() != ADDRESS_SIZE * 2 || hex::decode(&address).is_err() {
+        return None;
+    }
+
+    let hash = hex::encode(keccak256(address.as_bytes()));
+
+    let checksummed: String = address
+        .chars()
+        .zip(hash.chars())
+        .map(|(c, h)| match h {
+            '8'..='9' | 'a'..='f' => c.to_ascii_uppercase(),
+            _ => c,
+        })
+        .collect();
+
+    Some(format!("0x{}", checksummed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes() {
+        assert_eq!(
+            hex::encode(keccak256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(sha256(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex::encode(blake2b(b"")),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+    }
+
+    #[test]
+    fn selector() {
+        assert_eq!(
+            hex::encode(evm_selector("transfer(address, uint256)")),
+            "a9059cbb"
+        );
+    }
+
+    #[test]
+    fn checksum_address() {
+        assert_eq!(
+            evm_checksum_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").as_deref(),
+            Some("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed")
+        );
+        assert_eq!(evm_checksum_address("0x5aaeb6053f3e"), None);
+    }
+
+    #[test]
+    fn recover() {
+        let hash = keccak256(b"mamoru");
+        let key = k256::ecdsa::SigningKey::from_slice(&[1; 32]).unwrap();
+        let (signature, recovery_id) = key.sign_prehash_recoverable(&hash).unwrap();
+
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(recovery_id.to_byte() + 27);
+
+        let public = key.verifying_key().to_encoded_point(false);
+        let expected = &keccak256(&public.as_bytes()[1..])[12..];
+
+        assert_eq!(ecrecover(&hash, &bytes).unwrap(), expected);
+        assert_eq!(ecrecover(&hash, &bytes[..64]), None);
+    }
+
+    #[test]
+    fn recover_high_s() {
+        let hash = keccak256(b"mamoru");
+        let key = k256::ecdsa::SigningKey::from_slice(&[1; 32]).unwrap();
+        let (signature, recovery_id) = key.sign_prehash_recoverable(&hash).unwrap();
+
+        // The signer produces low-S signatures, `(r, n - s)` with the flipped parity is the high-S one.
+        let (r, s) = signature.split_scalars();
+        let high_s = Signature::from_scalars(r, -s).unwrap();
+        assert!(bool::from(high_s.s().is_high()));
+
+        let mut bytes = high_s.to_bytes().to_vec();
+        bytes.push((recovery_id.to_byte() ^ 1) + 27);
+
+        let public = key.verifying_key().to_encoded_point(false);
+        let expected = &keccak256(&public.as_bytes()[1..])[12..];
+
+        assert_eq!(ecrecover(&hash, &bytes).unwrap(), expected);
+    }
+}
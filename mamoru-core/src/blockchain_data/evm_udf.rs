@@ -5,7 +5,10 @@ use datafusion::arrow::datatypes::DataType;
 use ethabi::Token;
 use tracing::warn;
 
-use crate::blockchain_data::evm_value::{parse_evm_tx_input, TxInput};
+use crate::blockchain_data::crypto;
+use crate::blockchain_data::evm_value::{
+    decode_revert_reason, parse_evm_event, parse_evm_tx_input, TxInput,
+};
 use crate::{deserialize_data, serialize_data, udf};
 
 udf!(
@@ -27,6 +30,29 @@ udf!(
     }
 );
 
+udf!(
+    evm_parse_event,
+    (BinaryArray DataType::Binary),
+    [
+        0 => topic0: (BinaryArray DataType::Binary),
+        1 => topic1: (BinaryArray DataType::Binary),
+        2 => topic2: (BinaryArray DataType::Binary),
+        3 => topic3: (BinaryArray DataType::Binary),
+        4 => data: (BinaryArray DataType::Binary),
+        5 => abi: (StringArray DataType::Utf8),
+    ],
+    |topic0, topic1, topic2, topic3, data, abi| {
+        match parse_evm_event(abi, &[topic0, topic1, topic2, topic3], data) {
+            Ok(result) => result.map(|event| serialize_data(&event.0)),
+            Err(err) => {
+                warn!("Error while parsing EVM event: {}", err);
+
+                None
+            }
+        }
+    }
+);
+
 udf!(
     evm_take_token,
     (BinaryArray DataType::Binary),
@@ -105,6 +131,17 @@ udf!(
     }
 );
 
+udf!(
+    evm_decode_revert,
+    (StringArray DataType::Utf8),
+    [
+        0 => output: (BinaryArray DataType::Binary),
+    ],
+    |output| {
+        decode_revert_reason(output)
+    }
+);
+
 udf!(
     evm_as_string,
     (StringArray DataType::Utf8),
@@ -182,3 +219,25 @@ udf!(
         token.into_tuple().map(|tokens| serialize_data(&tokens))
     }
 );
+
+udf!(
+    evm_selector,
+    (BinaryArray DataType::Binary),
+    [
+        0 => signature: (StringArray DataType::Utf8),
+    ],
+    |signature| {
+        Some(crypto::evm_selector(signature))
+    }
+);
+
+udf!(
+    evm_checksum_address,
+    (StringArray DataType::Utf8),
+    [
+        0 => address: (StringArray DataType::Utf8),
+    ],
+    |address| {
+        crypto::evm_checksum_address(address)
+    }
+);
@@ -1,6 +1,6 @@
 use ethabi::param_type::Reader;
 pub(crate) use ethabi::Token;
-use ethabi::{decode, short_signature, ParamType};
+use ethabi::{decode, long_signature, short_signature, ParamType};
 pub use ethnum::{i256, u256};
 
 use crate::errors::ParseEvmInputError;
@@ -73,6 +73,170 @@ pub(crate) fn parse_evm_tx_input(
     Ok(Some(TxInput::new(tokens)))
 }
 
+/// Parses an EVM event log into a list of tokens in the order of the ABI parameters.
+/// The ABI format is `Name(type1 indexed,type2)`, `indexed` marks the parameters stored in topics.
+///
+/// Indexed parameters of reference types (strings, bytes, arrays and tuples) are stored
+/// as keccak256 hashes in topics, so they are returned as [`Token::FixedBytes`].
+///
+/// Returns `None` if the log does not match the given ABI signature.
+pub(crate) fn parse_evm_event(
+    abi: &str,
+    topics: &[&[u8]],
+    data: &[u8],
+) -> Result<Option<TxInput>, ParseEvmInputError> {
+    let (name, parameters) = parse_eth_signature(abi).ok_or_else(|| {
+        ParseEvmInputError::ParseABI(format!(
+            "Invalid ABI signature: \"{}\". The correct format is \"Name(type1 indexed,type2)\"",
+            abi
+        ))
+    })?;
+
+    let parameters = parse_event_parameters(parameters)?;
+    let types: Vec<ParamType> = parameters.iter().map(|(ty, _)| ty.clone()).collect();
+
+    let (signature, indexed_topics) = match topics.split_first() {
+        Some((signature, rest)) => (*signature, rest),
+        None => return Ok(None),
+    };
+
+    if long_signature(name, &types).as_bytes() != signature {
+        return Ok(None);
+    }
+
+    // Missing topics are passed as empty slices.
+    let indexed_topics: Vec<&[u8]> = indexed_topics
+        .iter()
+        .copied()
+        .filter(|topic| !topic.is_empty())
+        .collect();
+
+    let indexed_count = parameters.iter().filter(|(_, indexed)| *indexed).count();
+
+    // The same signature may be shared by events with different indexed parameters,
+    // e.g. ERC-20 and ERC-721 `Transfer(address,address,uint256)`.
+    if indexed_count != indexed_topics.len() {
+        return Ok(None);
+    }
+
+    let data_types: Vec<ParamType> = parameters
+        .iter()
+        .filter(|(_, indexed)| !*indexed)
+        .map(|(ty, _)| ty.clone())
+        .collect();
+
+    let mut data_tokens = decode(&data_types, data)
+        .map_err(ParseEvmInputError::Decode)?
+        .into_iter();
+    let mut indexed_topics = indexed_topics.into_iter();
+
+    let mut tokens = Vec::with_capacity(parameters.len());
+
+    for (ty, indexed) in parameters {
+        let token = if indexed {
+            let topic = indexed_topics
+                .next()
+                .expect("BUG: the number of topics is checked before.");
+
+            decode_topic(ty, topic)?
+        } else {
+            data_tokens
+                .next()
+                .expect("BUG: the number of data tokens is checked by `decode`.")
+        };
+
+        tokens.push(token);
+    }
+
+    Ok(Some(TxInput::new(tokens)))
+}
+
+fn decode_topic(ty: ParamType, topic: &[u8]) -> Result<Token, ParseEvmInputError> {
+    match ty {
+        ParamType::String
+        | ParamType::Bytes
+        | ParamType::Array(_)
+        | ParamType::FixedArray(_, _)
+        | ParamType::Tuple(_) => Ok(Token::FixedBytes(topic.to_vec())),
+        ty => {
+            let mut tokens = decode(&[ty], topic).map_err(ParseEvmInputError::Decode)?;
+
+            Ok(tokens.pop().expect("BUG: a single type is decoded."))
+        }
+    }
+}
+
+/// Parses event parameters in the format `(type1 indexed,type2)`.
+/// Returns parameter types with `indexed` flags.
+fn parse_event_parameters(parameters: &str) -> Result<Vec<(ParamType, bool)>, ParseEvmInputError> {
+    let inner = parameters
+        .trim()
+        .strip_prefix('(')
+        .and_then(|p| p.strip_suffix(')'))
+        .ok_or_else(|| {
+            ParseEvmInputError::ParseABI(format!("Invalid event parameters: \"{}\"", parameters))
+        })?;
+
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (idx, ch) in inner.char_indices() {
+        match ch {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                result.push(parse_event_parameter(&inner[start..idx])?);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if !inner.trim().is_empty() {
+        result.push(parse_event_parameter(&inner[start..])?);
+    }
+
+    Ok(result)
+}
+
+fn parse_event_parameter(parameter: &str) -> Result<(ParamType, bool), ParseEvmInputError> {
+    let parameter = parameter.trim();
+
+    let (ty, indexed) = match parameter.strip_suffix("indexed") {
+        Some(ty) if ty.ends_with(char::is_whitespace) => (ty.trim_end(), true),
+        _ => (parameter, false),
+    };
+
+    let ty = Reader::read(ty).map_err(ParseEvmInputError::ReadParameters)?;
+
+    Ok((ty, indexed))
+}
+
+/// Decodes the revert reason from the output of a failed EVM call.
+/// Supports `Error(string)` and `Panic(uint256)`, panics are formatted as `Panic(0x11)`.
+///
+/// Returns `None` for empty outputs and custom errors.
+pub fn decode_revert_reason(output: &[u8]) -> Option<String> {
+    if output.len() < 4 {
+        return None;
+    }
+
+    let (selector, data) = output.split_at(4);
+
+    if selector == short_signature("Error", &[ParamType::String]) {
+        let token = decode(&[ParamType::String], data).ok()?.pop()?;
+
+        token.into_string()
+    } else if selector == short_signature("Panic", &[ParamType::Uint(256)]) {
+        let token = decode(&[ParamType::Uint(256)], data).ok()?.pop()?;
+
+        token.into_uint().map(|code| format!("Panic({:#x})", code))
+    } else {
+        None
+    }
+}
+
 /// Parses a function signature in the format `name(type1,type2)`.
 fn parse_eth_signature(signature: &str) -> Option<(&str, &str)> {
     let open_paren_index = signature.find('(')?;
@@ -134,11 +298,11 @@ pub(crate) mod int256 {
 mod tests {
     use base64::{engine::general_purpose, Engine as _};
     use ethabi::ethereum_types::Address;
-    use ethabi::Token;
+    use ethabi::{ParamType, Token};
 
     use crate::blockchain_data::evm_value::TxInput;
 
-    use super::parse_eth_signature;
+    use super::{decode_revert_reason, parse_eth_signature, parse_event_parameters};
 
     #[test]
     fn parse_eth_signature_ok() {
@@ -174,6 +338,67 @@ mod tests {
         assert_eq!(parse_eth_signature("test("), None);
     }
 
+    #[test]
+    fn parse_event_parameters_ok() {
+        assert_eq!(
+            parse_event_parameters("(address indexed,address indexed,uint256)").unwrap(),
+            vec![
+                (ParamType::Address, true),
+                (ParamType::Address, true),
+                (ParamType::Uint(256), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_event_parameters_tuple() {
+        assert_eq!(
+            parse_event_parameters("(uint256 indexed,(string,bool),bytes32[2] indexed)").unwrap(),
+            vec![
+                (ParamType::Uint(256), true),
+                (
+                    ParamType::Tuple(vec![ParamType::String, ParamType::Bool]),
+                    false
+                ),
+                (
+                    ParamType::FixedArray(Box::new(ParamType::FixedBytes(32)), 2),
+                    true
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_event_parameters_empty() {
+        assert_eq!(parse_event_parameters("()").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn decode_revert_reason_ok() {
+        let error = [
+            ethabi::short_signature("Error", &[ParamType::String]).to_vec(),
+            ethabi::encode(&[Token::String("Not enough balance".to_string())]),
+        ]
+        .concat();
+
+        let panic = [
+            ethabi::short_signature("Panic", &[ParamType::Uint(256)]).to_vec(),
+            ethabi::encode(&[Token::Uint(0x11.into())]),
+        ]
+        .concat();
+
+        assert_eq!(
+            decode_revert_reason(&error),
+            Some("Not enough balance".to_string())
+        );
+        assert_eq!(
+            decode_revert_reason(&panic),
+            Some("Panic(0x11)".to_string())
+        );
+        assert_eq!(decode_revert_reason(&[]), None);
+        assert_eq!(decode_revert_reason(&[1, 2, 3, 4, 5]), None);
+    }
+
     // cargo test -p mamoru-core evm_value::tests::generate_test_tx_input -- --ignored --nocapture
     #[test]
     #[ignore]
@@ -3,14 +3,22 @@ use std::sync::Arc;
 
 use datafusion::{
     arrow::{datatypes::Schema, error::ArrowError, record_batch::RecordBatch},
+    execution::context::SessionState,
     prelude::SessionContext,
 };
 
-use crate::DataError;
+use crate::{DataError, TableSchema, ValueData};
 
+pub mod as_sdk;
+pub mod column;
+pub mod rust_sdk;
+pub mod schema;
 pub mod serialize;
 pub mod value;
 
+mod btc_udf;
+pub(crate) mod btc_value;
+pub(crate) mod crypto;
 mod evm_udf;
 pub(crate) mod evm_value;
 mod udf;
@@ -46,6 +54,31 @@ pub trait BlockchainCtx: Sync + Send + 'static {
 
     /// All tables that will be inserted into Arrow.
     fn as_tables(&self) -> Vec<Box<dyn BlockchainTableItem>>;
+
+    /// Describes the [`CtxImportFn::ById`] imports of [`BlockchainCtx::imports`],
+    /// so the SDK generators can add getters for the columns skipped by serde.
+    fn by_id_imports() -> Vec<ByIdImport>
+    where
+        Self: Sized,
+    {
+        vec![]
+    }
+
+    /// Called once by [`BlockchainDataBuilder::build`] before the tables are inserted into Arrow.
+    /// Override it to fill tables derived from the raw data.
+    fn finalize(&mut self) {}
+
+    /// Describes all tables of the context, see [`BlockchainCtx::as_tables`].
+    fn schema() -> Vec<TableSchema>
+    where
+        Self: Sized,
+    {
+        Self::empty()
+            .as_tables()
+            .iter()
+            .map(|table| TableSchema::from_table(table.as_ref()))
+            .collect()
+    }
 }
 
 /// A function that will be called from WASM to retrieve data.
@@ -61,12 +94,55 @@ pub enum CtxImportFn<T> {
     ById(fn(&T, u64) -> Result<&[u8], CtxImportError>),
 }
 
+/// A [`CtxImportFn::ById`] import returning a single column of a table row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByIdImport {
+    /// The import name, e.g. `get_call_trace_arg_by_id`.
+    pub import: &'static str,
+    pub table: &'static str,
+    /// The column the row is found by.
+    pub key: &'static str,
+    /// The returned column.
+    pub value: &'static str,
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum CtxImportError {
     #[error("Failed to retrieve data: {0}")]
     ById(String),
 }
 
+/// A value returned from [`CtxImportFn::ById`] imports generated by `#[derive(BlockchainCtx)]`.
+pub trait ByIdValue {
+    /// `None` fails the import.
+    fn by_id_bytes(&self) -> Option<&[u8]>;
+}
+
+/// MessagePack `nil`, returned for `None` by-id values.
+const MSGPACK_NIL: &[u8] = &[0xc0];
+
+impl ByIdValue for ValueData {
+    fn by_id_bytes(&self) -> Option<&[u8]> {
+        Some(self.as_ref())
+    }
+}
+
+impl ByIdValue for Vec<u8> {
+    fn by_id_bytes(&self) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+/// `None`, e.g. a deleted resource, is returned as MessagePack `nil`.
+impl<T: ByIdValue> ByIdValue for Option<T> {
+    fn by_id_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Some(value) => value.by_id_bytes(),
+            None => Some(MSGPACK_NIL),
+        }
+    }
+}
+
 pub type BlockchainSpecificImports<T> = HashMap<&'static str, CtxImportFn<T>>;
 
 pub struct BlockchainDataBuilder<T> {
@@ -136,7 +212,9 @@ impl<T: BlockchainCtx> BlockchainDataBuilder<T> {
         });
     }
 
-    pub fn build(self) -> Result<BlockchainData<T>, DataError> {
+    pub fn build(mut self) -> Result<BlockchainData<T>, DataError> {
+        self.data.finalize();
+
         let session = setup_session();
 
         for table in self.data.as_tables() {
@@ -223,7 +301,17 @@ impl<T> BlockchainData<T> {
         &self.session
     }
 
-    pub(crate) fn data(&self) -> &T {
+    /// The session state with `report_full_v2` registered,
+    /// only the daemons supporting incident v2 may call it.
+    pub(crate) fn session_state_v2(&self) -> SessionState {
+        let session = SessionContext::with_state(self.session.state());
+        session.register_udf(udf::report_full_v2());
+
+        session.state()
+    }
+
+    /// The blockchain data, e.g. for [`NativeDaemon`](crate::NativeDaemon).
+    pub fn data(&self) -> &T {
         &self.data
     }
 }
@@ -240,6 +328,9 @@ fn setup_session() -> SessionContext {
     session.register_udf(udf::struct_field());
     session.register_udf(udf::bytes_to_hex());
     session.register_udf(udf::hex_to_bytes());
+    session.register_udf(udf::base58_encode());
+    session.register_udf(udf::base58_decode());
+    session.register_udf(udf::keccak256());
 
     session.register_udf(udf::u256_from_str());
     session.register_udf(udf::u256_eq());
@@ -266,6 +357,8 @@ fn setup_session() -> SessionContext {
     session.register_udf(udf::i256_pow());
 
     session.register_udf(evm_udf::evm_parse_tx_input());
+    session.register_udf(evm_udf::evm_parse_event());
+    session.register_udf(evm_udf::evm_decode_revert());
     session.register_udf(evm_udf::evm_take_token());
     session.register_udf(evm_udf::evm_as_boolean());
     session.register_udf(evm_udf::evm_as_address());
@@ -277,6 +370,11 @@ fn setup_session() -> SessionContext {
     session.register_udf(evm_udf::evm_as_array());
     session.register_udf(evm_udf::evm_as_fixed_array());
     session.register_udf(evm_udf::evm_as_tuple());
+    session.register_udf(evm_udf::evm_selector());
+    session.register_udf(evm_udf::evm_checksum_address());
+
+    session.register_udf(btc_udf::btc_script_type());
+    session.register_udf(btc_udf::btc_script_to_address());
 
     session
 }
@@ -0,0 +1,216 @@
+use std::fmt::Write;
+
+use datafusion::arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+
+use crate::{
+    BlockchainCtx, ByIdImport, CodegenError, SCHEMA_DOC_KEY, SCHEMA_SKIP_SERIALIZING_KEY,
+    SCHEMA_STRUCT_KEY,
+};
+
+/// Rust keywords that can be used as field names only as raw identifiers.
+const KEYWORDS: &[&str] = &[
+    "as", "box", "const", "fn", "impl", "in", "let", "loop", "match", "mod", "move", "ref",
+    "static", "struct", "trait", "type", "use", "where",
+];
+
+/// Generates a `mamoru-daemon-sdk` module with structs decoding the tables of `T`
+/// passed to WASM via `get_<table>` imports.
+///
+/// Same as [`crate::generate_as_sdk`], the struct names, field order and doc comments
+/// are taken from the schema metadata generated by `blockchain-data-derive`,
+/// the fields read with a by-id import become methods returning `mamoru_daemon_sdk::ChainValue`.
+pub fn generate_rust_sdk<T: BlockchainCtx>() -> Result<String, CodegenError> {
+    let tables: Vec<_> = T::empty()
+        .as_tables()
+        .iter()
+        .map(|table| (table.table_name(), table.schema()))
+        .collect();
+    let by_id_imports = T::by_id_imports();
+
+    let mut structs = vec![];
+    let mut imports = vec![];
+
+    for (table_name, schema) in &tables {
+        let by_id: Vec<_> = by_id_imports
+            .iter()
+            .filter(|by_id| by_id.table == *table_name)
+            .collect();
+
+        structs.push(generate_struct(table_name, schema, &by_id)?);
+        imports.push(format!("    fn get_{}() -> u64;\n", table_name));
+        imports.extend(
+            by_id
+                .iter()
+                .map(|by_id| format!("    fn {}(id: u64) -> u64;\n", by_id.import)),
+        );
+    }
+
+    let mut out = String::new();
+
+    out.push_str("// This file is generated from the Rust types by `mamoru_core::generate_rust_sdk`, do not edit it.\n");
+    out.push_str("// Run the SDK tests with `UPDATE_EXPECT=1` to update it.\n");
+    out.push_str("use serde::Deserialize;\n\n");
+    writeln!(out, "#[link(wasm_import_module = \"{}\")]", T::module()).unwrap();
+    out.push_str("extern \"C\" {\n");
+
+    for import in imports {
+        out.push_str(&import);
+    }
+
+    out.push_str("}\n");
+
+    for item in structs {
+        out.push('\n');
+        out.push_str(&item);
+    }
+
+    Ok(out)
+}
+
+/// A column serialized for WASM.
+struct Column<'a> {
+    field: &'a Field,
+    /// Rust type, `Option` included.
+    rust_type: String,
+}
+
+impl Column<'_> {
+    fn ident(&self) -> String {
+        ident(self.field.name())
+    }
+}
+
+fn ident(name: &str) -> String {
+    if KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn generate_struct(
+    table_name: &str,
+    schema: &Schema,
+    by_id: &[&ByIdImport],
+) -> Result<String, CodegenError> {
+    let name = schema.metadata().get(SCHEMA_STRUCT_KEY).ok_or_else(|| {
+        CodegenError::MissingStructName {
+            table: table_name.to_string(),
+        }
+    })?;
+
+    let columns = schema
+        .fields()
+        .iter()
+        .filter(|field| !field.metadata().contains_key(SCHEMA_SKIP_SERIALIZING_KEY))
+        .map(|field| column(table_name, field))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut out = String::new();
+
+    if let Some(doc) = schema.metadata().get(SCHEMA_DOC_KEY) {
+        writeln!(out, "/// {}", doc).unwrap();
+    }
+
+    out.push_str("#[derive(Debug, Clone, PartialEq, Deserialize)]\n");
+    writeln!(out, "pub struct {} {{", name).unwrap();
+
+    for column in &columns {
+        if let Some(doc) = column.field.metadata().get(SCHEMA_DOC_KEY) {
+            writeln!(out, "    /// {}", doc).unwrap();
+        }
+
+        writeln!(out, "    pub {}: {},", column.ident(), column.rust_type).unwrap();
+    }
+
+    out.push_str("}\n\n");
+
+    writeln!(out, "impl {} {{", name).unwrap();
+    writeln!(out, "    /// Loads all rows of the `{}` table.", table_name).unwrap();
+    out.push_str("    pub fn load_all() -> Vec<Self> {\n");
+    writeln!(
+        out,
+        "        crate::abi::decode_packed(unsafe {{ get_{}() }})",
+        table_name
+    )
+    .unwrap();
+    out.push_str("    }\n");
+
+    for field in schema.fields() {
+        if let Some(by_id) = by_id
+            .iter()
+            .find(|by_id| by_id.value == field.name().as_str())
+        {
+            write_by_id_method(&mut out, field, by_id);
+        }
+    }
+
+    out.push_str("}\n");
+
+    Ok(out)
+}
+
+/// The method reading the column skipped by serde with the by-id import.
+fn write_by_id_method(out: &mut String, field: &Field, by_id: &ByIdImport) {
+    let return_type = if field.is_nullable() {
+        "Option<crate::ChainValue>"
+    } else {
+        "crate::ChainValue"
+    };
+
+    out.push('\n');
+
+    if let Some(doc) = field.metadata().get(SCHEMA_DOC_KEY) {
+        writeln!(out, "    /// {}", doc).unwrap();
+    }
+
+    writeln!(
+        out,
+        "    pub fn {}(&self) -> {} {{",
+        ident(field.name()),
+        return_type
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "        crate::abi::decode_packed(unsafe {{ {}(self.{}) }})",
+        by_id.import,
+        ident(by_id.key)
+    )
+    .unwrap();
+    out.push_str("    }\n");
+}
+
+fn column<'a>(table_name: &str, field: &'a Field) -> Result<Column<'a>, CodegenError> {
+    let rust_type = match field.data_type() {
+        DataType::Boolean => "bool",
+        DataType::UInt8 => "u8",
+        DataType::UInt16 => "u16",
+        DataType::UInt32 => "u32",
+        DataType::UInt64 => "u64",
+        DataType::Int8 => "i8",
+        DataType::Int16 => "i16",
+        DataType::Int32 => "i32",
+        DataType::Int64 | DataType::Timestamp(TimeUnit::Second, None) => "i64",
+        DataType::Decimal128(_, 0) => "i128",
+        DataType::Float32 => "f32",
+        DataType::Float64 => "f64",
+        DataType::Utf8 => "String",
+        DataType::Binary | DataType::LargeBinary => "Vec<u8>",
+        _ => {
+            return Err(CodegenError::UnsupportedType {
+                table: table_name.to_string(),
+                column: field.name().to_string(),
+                data_type: field.data_type().to_string(),
+            })
+        }
+    };
+
+    let rust_type = if field.is_nullable() {
+        format!("Option<{}>", rust_type)
+    } else {
+        rust_type.to_string()
+    };
+
+    Ok(Column { field, rust_type })
+}
@@ -0,0 +1,71 @@
+use datafusion::arrow::datatypes::Field;
+use serde::Serialize;
+
+use crate::BlockchainTableItem;
+
+/// The Arrow schema metadata key holding doc comments of tables and columns.
+pub const SCHEMA_DOC_KEY: &str = "doc";
+
+/// The Arrow schema metadata key holding the Rust struct name of a table,
+/// also set in the field metadata of nested struct columns, see [`crate::ArrowColumn::struct_name`].
+pub const SCHEMA_STRUCT_KEY: &str = "struct";
+
+/// The Arrow field metadata key marking columns that are not serialized for WASM.
+pub const SCHEMA_SKIP_SERIALIZING_KEY: &str = "skip_serializing";
+
+/// The Arrow field metadata key holding the deprecated AssemblyScript property name of a column,
+/// see [`crate::generate_as_sdk`].
+pub const SCHEMA_AS_ALIAS_KEY: &str = "as_alias";
+
+/// The Arrow field metadata key holding the AssemblyScript type a column is converted to,
+/// see [`crate::generate_as_sdk`].
+pub const SCHEMA_AS_TYPE_KEY: &str = "as_type";
+
+/// The Arrow field metadata key holding the AssemblyScript function converting a column
+/// to [`SCHEMA_AS_TYPE_KEY`] type, see [`crate::generate_as_sdk`].
+pub const SCHEMA_AS_FROM_KEY: &str = "as_from";
+
+/// Describes a table visible to daemons.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TableSchema {
+    pub name: String,
+    pub doc: Option<String>,
+    pub columns: Vec<ColumnSchema>,
+}
+
+/// Describes a column of [`TableSchema`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ColumnSchema {
+    pub name: String,
+    /// Arrow type name, e.g. `Utf8` or `UInt64`.
+    pub data_type: String,
+    pub nullable: bool,
+    pub doc: Option<String>,
+}
+
+impl TableSchema {
+    pub fn from_table(table: &dyn BlockchainTableItem) -> Self {
+        let schema = table.schema();
+
+        Self {
+            name: table.table_name().to_string(),
+            doc: schema.metadata().get(SCHEMA_DOC_KEY).cloned(),
+            columns: schema
+                .fields()
+                .iter()
+                .map(|field| ColumnSchema::from_field(field))
+                .collect(),
+        }
+    }
+}
+
+impl ColumnSchema {
+    fn from_field(field: &Field) -> Self {
+        Self {
+            name: field.name().to_string(),
+            data_type: field.data_type().to_string(),
+            nullable: field.is_nullable(),
+            doc: field.metadata().get(SCHEMA_DOC_KEY).cloned(),
+        }
+    }
+}
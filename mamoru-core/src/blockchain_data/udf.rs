@@ -5,9 +5,12 @@ use datafusion::arrow::{
     datatypes::DataType,
 };
 use ethnum::{i256, u256};
+use tracing::warn;
 
+use crate::blockchain_data::crypto;
 use crate::blockchain_data::evm_value::{int256, uint256};
 use crate::blockchain_data::value::Value;
+use crate::daemon::incident::IncidentMetadata;
 use crate::{serialize_data, Incident, IncidentSeverity};
 
 #[macro_export]
@@ -103,6 +106,45 @@ udf!(
     }
 );
 
+udf!(
+    report_full_v2,
+    (BinaryArray DataType::Binary),
+    [
+        0 => tx_hash: (StringArray DataType::Utf8),
+        1 => severity: (StringArray DataType::Utf8),
+        2 => message: (StringArray DataType::Utf8),
+        3 => address: (StringArray DataType::Utf8),
+        4 => data: (BinaryArray DataType::Binary),
+        5 => metadata: (StringArray DataType::Utf8),
+    ],
+    |tx_hash: &str, severity: &str, message: &str, address: &str, data: &[u8], metadata: &str| {
+        let mut incident = create_incident(
+            tx_hash.to_string(),
+            severity.to_string(),
+            message.to_string(),
+            Some(address.to_string()),
+            Some(data.to_vec()),
+        )?;
+
+        let metadata: IncidentMetadata = match serde_json::from_str(metadata) {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                warn!("Invalid incident metadata: {}", err);
+
+                return None;
+            }
+        };
+
+        if let Err(err) = incident.attach_metadata(metadata) {
+            warn!("Invalid incident metadata: {}", err);
+
+            return None;
+        }
+
+        Some(serialize_data(&incident))
+    }
+);
+
 fn create_incident(
     tx_hash: String,
     severity: String,
@@ -118,6 +160,7 @@ fn create_incident(
         message,
         address: address.unwrap_or_default(),
         data: data.unwrap_or_default(),
+        metadata: None,
     })
 }
 
@@ -194,6 +237,39 @@ udf!(
     }
 );
 
+udf!(
+    base58_encode,
+    (StringArray DataType::Utf8),
+    [
+        0 => data: (BinaryArray DataType::Binary),
+    ],
+    |data| {
+        Some(bs58::encode(data).into_string())
+    }
+);
+
+udf!(
+    base58_decode,
+    (BinaryArray DataType::Binary),
+    [
+        0 => data: (StringArray DataType::Utf8),
+    ],
+    |data: &str| {
+        bs58::decode(data).into_vec().ok()
+    }
+);
+
+udf!(
+    keccak256,
+    (BinaryArray DataType::Binary),
+    [
+        0 => data: (BinaryArray DataType::Binary),
+    ],
+    |data| {
+        Some(crypto::keccak256(data))
+    }
+);
+
 udf!(
     u256_from_str,
     (BinaryArray DataType::Binary),
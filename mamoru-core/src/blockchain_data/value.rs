@@ -37,6 +37,11 @@ impl ValueData {
             data: value.serialize(),
         })
     }
+
+    /// Deserializes the held [`Value`].
+    pub fn to_value(&self) -> Result<Value, ValueError> {
+        Value::from_slice(&self.data)
+    }
 }
 
 impl AsRef<[u8]> for ValueData {
@@ -72,28 +77,28 @@ impl Value {
 }
 
 impl Value {
-    pub(crate) fn as_bool(&self) -> Option<bool> {
+    pub fn as_bool(&self) -> Option<bool> {
         match self {
             Value::Bool(value) => Some(*value),
             _ => None,
         }
     }
 
-    pub(crate) fn as_u64(&self) -> Option<u64> {
+    pub fn as_u64(&self) -> Option<u64> {
         match self {
             Value::U64(value) => Some(*value),
             _ => None,
         }
     }
 
-    pub(crate) fn as_str(&self) -> Option<&str> {
+    pub fn as_str(&self) -> Option<&str> {
         match self {
             Value::String(value) => Some(value),
             _ => None,
         }
     }
 
-    pub(crate) fn as_struct(&self) -> Option<&StructValue> {
+    pub fn as_struct(&self) -> Option<&StructValue> {
         match self {
             Value::Struct(value) => Some(value),
             _ => None,
@@ -120,6 +125,10 @@ impl StructValue {
     pub fn field_exist(&self, key: &str) -> bool {
         self.fields.get(key).is_some()
     }
+
+    pub fn field(&self, key: &str) -> Option<&Value> {
+        self.fields.get(key)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,53 @@
+use wasmer::{ExportType, Module};
+
+use crate::DataError;
+
+/// The custom section a module may use to declare its ABI explicitly.
+/// The section content is the ABI name: `assemblyscript` or `plain`.
+pub const ABI_SECTION_NAME: &str = "mamoru_abi";
+
+/// The allocator exported by modules using [`WasmAbi::Plain`].
+/// Signature: `mamoru_alloc(size: u32) -> u32`, returns a pointer to `size` bytes owned by the module.
+pub const PLAIN_ALLOC_EXPORT: &str = "mamoru_alloc";
+
+/// How the host and a daemon module pass data to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmAbi {
+    /// AssemblyScript modules, the host allocates memory via AssemblyScript's GC exports
+    /// (`__new`, `__pin`) and passes strings as AssemblyScript string pointers.
+    AssemblyScript,
+
+    /// Modules compiled from other languages, e.g. Rust with `mamoru-daemon-sdk`.
+    /// Strings and bytes are passed to the host as `ptr, len` pairs,
+    /// the host allocates memory via [`PLAIN_ALLOC_EXPORT`] and returns packed `ptr << 32 | len` values.
+    Plain,
+}
+
+impl WasmAbi {
+    /// Selects the ABI of the module: [`ABI_SECTION_NAME`] custom section if present,
+    /// [`WasmAbi::Plain`] if the module exports [`PLAIN_ALLOC_EXPORT`],
+    /// [`WasmAbi::AssemblyScript`] otherwise.
+    pub fn detect(module: &Module) -> Result<Self, DataError> {
+        if let Some(section) = module.custom_sections(ABI_SECTION_NAME).next() {
+            return Self::from_section(&section);
+        }
+
+        let exports_alloc = module
+            .exports()
+            .any(|export: ExportType| export.name() == PLAIN_ALLOC_EXPORT);
+
+        if exports_alloc {
+            Ok(Self::Plain)
+        } else {
+            Ok(Self::AssemblyScript)
+        }
+    }
+
+    fn from_section(section: &[u8]) -> Result<Self, DataError> {
+        match String::from_utf8_lossy(section).trim() {
+            "assemblyscript" => Ok(Self::AssemblyScript),
+            "plain" => Ok(Self::Plain),
+            unknown => Err(DataError::WasmUnsupportedAbi(unknown.to_string())),
+        }
+    }
+}
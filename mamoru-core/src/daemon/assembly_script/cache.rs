@@ -0,0 +1,340 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, OnceLock,
+    },
+};
+
+use sha2::{Digest, Sha256};
+use tracing::warn;
+use wasmer::{Engine, Module, Store};
+
+use crate::DataError;
+
+/// The artifact file extension in the cache directory.
+const ARTIFACT_EXTENSION: &str = "wasmer";
+
+/// The size of the SHA-256 checksum stored before the artifact.
+const CHECKSUM_SIZE: usize = 32;
+
+static GLOBAL_CACHE: OnceLock<ModuleCache> = OnceLock::new();
+
+/// A content-addressed cache of compiled WASM modules.
+///
+/// Modules are keyed by the WASM hash, the engine, the compilation target and the wasmer version,
+/// so daemon reloads reuse the compiled module instead of compiling it again.
+/// With a cache directory, the serialized artifacts are also stored on disk to survive restarts.
+/// Each file starts with the SHA-256 checksum of the artifact, corrupted files are removed and recompiled.
+///
+/// The cache is bounded: the least recently used modules are evicted from memory,
+/// and the oldest artifacts are removed from disk once their total size exceeds the limit.
+///
+/// All modules are compiled by the single [`Engine`] owned by the cache.
+pub struct ModuleCache {
+    engine: Engine,
+    modules: Mutex<Lru>,
+    dir: Option<PathBuf>,
+    max_modules: usize,
+    max_disk_size: u64,
+    stats: Stats,
+}
+
+/// How modules were resolved by [`ModuleCache::get_or_compile`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModuleCacheStats {
+    pub memory_hits: u64,
+    pub disk_hits: u64,
+    pub compiled: u64,
+}
+
+/// The modules in memory with the tick they were last used at.
+#[derive(Default)]
+struct Lru {
+    entries: HashMap<String, (Module, u64)>,
+    tick: u64,
+}
+
+impl Lru {
+    fn get(&mut self, key: &str) -> Option<Module> {
+        self.tick += 1;
+        let (module, used_at) = self.entries.get_mut(key)?;
+        *used_at = self.tick;
+
+        Some(module.clone())
+    }
+
+    /// Inserts the module, evicting the least recently used ones over `max`.
+    fn insert(&mut self, key: String, module: Module, max: usize) {
+        self.tick += 1;
+        self.entries.insert(key, (module, self.tick));
+
+        while self.entries.len() > max {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used_at))| *used_at)
+                .map(|(key, _)| key.clone());
+
+            match oldest {
+                Some(key) => self.entries.remove(&key),
+                None => break,
+            };
+        }
+    }
+}
+
+#[derive(Default)]
+struct Stats {
+    memory_hits: AtomicU64,
+    disk_hits: AtomicU64,
+    compiled: AtomicU64,
+}
+
+impl ModuleCache {
+    /// The default number of modules kept in memory.
+    pub const DEFAULT_MAX_MODULES: usize = 64;
+
+    /// The default total size of the artifacts on disk, 1 GiB.
+    pub const DEFAULT_MAX_DISK_SIZE: u64 = 1024 * 1024 * 1024;
+
+    /// Creates a cache, the artifacts are stored in `dir` if provided.
+    /// The directory is created on the first write.
+    pub fn new(dir: Option<PathBuf>) -> Self {
+        Self {
+            engine: Store::default().engine().clone(),
+            modules: Mutex::new(Lru::default()),
+            dir,
+            max_modules: Self::DEFAULT_MAX_MODULES,
+            max_disk_size: Self::DEFAULT_MAX_DISK_SIZE,
+            stats: Stats::default(),
+        }
+    }
+
+    /// Limits the number of modules kept in memory, [`Self::DEFAULT_MAX_MODULES`] by default.
+    pub fn with_max_modules(mut self, max_modules: usize) -> Self {
+        self.max_modules = max_modules.max(1);
+
+        self
+    }
+
+    /// Limits the total size of the artifacts in the cache directory in bytes,
+    /// [`Self::DEFAULT_MAX_DISK_SIZE`] by default.
+    /// The artifact stored last is kept even if it exceeds the limit.
+    pub fn with_max_disk_size(mut self, max_disk_size: u64) -> Self {
+        self.max_disk_size = max_disk_size;
+
+        self
+    }
+
+    /// Sets the process-wide cache used by [`super::AssemblyScriptExecutor::new`].
+    /// Returns `false` if the global cache is already initialized.
+    pub fn init_global(cache: ModuleCache) -> bool {
+        GLOBAL_CACHE.set(cache).is_ok()
+    }
+
+    /// The process-wide cache, in-memory only unless [`ModuleCache::init_global`] is called first.
+    pub fn global() -> &'static ModuleCache {
+        GLOBAL_CACHE.get_or_init(|| ModuleCache::new(None))
+    }
+
+    /// The engine compiling the modules, the modules must be instantiated with it.
+    pub fn engine(&self) -> &Engine {
+        &self.engine
+    }
+
+    pub fn stats(&self) -> ModuleCacheStats {
+        ModuleCacheStats {
+            memory_hits: self.stats.memory_hits.load(Ordering::Relaxed),
+            disk_hits: self.stats.disk_hits.load(Ordering::Relaxed),
+            compiled: self.stats.compiled.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Returns the compiled module from memory or disk, compiles and stores it otherwise.
+    pub fn get_or_compile(&self, wasm: &[u8]) -> Result<Module, DataError> {
+        let key = self.key(wasm);
+
+        if let Some(module) = self.modules().get(&key) {
+            self.stats.memory_hits.fetch_add(1, Ordering::Relaxed);
+
+            return Ok(module);
+        }
+
+        let module = match self.load(&key) {
+            Some(module) => {
+                self.stats.disk_hits.fetch_add(1, Ordering::Relaxed);
+
+                module
+            }
+            None => {
+                let module =
+                    Module::from_binary(&self.engine, wasm).map_err(DataError::WasmCompile)?;
+                self.stats.compiled.fetch_add(1, Ordering::Relaxed);
+                self.store(&key, &module);
+
+                module
+            }
+        };
+
+        self.modules().insert(key, module.clone(), self.max_modules);
+
+        Ok(module)
+    }
+
+    /// The path of the artifact in the cache directory.
+    pub fn artifact_path(&self, wasm: &[u8]) -> Option<PathBuf> {
+        self.dir
+            .as_ref()
+            .map(|dir| artifact_path(dir, &self.key(wasm)))
+    }
+
+    fn key(&self, wasm: &[u8]) -> String {
+        let mut hasher = Sha256::new();
+
+        hasher.update(wasmer::VERSION);
+        hasher.update(self.engine.deterministic_id());
+        hasher.update(self.engine.target().triple().to_string());
+        hasher.update(wasm);
+
+        hex::encode(hasher.finalize())
+    }
+
+    fn modules(&self) -> std::sync::MutexGuard<Lru> {
+        self.modules
+            .lock()
+            .expect("BUG: ModuleCache lock is poisoned.")
+    }
+
+    fn load(&self, key: &str) -> Option<Module> {
+        let path = artifact_path(self.dir.as_ref()?, key);
+        let file = fs::read(&path).ok()?;
+
+        let is_valid = file.len() >= CHECKSUM_SIZE && {
+            let (checksum, artifact) = file.split_at(CHECKSUM_SIZE);
+
+            Sha256::digest(artifact).as_slice() == checksum
+        };
+
+        if !is_valid {
+            warn!(
+                ?path,
+                "WASM module artifact checksum mismatch, recompiling."
+            );
+            remove_artifact(&path);
+
+            return None;
+        }
+
+        let artifact = &file[CHECKSUM_SIZE..];
+
+        // SAFETY: the artifact is serialized by `ModuleCache::store` with the same engine and
+        // wasmer version, which are a part of the key, and the checksum is verified above.
+        match unsafe { Module::deserialize(&self.engine, artifact) } {
+            Ok(module) => Some(module),
+            Err(err) => {
+                warn!(
+                    ?err,
+                    ?path,
+                    "Failed to deserialize WASM module artifact, recompiling."
+                );
+                remove_artifact(&path);
+
+                None
+            }
+        }
+    }
+
+    fn store(&self, key: &str, module: &Module) {
+        let dir = match &self.dir {
+            Some(dir) => dir,
+            None => return,
+        };
+
+        let artifact = match module.serialize() {
+            Ok(artifact) => artifact,
+            Err(err) => {
+                warn!(?err, "Failed to serialize WASM module artifact.");
+
+                return;
+            }
+        };
+
+        let mut file = Vec::with_capacity(CHECKSUM_SIZE + artifact.len());
+        file.extend_from_slice(&Sha256::digest(&artifact));
+        file.extend_from_slice(&artifact);
+
+        let path = artifact_path(dir, key);
+        // Write to a temporary file first, so other processes never read a partial artifact.
+        let tmp_path = path.with_extension(format!("{}.tmp", std::process::id()));
+
+        let result = fs::create_dir_all(dir)
+            .and_then(|_| fs::write(&tmp_path, &file))
+            .and_then(|_| fs::rename(&tmp_path, &path));
+
+        if let Err(err) = result {
+            warn!(?err, ?path, "Failed to store WASM module artifact.");
+            remove_artifact(&tmp_path);
+
+            return;
+        }
+
+        if let Err(err) = cleanup_dir(dir, self.max_disk_size, &path) {
+            warn!(
+                ?err,
+                ?dir,
+                "Failed to clean up WASM module cache directory."
+            );
+        }
+    }
+}
+
+/// Removes the oldest artifacts except `keep` until their total size fits `max_size`.
+fn cleanup_dir(dir: &Path, max_size: u64, keep: &Path) -> std::io::Result<()> {
+    let mut artifacts = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.extension().and_then(|ext| ext.to_str()) != Some(ARTIFACT_EXTENSION) {
+            continue;
+        }
+
+        // The artifact may be removed by another process in the meantime.
+        if let Ok(metadata) = fs::metadata(&path) {
+            artifacts.push((metadata.modified()?, metadata.len(), path));
+        }
+    }
+
+    let mut total_size: u64 = artifacts.iter().map(|(_, len, _)| len).sum();
+    artifacts.sort();
+
+    for (_, len, path) in artifacts {
+        if total_size <= max_size {
+            break;
+        }
+
+        if path == keep {
+            continue;
+        }
+
+        remove_artifact(&path);
+        total_size -= len;
+    }
+
+    Ok(())
+}
+
+fn artifact_path(dir: &Path, key: &str) -> PathBuf {
+    dir.join(key).with_extension(ARTIFACT_EXTENSION)
+}
+
+fn remove_artifact(path: &Path) {
+    if let Err(err) = fs::remove_file(path) {
+        if err.kind() != std::io::ErrorKind::NotFound {
+            warn!(?err, ?path, "Failed to remove WASM module artifact.");
+        }
+    }
+}
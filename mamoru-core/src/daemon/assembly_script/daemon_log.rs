@@ -0,0 +1,85 @@
+use std::sync::{Arc, Mutex};
+
+use tracing::{debug, error, info, trace, warn};
+
+/// The `tracing` target of the messages logged by daemons.
+pub const DAEMON_LOG_TARGET: &str = "mamoru::daemon";
+
+/// The level of a message passed to `mamoru.log`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl TryFrom<u32> for LogLevel {
+    type Error = wasmer::RuntimeError;
+
+    fn try_from(value: u32) -> Result<Self, wasmer::RuntimeError> {
+        match value {
+            0 => Ok(Self::Error),
+            1 => Ok(Self::Warn),
+            2 => Ok(Self::Info),
+            3 => Ok(Self::Debug),
+            4 => Ok(Self::Trace),
+            _ => Err(wasmer::RuntimeError::new(format!(
+                "Invalid log level {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// A message logged by a daemon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaemonLog {
+    pub level: LogLevel,
+    pub message: String,
+}
+
+impl DaemonLog {
+    /// Emits the message as a `tracing` event.
+    /// The daemon id is a field of the `Daemon::verify` span.
+    pub(crate) fn emit(&self) {
+        let message = &self.message;
+
+        match self.level {
+            LogLevel::Error => error!(target: DAEMON_LOG_TARGET, "{}", message),
+            LogLevel::Warn => warn!(target: DAEMON_LOG_TARGET, "{}", message),
+            LogLevel::Info => info!(target: DAEMON_LOG_TARGET, "{}", message),
+            LogLevel::Debug => debug!(target: DAEMON_LOG_TARGET, "{}", message),
+            LogLevel::Trace => trace!(target: DAEMON_LOG_TARGET, "{}", message),
+        }
+    }
+}
+
+/// Collects the messages logged by daemons in addition to `tracing`,
+/// e.g. to show them to daemon authors.
+///
+/// The messages are kept even if the run fails.
+#[derive(Debug, Clone, Default)]
+pub struct LogCapture {
+    logs: Arc<Mutex<Vec<DaemonLog>>>,
+}
+
+impl LogCapture {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the captured messages and clears the capture.
+    pub fn take(&self) -> Vec<DaemonLog> {
+        std::mem::take(&mut *self.logs())
+    }
+
+    pub(crate) fn push(&self, log: DaemonLog) {
+        self.logs().push(log);
+    }
+
+    fn logs(&self) -> std::sync::MutexGuard<Vec<DaemonLog>> {
+        self.logs.lock().expect("BUG: LogCapture lock is poisoned.")
+    }
+}
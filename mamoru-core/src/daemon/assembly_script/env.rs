@@ -1,4 +1,4 @@
-use std::sync::{mpsc, Arc};
+use std::sync::Arc;
 
 use as_ffi_bindings::{Read, StringPtr, Write};
 use wasmer::{
@@ -6,20 +6,68 @@ use wasmer::{
     WasmPtr, WasmTypeList,
 };
 
+use crate::assembly_script::abi::{WasmAbi, PLAIN_ALLOC_EXPORT};
+use crate::assembly_script::daemon_log::{DaemonLog, LogCapture, DAEMON_LOG_TARGET};
 use crate::blockchain_data::serialize::pack_values;
-use crate::{BlockchainCtx, BlockchainData, DaemonParameters, DataError, Incident};
+use crate::{
+    BlockchainCtx, BlockchainData, DaemonParameters, DataError, Incident, IncidentLimit,
+    IncidentOverflow,
+};
 
 /// The context available to all exported host functions.
 pub(crate) struct WasmEnv<T> {
+    pub(crate) abi: WasmAbi,
     pub(crate) bindings_env: as_ffi_bindings::Env,
-    pub(crate) data_ctx: BlockchainData<T>,
-    pub(crate) incidents_tx: mpsc::SyncSender<Incident>,
+    /// The module allocator, set for [`WasmAbi::Plain`] only.
+    pub(crate) plain_alloc: Option<TypedFunction<u32, u32>>,
+    /// The per-run state, set by [`WasmEnv::start_run`].
+    pub(crate) run: Option<RunState<T>>,
     pub(crate) parameters: Arc<DaemonParameters>,
+    /// Collects the logged messages in addition to `tracing`, if set.
+    pub(crate) log_capture: Option<LogCapture>,
+}
+
+/// The trap raised when the daemon reports more incidents than allowed
+/// with [`IncidentOverflow::Fail`], converted to [`DataError::TooManyIncidents`].
+#[derive(Debug, thiserror::Error)]
+#[error("Daemon reported more than {limit} incidents")]
+pub(crate) struct TooManyIncidents {
+    limit: usize,
+}
+
+impl From<TooManyIncidents> for DataError {
+    fn from(value: TooManyIncidents) -> Self {
+        Self::TooManyIncidents { limit: value.limit }
+    }
+}
+
+/// The state of a single entrypoint call.
+pub(crate) struct RunState<T> {
+    pub(crate) data_ctx: BlockchainData<T>,
+    /// At most [`IncidentLimit::max_incidents`] reported incidents.
+    pub(crate) incidents: Vec<Incident>,
+    pub(crate) incident_limit: IncidentLimit,
+    /// The number of incidents dropped by [`IncidentOverflow::Truncate`].
+    pub(crate) suppressed_incidents: usize,
+    /// The number of messages the daemon can still log.
+    pub(crate) logs_left: usize,
 }
 
 impl<T: BlockchainCtx> WasmEnv<T> {
+    /// Imports WASM memory and the allocation functions of the module ABI.
+    pub(crate) fn init(
+        env: &FunctionEnv<Self>,
+        store: &mut Store,
+        instance: &Instance,
+    ) -> Result<(), DataError> {
+        match env.as_ref(store).abi {
+            WasmAbi::AssemblyScript => Self::init_bindings_env(env, store, instance),
+            WasmAbi::Plain => Self::init_plain_env(env, store, instance),
+        }
+    }
+
     /// Imports WASM memory and AssemblyScript's GC functions.
-    pub(crate) fn init_bindings_env(
+    fn init_bindings_env(
         env: &FunctionEnv<Self>,
         store: &mut Store,
         instance: &Instance,
@@ -42,11 +90,118 @@ impl<T: BlockchainCtx> WasmEnv<T> {
         Ok(())
     }
 
-    pub(crate) fn memory(&self) -> &Memory {
+    /// Imports WASM memory and the module allocator.
+    fn init_plain_env(
+        env: &FunctionEnv<Self>,
+        store: &mut Store,
+        instance: &Instance,
+    ) -> Result<(), DataError> {
+        let memory = get_memory(instance, "memory")?;
+        let alloc = get_typed_function(instance, store, PLAIN_ALLOC_EXPORT)?;
+
+        let env = env.as_mut(store);
+        env.bindings_env
+            .init_with(Some(memory), None, None, None, None);
+        env.plain_alloc = Some(alloc);
+
+        Ok(())
+    }
+
+    /// Sets the data for the next entrypoint call.
+    pub(crate) fn start_run(
+        &mut self,
+        data_ctx: &BlockchainData<T>,
+        incident_limit: IncidentLimit,
+        max_logs: usize,
+    ) {
+        self.run = Some(RunState {
+            data_ctx: data_ctx.clone(),
+            incidents: vec![],
+            incident_limit,
+            suppressed_incidents: 0,
+            logs_left: max_logs,
+        });
+    }
+
+    /// Drops the data of the finished entrypoint call.
+    pub(crate) fn finish_run(&mut self) {
+        self.run = None;
+    }
+
+    pub(crate) fn data_ctx(&self) -> Result<&BlockchainData<T>, wasmer::RuntimeError> {
+        Ok(&self.run_state()?.data_ctx)
+    }
+
+    /// Collects the incident reported by the daemon,
+    /// the incidents over [`IncidentLimit::max_incidents`] are handled by [`IncidentOverflow`].
+    pub(crate) fn report(&mut self, incident: Incident) -> Result<(), wasmer::RuntimeError> {
+        let run = self.run_state_mut()?;
+
+        if run.incidents.len() < run.incident_limit.max_incidents {
+            run.incidents.push(incident);
+
+            return Ok(());
+        }
+
+        match run.incident_limit.overflow {
+            IncidentOverflow::Truncate => {
+                run.suppressed_incidents += 1;
+
+                Ok(())
+            }
+            IncidentOverflow::Fail => Err(wasmer::RuntimeError::user(Box::new(TooManyIncidents {
+                limit: run.incident_limit.max_incidents,
+            }))),
+        }
+    }
+
+    /// Takes the incidents of the current run and the number of the dropped ones.
+    pub(crate) fn take_incidents(&mut self) -> (Vec<Incident>, usize) {
+        match self.run.as_mut() {
+            Some(run) => (std::mem::take(&mut run.incidents), run.suppressed_incidents),
+            None => (vec![], 0),
+        }
+    }
+
+    /// Emits the message logged by the daemon, the messages over the per-run limit are dropped.
+    pub(crate) fn log(&mut self, log: DaemonLog) -> Result<(), wasmer::RuntimeError> {
+        let run = self.run.as_mut().ok_or_else(outside_of_entrypoint)?;
+
+        if run.logs_left == 0 {
+            return Ok(());
+        }
+
+        run.logs_left -= 1;
+        log.emit();
+
+        if let Some(capture) = &self.log_capture {
+            capture.push(log);
+        }
+
+        if run.logs_left == 0 {
+            tracing::warn!(
+                target: DAEMON_LOG_TARGET,
+                "Daemon log limit is reached, the rest of the messages are dropped."
+            );
+        }
+
+        Ok(())
+    }
+
+    fn run_state(&self) -> Result<&RunState<T>, wasmer::RuntimeError> {
+        self.run.as_ref().ok_or_else(outside_of_entrypoint)
+    }
+
+    fn run_state_mut(&mut self) -> Result<&mut RunState<T>, wasmer::RuntimeError> {
+        self.run.as_mut().ok_or_else(outside_of_entrypoint)
+    }
+
+    /// The module memory, it's not initialized while the module start function runs.
+    pub(crate) fn memory(&self) -> Result<&Memory, wasmer::RuntimeError> {
         self.bindings_env
             .memory
             .as_ref()
-            .expect("BUG: Memory is not initialized.")
+            .ok_or_else(outside_of_entrypoint)
     }
 
     pub(crate) fn read_string_ptr(
@@ -55,12 +210,39 @@ impl<T: BlockchainCtx> WasmEnv<T> {
         store: &impl AsStoreRef,
     ) -> Result<String, wasmer::RuntimeError> {
         let value = ptr
-            .read(self.memory(), store)
+            .read(self.memory()?, store)
             .map_err(|e| wasmer::RuntimeError::new(e.to_string()))?;
 
         Ok(value)
     }
 
+    /// Reads `len` bytes at `ptr`, used by [`WasmAbi::Plain`].
+    pub(crate) fn read_bytes(
+        &self,
+        ptr: u32,
+        len: u32,
+        store: &impl AsStoreRef,
+    ) -> Result<Vec<u8>, wasmer::RuntimeError> {
+        let memory = self.memory()?.view(store);
+
+        WasmPtr::<u8>::new(ptr)
+            .slice(&memory, len)
+            .and_then(|slice| slice.read_to_vec())
+            .map_err(|err| wasmer::RuntimeError::new(err.to_string()))
+    }
+
+    /// Reads an UTF-8 string of `len` bytes at `ptr`, used by [`WasmAbi::Plain`].
+    pub(crate) fn read_str(
+        &self,
+        ptr: u32,
+        len: u32,
+        store: &impl AsStoreRef,
+    ) -> Result<String, wasmer::RuntimeError> {
+        let bytes = self.read_bytes(ptr, len, store)?;
+
+        String::from_utf8(bytes).map_err(|err| wasmer::RuntimeError::new(err.to_string()))
+    }
+
     pub(crate) fn alloc_string_ptr(
         env: as_ffi_bindings::Env,
         value: String,
@@ -72,39 +254,62 @@ impl<T: BlockchainCtx> WasmEnv<T> {
         Ok(*ptr)
     }
 
+    /// Copies `value` to WASM memory allocated by the module,
+    /// returns the packed pointer and length.
     pub(crate) fn alloc_slice(
         mut ctx: &mut FunctionEnvMut<WasmEnv<T>>,
         value: &[u8],
     ) -> Result<u64, wasmer::RuntimeError> {
         let len = value.len() as u32;
+
+        let offset = match ctx.data().plain_alloc.clone() {
+            Some(alloc) => alloc.call(&mut ctx, len)?,
+            None => Self::alloc_assembly_script(ctx, len)?,
+        };
+
+        let memory = ctx.data().memory()?.view(&ctx);
+
+        WasmPtr::<u8>::new(offset)
+            .slice(&memory, len)
+            .map_err(|err| wasmer::RuntimeError::new(err.to_string()))?
+            .write_slice(value)
+            .map_err(|err| wasmer::RuntimeError::new(err.to_string()))?;
+
+        Ok(pack_values(offset, len))
+    }
+
+    fn alloc_assembly_script(
+        mut ctx: &mut FunctionEnvMut<WasmEnv<T>>,
+        len: u32,
+    ) -> Result<u32, wasmer::RuntimeError> {
         let fn_new = ctx
             .data()
             .bindings_env
             .fn_new
             .clone()
-            .expect("BUG: bindings_env is not initialized.");
+            .ok_or_else(outside_of_entrypoint)?;
         let fn_pin = ctx
             .data()
             .bindings_env
             .fn_pin
             .clone()
-            .expect("BUG: bindings_env is not initialized.");
+            .ok_or_else(outside_of_entrypoint)?;
 
         let offset = fn_new.call(&mut ctx, len as i32, 1)? as u32;
         fn_pin.call(&mut ctx, offset as i32)?;
 
-        let memory = ctx.data().memory().view(&ctx);
-
-        WasmPtr::<u8>::new(offset)
-            .slice(&memory, len)
-            .map_err(|err| wasmer::RuntimeError::new(err.to_string()))?
-            .write_slice(value)
-            .map_err(|err| wasmer::RuntimeError::new(err.to_string()))?;
-
-        Ok(pack_values(offset, len))
+        Ok(offset)
     }
 }
 
+/// The trap raised when a host function is called before [`WasmEnv::start_run`],
+/// e.g. by AssemblyScript top-level code running in the module start function.
+fn outside_of_entrypoint() -> wasmer::RuntimeError {
+    wasmer::RuntimeError::new(
+        "Host function is called outside of the daemon entrypoint, e.g. from the module top-level code",
+    )
+}
+
 fn get_memory(instance: &Instance, name: &str) -> Result<Memory, DataError> {
     let memory = instance
         .exports
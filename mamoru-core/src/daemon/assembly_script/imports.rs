@@ -1,39 +1,83 @@
 use std::error::Error;
 
-use crate::assembly_script::incident::IncidentV1;
+use crate::assembly_script::daemon_log::{DaemonLog, LogLevel};
+use crate::assembly_script::incident::{IncidentV1, IncidentV2};
+use crate::assembly_script::query_result::{self, QueryFormat};
+use crate::assembly_script::registry::{ImportDef, MAMORU_EVM_SDK, MAMORU_SDK};
 use as_ffi_bindings::StringPtr;
 use base64::prelude::{Engine as _, BASE64_STANDARD};
 use ethnum::u256;
 use tokio::runtime::Handle;
-use tracing::error;
-use wasmer::{imports, AsStoreMut, Function, FunctionEnv, FunctionEnvMut, Imports};
+use wasmer::{Function, FunctionEnvMut};
 
-use crate::blockchain_data::evm_value::parse_evm_tx_input;
+use crate::blockchain_data::crypto;
+use crate::blockchain_data::evm_value::{parse_evm_event, parse_evm_tx_input};
 use crate::daemon::sql::SqlQuery;
-use crate::BlockchainCtx;
+use crate::{BlockchainCtx, DataError, Incident};
 
 use super::WasmEnv;
 
-pub(crate) fn all<T: BlockchainCtx>(
-    store: &mut impl AsStoreMut,
-    env: &FunctionEnv<WasmEnv<T>>,
-) -> Imports {
-    imports! {
-        "env" => {
-            "abort" => Function::new_typed_with_env(store,  env, abort),
-            "assert" => Function::new_typed_with_env(store, env, assert),
-        },
-        "mamoru" => {
-            "query" => Function::new_typed_with_env(store, env, query),
-            "report" => Function::new_typed_with_env(store, env, report),
-            "http" => Function::new_typed_with_env(store, env, http),
-            "parameter" => Function::new_typed_with_env(store, env, parameter),
-            "u256_from_str" => Function::new_typed_with_env(store, env, u256_from_str),
-        },
-        "mamoru_evm" => {
-            "parse_tx_input" => Function::new_typed_with_env(store, env, parse_tx_input),
-        },
-    }
+pub(crate) fn all<T: BlockchainCtx>() -> Vec<ImportDef<T>> {
+    vec![
+        ImportDef::new("env", "abort", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, abort)
+        }),
+        ImportDef::new("env", "assert", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, assert)
+        }),
+        ImportDef::new("mamoru", "query", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, query)
+        }),
+        ImportDef::new("mamoru", "query_v2", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, query_v2)
+        })
+        .versions(">=0.9.0"),
+        ImportDef::new("mamoru", "report", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, report)
+        }),
+        ImportDef::new("mamoru", "report", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, report_v2)
+        })
+        .versions(">=0.11.0"),
+        ImportDef::new("mamoru", "log", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, log)
+        })
+        .versions(">=0.8.0"),
+        ImportDef::new("mamoru", "http", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, http)
+        }),
+        ImportDef::new("mamoru", "parameter", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, parameter)
+        }),
+        ImportDef::new("mamoru", "u256_from_str", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, u256_from_str)
+        }),
+        ImportDef::new("mamoru", "keccak256", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, keccak256)
+        })
+        .versions(">=0.10.0"),
+        ImportDef::new("mamoru", "sha256", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, sha256)
+        })
+        .versions(">=0.10.0"),
+        ImportDef::new("mamoru", "blake2b", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, blake2b)
+        })
+        .versions(">=0.10.0"),
+        ImportDef::new("mamoru", "ecrecover", MAMORU_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, ecrecover)
+        })
+        .versions(">=0.10.0"),
+        ImportDef::new(
+            "mamoru_evm",
+            "parse_tx_input",
+            MAMORU_EVM_SDK,
+            |store, env| Function::new_typed_with_env(store, env, parse_tx_input),
+        ),
+        ImportDef::new("mamoru_evm", "parse_event", MAMORU_EVM_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, parse_event)
+        }),
+    ]
 }
 
 /// Parses EVM tx input using the provided ABI.
@@ -55,19 +99,99 @@ fn parse_tx_input<T: BlockchainCtx>(
         ))
     })?;
 
-    let parsed_input = parse_evm_tx_input(&abi, &input).map_err(|err| {
-        wasmer::RuntimeError::new(format!("Failed to parse EVM input. Error: {}", err))
-    })?;
+    let parsed_input = tx_input(&abi, &input)?;
 
-    match parsed_input {
-        Some(input) => {
-            let serialized = input.serialize();
-            let ptr = WasmEnv::alloc_slice(&mut ctx, &serialized)?;
+    alloc_optional_slice(&mut ctx, parsed_input)
+}
 
-            Ok(ptr)
-        }
-        None => Ok(0),
-    }
+/// Parses EVM event log using the provided ABI.
+/// Topics and data are passed as base64 strings, missing topics are empty strings.
+/// Returns a pointer to the serialized data.
+/// The pointer is `0` if the signature is not matched.
+fn parse_event<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    abi_ptr: StringPtr,
+    topic0_ptr: StringPtr,
+    topic1_ptr: StringPtr,
+    topic2_ptr: StringPtr,
+    topic3_ptr: StringPtr,
+    data_ptr: StringPtr,
+) -> Result<u64, wasmer::RuntimeError> {
+    let env = ctx.data();
+    let abi = env.read_string_ptr(&abi_ptr, &ctx)?;
+
+    let decode_base64 = |ptr: &StringPtr| -> Result<Vec<u8>, wasmer::RuntimeError> {
+        let value_base64 = env.read_string_ptr(ptr, &ctx)?;
+
+        BASE64_STANDARD.decode(&value_base64).map_err(|err| {
+            wasmer::RuntimeError::new(format!(
+                "Invalid base64 input: {:?}. Error: {}",
+                value_base64, err
+            ))
+        })
+    };
+
+    let topic0 = decode_base64(&topic0_ptr)?;
+    let topic1 = decode_base64(&topic1_ptr)?;
+    let topic2 = decode_base64(&topic2_ptr)?;
+    let topic3 = decode_base64(&topic3_ptr)?;
+    let data = decode_base64(&data_ptr)?;
+
+    let parsed_event = event(&abi, &[&topic0, &topic1, &topic2, &topic3], &data)?;
+
+    alloc_optional_slice(&mut ctx, parsed_event)
+}
+
+/// Hashes `len` bytes at `ptr` with Keccak-256.
+/// Unlike other imports, the input is passed as raw bytes by both ABIs.
+pub(super) fn keccak256<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    ptr: u32,
+    len: u32,
+) -> Result<u64, wasmer::RuntimeError> {
+    let data = ctx.data().read_bytes(ptr, len, &ctx)?;
+
+    WasmEnv::alloc_slice(&mut ctx, &crypto::keccak256(&data))
+}
+
+/// Hashes `len` bytes at `ptr` with SHA-256.
+pub(super) fn sha256<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    ptr: u32,
+    len: u32,
+) -> Result<u64, wasmer::RuntimeError> {
+    let data = ctx.data().read_bytes(ptr, len, &ctx)?;
+
+    WasmEnv::alloc_slice(&mut ctx, &crypto::sha256(&data))
+}
+
+/// Hashes `len` bytes at `ptr` with BLAKE2b-256.
+pub(super) fn blake2b<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    ptr: u32,
+    len: u32,
+) -> Result<u64, wasmer::RuntimeError> {
+    let data = ctx.data().read_bytes(ptr, len, &ctx)?;
+
+    WasmEnv::alloc_slice(&mut ctx, &crypto::blake2b(&data))
+}
+
+/// Recovers the EVM address from the 32 bytes hash and the 65 bytes signature.
+/// The pointer is `0` if the signature is invalid.
+pub(super) fn ecrecover<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    hash_ptr: u32,
+    hash_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u64, wasmer::RuntimeError> {
+    let env = ctx.data();
+    let hash = env.read_bytes(hash_ptr, hash_len, &ctx)?;
+    let signature = env.read_bytes(signature_ptr, signature_len, &ctx)?;
+
+    let address = crypto::ecrecover(&hash, &signature).map(|address| address.to_vec());
+
+    alloc_optional_slice(&mut ctx, address)
 }
 
 /// Parses decimal or hexadecimal string into u256.
@@ -79,16 +203,34 @@ fn u256_from_str<T: BlockchainCtx>(
     let env = ctx.data();
     let payload = env.read_string_ptr(&string_ptr, &ctx)?;
 
-    let number = u256::from_str_prefixed(&payload).map_err(|err| {
-        wasmer::RuntimeError::new(format!("Failed to parse u256. Error: {}", err))
-    })?;
-
-    let result = number.to_be_bytes();
+    let result = u256_be_bytes(&payload)?;
     let ptr = WasmEnv::alloc_slice(&mut ctx, &result)?;
 
     Ok(ptr)
 }
 
+/// The trap raised by AssemblyScript's `abort`, converted to [`DataError::WasmAbort`].
+#[derive(Debug, thiserror::Error)]
+#[error("{message} at {file}:{line}:{col}")]
+pub(super) struct Abort {
+    message: String,
+    file: String,
+    line: u32,
+    col: u32,
+}
+
+impl From<Abort> for DataError {
+    fn from(value: Abort) -> Self {
+        Self::WasmAbort {
+            message: value.message,
+            file: value.file,
+            line: value.line,
+            col: value.col,
+        }
+    }
+}
+
+/// Stops the execution, the module is in an undefined state after a panic.
 fn abort<T: BlockchainCtx>(
     ctx: FunctionEnvMut<WasmEnv<T>>,
     message: StringPtr,
@@ -98,11 +240,14 @@ fn abort<T: BlockchainCtx>(
 ) -> Result<(), wasmer::RuntimeError> {
     let env = ctx.data();
     let message = env.read_string_ptr(&message, &ctx)?;
-    let filename = env.read_string_ptr(&filename, &ctx)?;
-
-    error!("Error: {} at {}:{} col: {}", message, filename, line, col);
-
-    Ok(())
+    let file = env.read_string_ptr(&filename, &ctx)?;
+
+    Err(wasmer::RuntimeError::user(Box::new(Abort {
+        message,
+        file,
+        line,
+        col,
+    })))
 }
 
 #[cfg(feature = "testing")]
@@ -136,40 +281,55 @@ fn query<T: BlockchainCtx>(
     mut ctx: FunctionEnvMut<WasmEnv<T>>,
     query: StringPtr,
 ) -> Result<StringPtr, wasmer::RuntimeError> {
-    runtime_error_ctx(|| {
-        let env = ctx.data();
-        let query = env.read_string_ptr(&query, &ctx)?;
-        let sql_query = SqlQuery::new(&query)?;
+    let env = ctx.data();
+    let query = env.read_string_ptr(&query, &ctx)?;
+    let serialized = query_json(env, &query)?;
 
-        let outputs = Handle::current().block_on(async move {
-            sql_query
-                .query_serialize(env.data_ctx.session().state())
-                .await
-        })?;
+    WasmEnv::<T>::alloc_string_ptr(env.bindings_env.clone(), serialized, &mut ctx)
+}
 
-        let serialized = serde_json::to_string(&outputs)?;
-        let ptr = WasmEnv::<T>::alloc_string_ptr(env.bindings_env.clone(), serialized, &mut ctx)?;
+/// Executes the SQL query, returns a pointer to the rows encoded in [`QueryFormat`].
+#[tracing::instrument(skip_all, level = "trace")]
+fn query_v2<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    query: StringPtr,
+    format: u32,
+) -> Result<u64, wasmer::RuntimeError> {
+    let env = ctx.data();
+    let query = env.read_string_ptr(&query, &ctx)?;
+    let encoded = query_encoded(env, &query, format)?;
 
-        Ok(ptr)
-    })
+    WasmEnv::alloc_slice(&mut ctx, &encoded)
 }
 
 #[tracing::instrument(skip_all, level = "trace")]
 fn report<T: BlockchainCtx>(
-    ctx: FunctionEnvMut<WasmEnv<T>>,
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
     incident_json_ptr: StringPtr,
 ) -> Result<(), wasmer::RuntimeError> {
-    let env = ctx.data();
-    let tx = &env.incidents_tx;
-    let incident_json = env.read_string_ptr(&incident_json_ptr, &ctx)?;
+    let incident_json = ctx.data().read_string_ptr(&incident_json_ptr, &ctx)?;
 
-    runtime_error_ctx(|| {
-        let incident: IncidentV1 = serde_json::from_str(&incident_json)?;
+    send_incident(ctx.data_mut(), &incident_json)
+}
 
-        tx.try_send(incident.into())?;
+#[tracing::instrument(skip_all, level = "trace")]
+fn report_v2<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    incident_json_ptr: StringPtr,
+) -> Result<(), wasmer::RuntimeError> {
+    let incident_json = ctx.data().read_string_ptr(&incident_json_ptr, &ctx)?;
 
-        Ok(())
-    })
+    send_incident_v2(ctx.data_mut(), &incident_json)
+}
+
+fn log<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    level: u32,
+    message: StringPtr,
+) -> Result<(), wasmer::RuntimeError> {
+    let message = ctx.data().read_string_ptr(&message, &ctx)?;
+
+    write_log(ctx.data_mut(), level, message)
 }
 
 #[tracing::instrument(skip_all, level = "trace")]
@@ -179,10 +339,7 @@ fn parameter<T: BlockchainCtx>(
 ) -> Result<StringPtr, wasmer::RuntimeError> {
     let env = ctx.data();
     let key = env.read_string_ptr(&key, &ctx)?;
-
-    let value = env.parameters.get(&key).cloned().ok_or_else(|| {
-        wasmer::RuntimeError::new(format!("No parameter found with key \"{}\"", key))
-    })?;
+    let value = parameter_value(env, &key)?;
 
     let value_ptr = WasmEnv::<T>::alloc_string_ptr(env.bindings_env.clone(), value, &mut ctx)?;
 
@@ -309,6 +466,127 @@ fn http<T: BlockchainCtx>(
     // })
 }
 
+/// Executes the SQL query against the data context, returns the rows as JSON.
+pub(super) fn query_json<T: BlockchainCtx>(
+    env: &WasmEnv<T>,
+    query: &str,
+) -> Result<String, wasmer::RuntimeError> {
+    let data_ctx = env.data_ctx()?;
+
+    runtime_error_ctx(|| {
+        let sql_query = SqlQuery::new(query)?;
+
+        let outputs = Handle::current()
+            .block_on(async move { sql_query.query_serialize(data_ctx.session().state()).await })?;
+
+        Ok(serde_json::to_string(&outputs)?)
+    })
+}
+
+/// Executes the SQL query against the data context, returns the rows encoded in `format`.
+pub(super) fn query_encoded<T: BlockchainCtx>(
+    env: &WasmEnv<T>,
+    query: &str,
+    format: u32,
+) -> Result<Vec<u8>, wasmer::RuntimeError> {
+    let format = QueryFormat::try_from(format)?;
+    let data_ctx = env.data_ctx()?;
+
+    runtime_error_ctx(|| {
+        let sql_query = SqlQuery::new(query)?;
+
+        let batches = Handle::current()
+            .block_on(async move { sql_query.query(data_ctx.session().state()).await })?;
+
+        Ok(query_result::encode(&batches, format)?)
+    })
+}
+
+/// Sends [`IncidentV1`] JSON reported by the daemon.
+pub(super) fn send_incident<T: BlockchainCtx>(
+    env: &mut WasmEnv<T>,
+    incident_json: &str,
+) -> Result<(), wasmer::RuntimeError> {
+    let incident: IncidentV1 = runtime_error_ctx(|| Ok(serde_json::from_str(incident_json)?))?;
+
+    env.report(incident.into())
+}
+
+/// Sends [`IncidentV2`] JSON reported by the daemon.
+pub(super) fn send_incident_v2<T: BlockchainCtx>(
+    env: &mut WasmEnv<T>,
+    incident_json: &str,
+) -> Result<(), wasmer::RuntimeError> {
+    let incident = runtime_error_ctx(|| {
+        let incident: IncidentV2 = serde_json::from_str(incident_json)?;
+
+        Ok(Incident::try_from(incident)?)
+    })?;
+
+    env.report(incident)
+}
+
+/// Emits the message logged by the daemon, fails on unknown levels.
+pub(super) fn write_log<T: BlockchainCtx>(
+    env: &mut WasmEnv<T>,
+    level: u32,
+    message: String,
+) -> Result<(), wasmer::RuntimeError> {
+    let level = LogLevel::try_from(level)?;
+
+    env.log(DaemonLog { level, message })
+}
+
+pub(super) fn parameter_value<T: BlockchainCtx>(
+    env: &WasmEnv<T>,
+    key: &str,
+) -> Result<String, wasmer::RuntimeError> {
+    env.parameters.get(key).cloned().ok_or_else(|| {
+        wasmer::RuntimeError::new(format!("No parameter found with key \"{}\"", key))
+    })
+}
+
+/// Parses EVM tx input, returns the serialized data if the signature is matched.
+pub(super) fn tx_input(abi: &str, input: &[u8]) -> Result<Option<Vec<u8>>, wasmer::RuntimeError> {
+    let parsed_input = parse_evm_tx_input(abi, input).map_err(|err| {
+        wasmer::RuntimeError::new(format!("Failed to parse EVM input. Error: {}", err))
+    })?;
+
+    Ok(parsed_input.map(|input| input.serialize()))
+}
+
+/// Parses EVM event log, returns the serialized data if the signature is matched.
+pub(super) fn event(
+    abi: &str,
+    topics: &[&[u8]],
+    data: &[u8],
+) -> Result<Option<Vec<u8>>, wasmer::RuntimeError> {
+    let parsed_event = parse_evm_event(abi, topics, data).map_err(|err| {
+        wasmer::RuntimeError::new(format!("Failed to parse EVM event. Error: {}", err))
+    })?;
+
+    Ok(parsed_event.map(|event| event.serialize()))
+}
+
+pub(super) fn u256_be_bytes(payload: &str) -> Result<[u8; 32], wasmer::RuntimeError> {
+    let number = u256::from_str_prefixed(payload).map_err(|err| {
+        wasmer::RuntimeError::new(format!("Failed to parse u256. Error: {}", err))
+    })?;
+
+    Ok(number.to_be_bytes())
+}
+
+/// Copies the data to WASM memory, the pointer is `0` if there is no data.
+pub(super) fn alloc_optional_slice<T: BlockchainCtx>(
+    ctx: &mut FunctionEnvMut<WasmEnv<T>>,
+    value: Option<Vec<u8>>,
+) -> Result<u64, wasmer::RuntimeError> {
+    match value {
+        Some(value) => WasmEnv::alloc_slice(ctx, &value),
+        None => Ok(0),
+    }
+}
+
 fn runtime_error_ctx<F, T>(fun: F) -> Result<T, wasmer::RuntimeError>
 where
     F: FnOnce() -> Result<T, Box<dyn Error>>,
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use serde_with::base64::Base64;
 use serde_with::serde_as;
 
+use crate::daemon::incident::IncidentMetadata;
+
 /// The incident reported by a Daemon.
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,10 +30,51 @@ impl From<IncidentV1> for crate::Incident {
             tx_hash: value.tx_hash,
             address: value.address,
             data: value.data,
+            metadata: None,
         }
     }
 }
 
+/// [`IncidentV1`] with the triage fields of [`IncidentMetadata`].
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncidentV2 {
+    pub severity: IncidentSeverity,
+    pub message: String,
+
+    #[serde(default)]
+    pub tx_hash: String,
+
+    #[serde(default)]
+    pub address: String,
+
+    #[serde_as(as = "Base64")]
+    #[serde(default)]
+    pub data: Vec<u8>,
+
+    #[serde(flatten)]
+    pub metadata: IncidentMetadata,
+}
+
+impl TryFrom<IncidentV2> for crate::Incident {
+    type Error = String;
+
+    fn try_from(value: IncidentV2) -> Result<Self, String> {
+        let mut incident = Self {
+            severity: value.severity.into(),
+            message: value.message,
+            tx_hash: value.tx_hash,
+            address: value.address,
+            data: value.data,
+            metadata: None,
+        };
+
+        incident.attach_metadata(value.metadata)?;
+
+        Ok(incident)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum IncidentSeverity {
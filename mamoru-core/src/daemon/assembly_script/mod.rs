@@ -2,25 +2,48 @@ use semver::Version;
 use std::collections::HashMap;
 use std::{
     fmt::{Debug, Formatter},
-    sync::{mpsc, Arc},
+    sync::Arc,
 };
 
 use tracing::Level;
 pub use wasmer::{imports, AsStoreMut, Function, FunctionEnv, FunctionEnvMut, Imports};
-use wasmer::{Engine, Extern, Instance, Module, Store, TypedFunction};
+use wasmer::{Engine, Instance, Module, Store, TypedFunction};
+
+pub use cache::{ModuleCache, ModuleCacheStats};
+pub use daemon_log::{DaemonLog, LogCapture, LogLevel, DAEMON_LOG_TARGET};
+pub use query_result::QueryFormat;
+pub use registry::{MAMORU_EVM_SDK, MAMORU_RUST_SDK, MAMORU_SDK, SUPPORTED_SDKS};
 
 use crate::{
-    assembly_script::env::{get_typed_function, WasmEnv},
-    daemon::{DaemonParameters, Incident},
+    assembly_script::{
+        abi::WasmAbi,
+        env::{get_typed_function, TooManyIncidents, WasmEnv},
+        imports::Abort,
+        pool::{InstancePool, Vm},
+        registry::{ImportDef, NoChainCtx},
+        snapshot::{export_mutable_globals, Snapshot},
+    },
+    daemon::{DaemonParameters, DaemonVersions, Incident, IncidentLimit},
     BlockchainCtx, BlockchainData, CtxImportFn, DataError,
 };
 
+pub mod abi;
+mod cache;
+mod daemon_log;
 mod env;
 mod imports;
 mod incident;
+mod plain_imports;
+mod pool;
+mod query_result;
+mod registry;
+mod snapshot;
+
+/// Maximum messages logged via `mamoru.log` by a single run.
+const MAX_LOGS: usize = 64;
 
-/// Maximum incident reports by a single run.
-const MAX_INCIDENTS: usize = 128;
+/// The default [`ExecutorOptions::pool_size`].
+const DEFAULT_POOL_SIZE: usize = 4;
 
 /// The main function name.
 const ENTRYPOINT_NAME: &str = "main";
@@ -28,13 +51,17 @@ const ENTRYPOINT_NAME: &str = "main";
 /// The main function signature.
 type Entrypoint = TypedFunction<(), ()>;
 
-/// Executes WASM binaries compiled from AssemblyScript.
-/// Other runtimes is not supported by this executor: we rely on AssemblyScript's GC exports.
+/// Executes WASM binaries compiled from AssemblyScript or,
+/// using [`WasmAbi::Plain`], from other languages, e.g. Rust with `mamoru-daemon-sdk`.
+/// The ABI is selected once on compilation, see [`WasmAbi::detect`].
 ///
 /// The instantiated struct holds a compiled [`wasmer::Module`], so it's compiled only once.
+/// The compiled modules are shared between executors via [`ModuleCache`].
 ///
 /// Each [`AssemblyScriptExecutor::execute`] starts a fresh environment,
 /// it's not possible to store data between runs in WASM memory.
+/// The environment is either a new instance or a pooled instance
+/// restored to the state right after initialization, see [`ExecutorOptions::pool_size`].
 pub struct AssemblyScriptExecutor {
     /// The compiled [`wasmer::Module`].
     module: Module,
@@ -42,124 +69,267 @@ pub struct AssemblyScriptExecutor {
     /// The engine used to compile the module.
     engine: Engine,
 
+    /// How the host and the module pass data to each other.
+    abi: WasmAbi,
+
+    /// Initialized instances for reuse, `None` if pooling is disabled.
+    pool: Option<InstancePool>,
+
+    /// See [`ExecutorOptions::log_capture`].
+    log_capture: Option<LogCapture>,
+
     /// The parameters that are passed to Daemon.
     /// Accessible from WASM via host functions.
     parameters: Arc<DaemonParameters>,
 
-    /// The SDKs versions daemon uses.
-    _versions: HashMap<String, Version>,
+    /// The SDKs versions daemon uses, select the linked host imports.
+    versions: DaemonVersions,
+}
+
+/// Options of [`AssemblyScriptExecutor`].
+#[derive(Debug, Clone)]
+pub struct ExecutorOptions {
+    /// The maximum number of initialized instances kept for reuse per chain,
+    /// `0` creates a new instance for each run.
+    ///
+    /// Pooled instances are restored from a snapshot of the memory and the mutable globals,
+    /// an instance is dropped instead if its memory has grown during the run.
+    pub pool_size: usize,
+
+    /// Collects the messages logged via `mamoru.log`, they are always emitted to `tracing`.
+    pub log_capture: Option<LogCapture>,
+}
+
+impl Default for ExecutorOptions {
+    fn default() -> Self {
+        Self {
+            pool_size: DEFAULT_POOL_SIZE,
+            log_capture: None,
+        }
+    }
 }
 
 impl AssemblyScriptExecutor {
+    /// Creates an executor, the module is compiled via [`ModuleCache::global`].
     pub fn new(
         wasm: impl AsRef<[u8]>,
         parameters: DaemonParameters,
         versions: HashMap<String, Version>,
     ) -> Result<Self, DataError> {
-        let store = Store::default();
-        let engine = store.engine().clone();
-        let module = Module::from_binary(&engine, wasm.as_ref()).map_err(DataError::WasmCompile)?;
+        Self::with_options(
+            wasm,
+            parameters,
+            versions,
+            ModuleCache::global(),
+            ExecutorOptions::default(),
+        )
+    }
+
+    pub fn with_options(
+        wasm: impl AsRef<[u8]>,
+        parameters: DaemonParameters,
+        versions: HashMap<String, Version>,
+        cache: &ModuleCache,
+        options: ExecutorOptions,
+    ) -> Result<Self, DataError> {
+        let wasm = wasm.as_ref();
+
+        registry::check_versions(&versions)?;
+
+        // Pooled instances need all mutable globals exported to restore them.
+        // Invalid modules fail on compilation below.
+        let instrumented = match options.pool_size {
+            0 => None,
+            _ => export_mutable_globals(wasm).ok(),
+        };
+
+        let pool = instrumented
+            .as_ref()
+            .map(|_| InstancePool::new(options.pool_size));
+
+        let engine = cache.engine().clone();
+        let module = cache.get_or_compile(instrumented.as_deref().unwrap_or(wasm))?;
+        let abi = WasmAbi::detect(&module)?;
+
+        // The chain getters are checked once the chain is known, see `prepare_vm`.
+        registry::check_imports(&module, &import_defs::<NoChainCtx>(abi), &versions, true)?;
 
         Ok(Self {
             module,
             engine,
+            abi,
+            pool,
+            log_capture: options.log_capture,
             parameters: Arc::new(parameters),
-            _versions: versions,
+            versions,
         })
     }
 
+    pub fn abi(&self) -> WasmAbi {
+        self.abi
+    }
+
+    /// The number of idle instances kept for reuse.
+    pub fn pooled_instances(&self) -> usize {
+        self.pool.as_ref().map_or(0, InstancePool::len)
+    }
+
+    /// Runs the entrypoint, the reported incidents are limited by `incident_limit`.
     pub async fn execute<T: BlockchainCtx>(
         &self,
         ctx: &BlockchainData<T>,
+        incident_limit: IncidentLimit,
     ) -> Result<Vec<Incident>, DataError> {
-        let (mut store, entrypoint, incidents_rx) = self.prepare_vm(ctx)?;
+        let mut vm = match self.pool.as_ref().and_then(|pool| pool.take::<T>()) {
+            Some(vm) => vm,
+            None => self.prepare_vm::<T>()?,
+        };
+
+        vm.start_run(ctx, incident_limit, MAX_LOGS);
 
-        tokio::task::spawn_blocking(move || {
-            let span = tracing::span!(Level::TRACE, "assembly_script:entrypoint");
+        // Keeps the daemon id in the events logged by the daemon.
+        let parent_span = tracing::Span::current();
+
+        let (mut vm, result) = tokio::task::spawn_blocking(move || {
+            let span =
+                tracing::span!(parent: &parent_span, Level::TRACE, "assembly_script:entrypoint");
+            let _parent_guard = parent_span.enter();
             let _guard = span.enter();
 
-            entrypoint.call(&mut store)
+            let result = vm.call_entrypoint();
+
+            (vm, result)
         })
         .await
-        .expect("BUG: AssemblyScriptExecutor entrypoint call is panicked.")
-        .map_err(DataError::WasmRuntime)?;
+        .expect("BUG: AssemblyScriptExecutor entrypoint call is panicked.");
+
+        result.map_err(|err| match err.downcast::<Abort>() {
+            Ok(abort) => abort.into(),
+            Err(err) => match err.downcast::<TooManyIncidents>() {
+                Ok(too_many) => too_many.into(),
+                Err(err) => DataError::WasmRuntime(err),
+            },
+        })?;
+
+        let (incidents, suppressed) = vm.take_incidents();
 
-        let incidents = incidents_rx.into_iter().collect();
+        if let Some(pool) = &self.pool {
+            if vm.reset() {
+                pool.put(vm);
+            }
+        }
 
-        Ok(incidents)
+        incident_limit.apply(incidents, suppressed)
     }
 
     /// Creates new environment for WASM execution.
-    #[tracing::instrument(skip(ctx, self), level = "trace")]
-    fn prepare_vm<T: BlockchainCtx>(
-        &self,
-        ctx: &BlockchainData<T>,
-    ) -> Result<(Store, Entrypoint, mpsc::Receiver<Incident>), DataError> {
+    #[tracing::instrument(skip(self), level = "trace")]
+    fn prepare_vm<T: BlockchainCtx>(&self) -> Result<Vm<T>, DataError> {
         let mut store = Store::new(self.engine.clone());
 
-        let (tx, rx) = mpsc::sync_channel::<Incident>(MAX_INCIDENTS);
         let env = FunctionEnv::new(
             &mut store,
             WasmEnv {
+                abi: self.abi,
                 bindings_env: as_ffi_bindings::Env::default(),
-                data_ctx: ctx.clone(),
-                incidents_tx: tx,
+                plain_alloc: None,
+                run: None,
                 parameters: Arc::clone(&self.parameters),
+                log_capture: self.log_capture.clone(),
             },
         );
 
-        let mut imports = imports::all::<T>(&mut store, &env);
-
-        let blockchain_module = T::module();
-        let blockchain_imports = T::imports();
-
-        imports.extend(blockchain_imports.into_iter().map(|(func_name, func)| {
-            let wasm_func = Extern::Function(match func {
-                CtxImportFn::NoArgs(func) => Function::new_typed_with_env(
-                    &mut store,
-                    &env,
-                    move |mut ctx: FunctionEnvMut<WasmEnv<T>>| {
-                        let value = func(ctx.data().data_ctx.data());
-                        let ptr = WasmEnv::alloc_slice(&mut ctx, &value)?;
-
-                        Ok::<u64, wasmer::RuntimeError>(ptr)
-                    },
-                ),
-                CtxImportFn::ById(func) => Function::new_typed_with_env(
-                    &mut store,
-                    &env,
-                    move |mut ctx: FunctionEnvMut<WasmEnv<T>>, id: u64| {
-                        let data = ctx.data().data_ctx.clone();
-                        let value = func(data.data(), id).map_err(|err| {
-                            wasmer::RuntimeError::new(format!(
-                                "error calling blockchain import {}: {}",
-                                func_name, err
-                            ))
-                        })?;
-                        let ptr = WasmEnv::alloc_slice(&mut ctx, value)?;
-
-                        Ok::<u64, wasmer::RuntimeError>(ptr)
-                    },
-                ),
-            });
-
-            (
-                (blockchain_module.to_string(), func_name.to_string()),
-                wasm_func,
-            )
-        }));
+        let mut import_defs = import_defs::<T>(self.abi);
+        import_defs.extend(blockchain_import_defs::<T>());
+
+        registry::check_imports(&self.module, &import_defs, &self.versions, false)?;
+
+        let imports = registry::link(import_defs, &self.versions, &mut store, &env);
 
         let instance = Instance::new(&mut store, &self.module, &imports)
             .map_err(|err| DataError::WasmInit(Box::new(err)))?;
 
-        WasmEnv::init_bindings_env(&env, &mut store, &instance)?;
+        WasmEnv::init(&env, &mut store, &instance)?;
 
         let entrypoint = get_typed_function(&instance, &store, ENTRYPOINT_NAME)?;
 
-        Ok((store, entrypoint, rx))
+        let snapshot = match self.pool {
+            Some(_) => {
+                let memory = env
+                    .as_ref(&store)
+                    .memory()
+                    .map_err(DataError::WasmRuntime)?;
+                let snapshot = Snapshot::take(&instance, memory, &store).map_err(|err| {
+                    DataError::WasmRuntime(wasmer::RuntimeError::new(err.to_string()))
+                })?;
+
+                Some(snapshot)
+            }
+            None => None,
+        };
+
+        Ok(Vm {
+            store,
+            env,
+            entrypoint,
+            snapshot,
+        })
+    }
+}
+
+/// The host imports of `abi`, except the chain getters.
+fn import_defs<T: BlockchainCtx>(abi: WasmAbi) -> Vec<ImportDef<T>> {
+    match abi {
+        WasmAbi::AssemblyScript => imports::all::<T>(),
+        WasmAbi::Plain => plain_imports::all::<T>(),
     }
 }
 
+/// The chain getters, see [`BlockchainCtx::imports`].
+/// They are linked for all versions of the chain SDK named after [`BlockchainCtx::module`].
+fn blockchain_import_defs<T: BlockchainCtx>() -> Vec<ImportDef<T>> {
+    let blockchain_module = T::module();
+
+    T::imports()
+        .into_iter()
+        .map(|(func_name, func)| {
+            ImportDef::new(
+                blockchain_module,
+                func_name,
+                blockchain_module,
+                move |store, env| match func {
+                    CtxImportFn::NoArgs(func) => Function::new_typed_with_env(
+                        store,
+                        env,
+                        move |mut ctx: FunctionEnvMut<WasmEnv<T>>| {
+                            let value = func(ctx.data().data_ctx()?.data());
+                            let ptr = WasmEnv::alloc_slice(&mut ctx, &value)?;
+
+                            Ok::<u64, wasmer::RuntimeError>(ptr)
+                        },
+                    ),
+                    CtxImportFn::ById(func) => Function::new_typed_with_env(
+                        store,
+                        env,
+                        move |mut ctx: FunctionEnvMut<WasmEnv<T>>, id: u64| {
+                            let data = ctx.data().data_ctx()?.clone();
+                            let value = func(data.data(), id).map_err(|err| {
+                                wasmer::RuntimeError::new(format!(
+                                    "error calling blockchain import {}: {}",
+                                    func_name, err
+                                ))
+                            })?;
+                            let ptr = WasmEnv::alloc_slice(&mut ctx, value)?;
+
+                            Ok::<u64, wasmer::RuntimeError>(ptr)
+                        },
+                    ),
+                },
+            )
+        })
+        .collect()
+}
+
 impl Debug for AssemblyScriptExecutor {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str("AssemblyScriptExecutor")?;
@@ -0,0 +1,198 @@
+//! Host functions for modules using [`WasmAbi::Plain`](super::abi::WasmAbi::Plain).
+//!
+//! The functions have the same names as in [`super::imports`], but strings and bytes
+//! are passed as `ptr, len` pairs and the results are packed `ptr << 32 | len` values
+//! allocated via the module allocator.
+
+use wasmer::{Function, FunctionEnvMut};
+
+use crate::BlockchainCtx;
+
+use super::imports::{
+    alloc_optional_slice, blake2b, ecrecover, event, keccak256, parameter_value, query_encoded,
+    query_json, send_incident, send_incident_v2, sha256, tx_input, u256_be_bytes, write_log,
+};
+use super::registry::{ImportDef, MAMORU_RUST_SDK};
+use super::WasmEnv;
+
+/// EVM event topic size, `parse_event` accepts up to 4 topics concatenated.
+const TOPIC_SIZE: usize = 32;
+
+pub(crate) fn all<T: BlockchainCtx>() -> Vec<ImportDef<T>> {
+    vec![
+        ImportDef::new("mamoru", "query", MAMORU_RUST_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, query)
+        }),
+        ImportDef::new("mamoru", "query_v2", MAMORU_RUST_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, query_v2)
+        }),
+        ImportDef::new("mamoru", "report", MAMORU_RUST_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, report)
+        }),
+        ImportDef::new("mamoru", "report", MAMORU_RUST_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, report_v2)
+        })
+        .versions(">=0.2.0"),
+        ImportDef::new("mamoru", "log", MAMORU_RUST_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, log)
+        }),
+        ImportDef::new("mamoru", "parameter", MAMORU_RUST_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, parameter)
+        }),
+        ImportDef::new("mamoru", "u256_from_str", MAMORU_RUST_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, u256_from_str)
+        }),
+        ImportDef::new("mamoru", "keccak256", MAMORU_RUST_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, keccak256)
+        }),
+        ImportDef::new("mamoru", "sha256", MAMORU_RUST_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, sha256)
+        }),
+        ImportDef::new("mamoru", "blake2b", MAMORU_RUST_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, blake2b)
+        }),
+        ImportDef::new("mamoru", "ecrecover", MAMORU_RUST_SDK, |store, env| {
+            Function::new_typed_with_env(store, env, ecrecover)
+        }),
+        ImportDef::new(
+            "mamoru_evm",
+            "parse_tx_input",
+            MAMORU_RUST_SDK,
+            |store, env| Function::new_typed_with_env(store, env, parse_tx_input),
+        ),
+        ImportDef::new(
+            "mamoru_evm",
+            "parse_event",
+            MAMORU_RUST_SDK,
+            |store, env| Function::new_typed_with_env(store, env, parse_event),
+        ),
+    ]
+}
+
+/// Parses EVM tx input using the provided ABI.
+/// The pointer is `0` if the signature is not matched.
+fn parse_tx_input<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    abi_ptr: u32,
+    abi_len: u32,
+    input_ptr: u32,
+    input_len: u32,
+) -> Result<u64, wasmer::RuntimeError> {
+    let env = ctx.data();
+    let abi = env.read_str(abi_ptr, abi_len, &ctx)?;
+    let input = env.read_bytes(input_ptr, input_len, &ctx)?;
+
+    let parsed_input = tx_input(&abi, &input)?;
+
+    alloc_optional_slice(&mut ctx, parsed_input)
+}
+
+/// Parses EVM event log using the provided ABI.
+/// Topics are passed as a single buffer of 32 bytes chunks.
+/// The pointer is `0` if the signature is not matched.
+fn parse_event<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    abi_ptr: u32,
+    abi_len: u32,
+    topics_ptr: u32,
+    topics_len: u32,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<u64, wasmer::RuntimeError> {
+    let env = ctx.data();
+    let abi = env.read_str(abi_ptr, abi_len, &ctx)?;
+    let topics = env.read_bytes(topics_ptr, topics_len, &ctx)?;
+    let data = env.read_bytes(data_ptr, data_len, &ctx)?;
+
+    let topics: Vec<&[u8]> = topics.chunks(TOPIC_SIZE).collect();
+    let parsed_event = event(&abi, &topics, &data)?;
+
+    alloc_optional_slice(&mut ctx, parsed_event)
+}
+
+/// Parses decimal or hexadecimal string into u256.
+/// Returns to WASM as as BE bytes.
+fn u256_from_str<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    ptr: u32,
+    len: u32,
+) -> Result<u64, wasmer::RuntimeError> {
+    let payload = ctx.data().read_str(ptr, len, &ctx)?;
+    let result = u256_be_bytes(&payload)?;
+
+    WasmEnv::alloc_slice(&mut ctx, &result)
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn query<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    ptr: u32,
+    len: u32,
+) -> Result<u64, wasmer::RuntimeError> {
+    let env = ctx.data();
+    let query = env.read_str(ptr, len, &ctx)?;
+    let serialized = query_json(env, &query)?;
+
+    WasmEnv::alloc_slice(&mut ctx, serialized.as_bytes())
+}
+
+/// Executes the SQL query, returns the rows encoded in [`QueryFormat`](super::query_result::QueryFormat).
+#[tracing::instrument(skip_all, level = "trace")]
+fn query_v2<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    ptr: u32,
+    len: u32,
+    format: u32,
+) -> Result<u64, wasmer::RuntimeError> {
+    let env = ctx.data();
+    let query = env.read_str(ptr, len, &ctx)?;
+    let encoded = query_encoded(env, &query, format)?;
+
+    WasmEnv::alloc_slice(&mut ctx, &encoded)
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn report<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    ptr: u32,
+    len: u32,
+) -> Result<(), wasmer::RuntimeError> {
+    let incident_json = ctx.data().read_str(ptr, len, &ctx)?;
+
+    send_incident(ctx.data_mut(), &incident_json)
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn report_v2<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    ptr: u32,
+    len: u32,
+) -> Result<(), wasmer::RuntimeError> {
+    let incident_json = ctx.data().read_str(ptr, len, &ctx)?;
+
+    send_incident_v2(ctx.data_mut(), &incident_json)
+}
+
+fn log<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    level: u32,
+    ptr: u32,
+    len: u32,
+) -> Result<(), wasmer::RuntimeError> {
+    let message = ctx.data().read_str(ptr, len, &ctx)?;
+
+    write_log(ctx.data_mut(), level, message)
+}
+
+#[tracing::instrument(skip_all, level = "trace")]
+fn parameter<T: BlockchainCtx>(
+    mut ctx: FunctionEnvMut<WasmEnv<T>>,
+    ptr: u32,
+    len: u32,
+) -> Result<u64, wasmer::RuntimeError> {
+    let env = ctx.data();
+    let key = env.read_str(ptr, len, &ctx)?;
+    let value = parameter_value(env, &key)?;
+
+    WasmEnv::alloc_slice(&mut ctx, value.as_bytes())
+}
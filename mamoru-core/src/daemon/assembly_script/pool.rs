@@ -0,0 +1,116 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+};
+
+use tracing::warn;
+use wasmer::{FunctionEnv, Store};
+
+use crate::{
+    assembly_script::{env::WasmEnv, snapshot::Snapshot, Entrypoint},
+    BlockchainCtx, BlockchainData, Incident, IncidentLimit,
+};
+
+/// An instantiated daemon module ready to run the entrypoint.
+pub(crate) struct Vm<T> {
+    pub(crate) store: Store,
+    pub(crate) env: FunctionEnv<WasmEnv<T>>,
+    pub(crate) entrypoint: Entrypoint,
+
+    /// The state right after initialization, set for pooled instances only.
+    pub(crate) snapshot: Option<Snapshot>,
+}
+
+impl<T: BlockchainCtx> Vm<T> {
+    pub(crate) fn start_run(
+        &mut self,
+        ctx: &BlockchainData<T>,
+        incident_limit: IncidentLimit,
+        max_logs: usize,
+    ) {
+        self.env
+            .as_mut(&mut self.store)
+            .start_run(ctx, incident_limit, max_logs)
+    }
+
+    pub(crate) fn take_incidents(&mut self) -> (Vec<Incident>, usize) {
+        self.env.as_mut(&mut self.store).take_incidents()
+    }
+
+    pub(crate) fn call_entrypoint(&mut self) -> Result<(), wasmer::RuntimeError> {
+        self.entrypoint.call(&mut self.store)
+    }
+
+    /// Restores the state right after initialization.
+    /// Returns `false` if the instance can't be reused.
+    pub(crate) fn reset(&mut self) -> bool {
+        self.env.as_mut(&mut self.store).finish_run();
+
+        let snapshot = match &self.snapshot {
+            Some(snapshot) => snapshot,
+            None => return false,
+        };
+
+        let memory = match self.env.as_ref(&self.store).memory() {
+            Ok(memory) => memory.clone(),
+            Err(_) => return false,
+        };
+
+        match snapshot.restore(&memory, &mut self.store) {
+            Ok(restored) => restored,
+            Err(err) => {
+                warn!(?err, "Failed to restore WASM instance.");
+
+                false
+            }
+        }
+    }
+}
+
+/// Keeps initialized instances for reuse.
+///
+/// The instances are stored per [`BlockchainCtx`] type, as the host functions depend on it.
+pub(crate) struct InstancePool {
+    size: usize,
+    vms: Mutex<HashMap<TypeId, Vec<Box<dyn Any + Send>>>>,
+}
+
+impl InstancePool {
+    pub(crate) fn new(size: usize) -> Self {
+        Self {
+            size,
+            vms: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn take<T: BlockchainCtx>(&self) -> Option<Vm<T>> {
+        let vm = self.vms().get_mut(&TypeId::of::<T>())?.pop()?;
+
+        Some(
+            *vm.downcast::<Vm<T>>()
+                .expect("BUG: InstancePool contains an instance of another type."),
+        )
+    }
+
+    /// Returns the instance to the pool, drops it if the pool is full.
+    pub(crate) fn put<T: BlockchainCtx>(&self, vm: Vm<T>) {
+        let mut vms = self.vms();
+        let pooled = vms.entry(TypeId::of::<T>()).or_default();
+
+        if pooled.len() < self.size {
+            pooled.push(Box::new(vm));
+        }
+    }
+
+    /// The number of idle instances.
+    pub(crate) fn len(&self) -> usize {
+        self.vms().values().map(Vec::len).sum()
+    }
+
+    fn vms(&self) -> MutexGuard<HashMap<TypeId, Vec<Box<dyn Any + Send>>>> {
+        self.vms
+            .lock()
+            .expect("BUG: InstancePool lock is poisoned.")
+    }
+}
@@ -0,0 +1,280 @@
+use datafusion::arrow::{
+    array::{Array, ArrayRef, AsArray, FixedSizeBinaryArray},
+    compute::cast,
+    datatypes::{DataType, Float64Type, Int64Type, Schema, UInt64Type},
+    error::ArrowError,
+    ipc::writer::StreamWriter,
+    record_batch::RecordBatch,
+    util::display::array_value_to_string,
+};
+use serde::Serialize;
+
+use crate::{serialize_data, DataError};
+
+/// The result format of `mamoru.query_v2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryFormat {
+    /// The columns and the typed rows serialized via [`serialize_data`].
+    MessagePack,
+    /// The record batches as an Arrow IPC stream.
+    ArrowIpc,
+}
+
+impl TryFrom<u32> for QueryFormat {
+    type Error = wasmer::RuntimeError;
+
+    fn try_from(value: u32) -> Result<Self, wasmer::RuntimeError> {
+        match value {
+            0 => Ok(Self::MessagePack),
+            1 => Ok(Self::ArrowIpc),
+            _ => Err(wasmer::RuntimeError::new(format!(
+                "Invalid query format {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// The query result in [`QueryFormat::MessagePack`].
+///
+/// The values are typed by [`QueryColumn::kind`], so binary and large integer columns
+/// are passed as is instead of JSON strings and numbers.
+/// The columns are empty if the query returns no record batches.
+#[derive(Debug, Serialize)]
+struct QueryRows {
+    columns: Vec<QueryColumn>,
+    rows: Vec<Vec<QueryValue>>,
+}
+
+#[derive(Debug, Serialize)]
+struct QueryColumn {
+    name: String,
+    /// One of `bool`, `int`, `uint`, `float`, `string` or `binary`.
+    /// The columns of other types are passed as `string`.
+    kind: &'static str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+enum QueryValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Binary(Vec<u8>),
+}
+
+/// Serializes the query result in the requested format.
+pub(crate) fn encode(batches: &[RecordBatch], format: QueryFormat) -> Result<Vec<u8>, DataError> {
+    match format {
+        QueryFormat::MessagePack => encode_message_pack(batches),
+        QueryFormat::ArrowIpc => encode_arrow_ipc(batches),
+    }
+    .map_err(DataError::EncodeQueryResult)
+}
+
+fn encode_message_pack(batches: &[RecordBatch]) -> Result<Vec<u8>, ArrowError> {
+    let columns = match batches.first() {
+        Some(batch) => batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| QueryColumn {
+                name: field.name().to_string(),
+                kind: column_kind(field.data_type()),
+            })
+            .collect(),
+        None => vec![],
+    };
+
+    let mut rows = vec![];
+
+    for batch in batches {
+        let mut batch_rows = vec![Vec::with_capacity(batch.num_columns()); batch.num_rows()];
+
+        for column in batch.columns() {
+            for (row, value) in batch_rows.iter_mut().zip(column_values(column)?) {
+                row.push(value);
+            }
+        }
+
+        rows.extend(batch_rows);
+    }
+
+    Ok(serialize_data(&QueryRows { columns, rows }))
+}
+
+fn encode_arrow_ipc(batches: &[RecordBatch]) -> Result<Vec<u8>, ArrowError> {
+    let schema = match batches.first() {
+        Some(batch) => batch.schema(),
+        None => Schema::empty().into(),
+    };
+
+    let mut buf = vec![];
+    let mut writer = StreamWriter::try_new(&mut buf, &schema)?;
+
+    for batch in batches {
+        writer.write(batch)?;
+    }
+
+    writer.finish()?;
+    drop(writer);
+
+    Ok(buf)
+}
+
+fn column_kind(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::Boolean => "bool",
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Timestamp(_, _) => "int",
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => "uint",
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => "float",
+        DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => "binary",
+        _ => "string",
+    }
+}
+
+fn column_values(column: &ArrayRef) -> Result<Vec<QueryValue>, ArrowError> {
+    let values = match column_kind(column.data_type()) {
+        "bool" => column
+            .as_boolean()
+            .iter()
+            .map(|value| value.map(QueryValue::Bool))
+            .collect(),
+        "int" => cast(column, &DataType::Int64)?
+            .as_primitive::<Int64Type>()
+            .iter()
+            .map(|value| value.map(QueryValue::Int))
+            .collect(),
+        "uint" => cast(column, &DataType::UInt64)?
+            .as_primitive::<UInt64Type>()
+            .iter()
+            .map(|value| value.map(QueryValue::UInt))
+            .collect(),
+        "float" => cast(column, &DataType::Float64)?
+            .as_primitive::<Float64Type>()
+            .iter()
+            .map(|value| value.map(QueryValue::Float))
+            .collect(),
+        "binary" => binary_values(column)?,
+        _ => (0..column.len())
+            .map(|idx| {
+                if column.is_null(idx) {
+                    Ok(None)
+                } else {
+                    array_value_to_string(column, idx).map(|value| Some(QueryValue::String(value)))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+    };
+
+    Ok(values
+        .into_iter()
+        .map(|value: Option<QueryValue>| value.unwrap_or(QueryValue::Null))
+        .collect())
+}
+
+fn binary_values(column: &ArrayRef) -> Result<Vec<Option<QueryValue>>, ArrowError> {
+    let to_value = |value: Option<&[u8]>| value.map(|bytes| QueryValue::Binary(bytes.to_vec()));
+
+    let values = match column.data_type() {
+        DataType::FixedSizeBinary(_) => column
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .expect("BUG: FixedSizeBinary column is not FixedSizeBinaryArray")
+            .iter()
+            .map(to_value)
+            .collect(),
+        _ => cast(column, &DataType::Binary)?
+            .as_binary::<i32>()
+            .iter()
+            .map(to_value)
+            .collect(),
+    };
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use datafusion::arrow::{
+        array::{BinaryArray, StringArray, UInt64Array},
+        datatypes::Field,
+        ipc::reader::StreamReader,
+    };
+
+    use super::*;
+
+    fn batch() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("amount", DataType::UInt64, false),
+            Field::new("hash", DataType::Binary, true),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(UInt64Array::from(vec![u64::MAX, 1])),
+                Arc::new(BinaryArray::from(vec![Some(&[0xff, 0x00][..]), None])),
+                Arc::new(StringArray::from(vec![Some("first"), None])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn message_pack_keeps_types() {
+        let bytes = encode(&[batch()], QueryFormat::MessagePack).unwrap();
+
+        type Row = (u64, Option<Vec<u8>>, Option<String>);
+        let (columns, rows): (Vec<(String, String)>, Vec<Row>) =
+            crate::deserialize_data(&bytes).unwrap();
+
+        assert_eq!(
+            columns,
+            vec![
+                ("amount".to_string(), "uint".to_string()),
+                ("hash".to_string(), "binary".to_string()),
+                ("name".to_string(), "string".to_string()),
+            ]
+        );
+        assert_eq!(
+            rows,
+            vec![
+                (u64::MAX, Some(vec![0xff, 0x00]), Some("first".to_string())),
+                (1, None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn arrow_ipc_roundtrip() {
+        let bytes = encode(&[batch()], QueryFormat::ArrowIpc).unwrap();
+
+        let batches = StreamReader::try_new(bytes.as_slice(), None)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(batches, vec![batch()]);
+    }
+
+    #[test]
+    fn empty_result() {
+        let bytes = encode(&[], QueryFormat::MessagePack).unwrap();
+        let (columns, rows): (Vec<(String, String)>, Vec<()>) =
+            crate::deserialize_data(&bytes).unwrap();
+
+        assert!(columns.is_empty());
+        assert!(rows.is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+//! Host imports linked by the SDK versions a daemon declares in [`DaemonVersions`].
+//!
+//! Each host import implementation declares the SDK it belongs to and the SDK versions it supports.
+//! An SDK version that is not declared by a daemon links the newest implementations.
+//! A module importing a function that is not linked is rejected, see [`check_imports`].
+
+use std::collections::HashMap;
+
+use semver::{Version, VersionReq};
+use wasmer::{Function, FunctionEnv, Imports, Module, Store};
+
+use crate::{
+    BlockchainCtx, BlockchainSpecificImports, BlockchainTableItem, DaemonVersions, DataError,
+};
+
+use super::WasmEnv;
+
+/// The core SDK, e.g. `@mamoru-ai/mamoru-sdk-as`, same as [`crate::Daemon::MAMORU_VERSION_KEY`].
+pub const MAMORU_SDK: &str = "mamoru";
+
+/// The EVM SDK, e.g. `@mamoru-ai/mamoru-evm-sdk-as`, also calling the `mamoru_evm` helpers.
+pub const MAMORU_EVM_SDK: &str = "mamoru_evm";
+
+/// The Rust SDK `mamoru-daemon-sdk` using [`super::abi::WasmAbi::Plain`].
+pub const MAMORU_RUST_SDK: &str = "mamoru_rust";
+
+/// The SDKs known to the runtime and the versions it supports.
+///
+/// The chain SDKs are named after the chain import modules, see [`crate::BlockchainCtx::module`].
+/// All their versions read the tables serialized via [`crate::serialize_data`].
+pub const SUPPORTED_SDKS: &[(&str, &str)] = &[
+    (MAMORU_SDK, ">=0.1.0, <0.12.0"),
+    (MAMORU_RUST_SDK, "<1.0.0"),
+    ("mamoru_aptos", "<1.0.0"),
+    ("mamoru_bitcoin", "<1.0.0"),
+    ("mamoru_cosmos", "<1.0.0"),
+    (MAMORU_EVM_SDK, "<1.0.0"),
+    ("mamoru_solana", "<1.0.0"),
+    ("mamoru_sui", "<1.0.0"),
+];
+
+/// Creates the host function.
+pub(crate) type ImportBuilder<T> = Box<dyn Fn(&mut Store, &FunctionEnv<WasmEnv<T>>) -> Function>;
+
+/// A host import implementation.
+pub(crate) struct ImportDef<T> {
+    pub(crate) module: &'static str,
+    pub(crate) name: &'static str,
+    /// The SDK calling the import.
+    pub(crate) sdk: &'static str,
+    /// The SDK versions the implementation supports, parsed as [`VersionReq`].
+    pub(crate) versions: &'static str,
+    pub(crate) build: ImportBuilder<T>,
+}
+
+impl<T> ImportDef<T> {
+    /// An import supported by all versions of `sdk`.
+    pub(crate) fn new(
+        module: &'static str,
+        name: &'static str,
+        sdk: &'static str,
+        build: impl Fn(&mut Store, &FunctionEnv<WasmEnv<T>>) -> Function + 'static,
+    ) -> Self {
+        Self {
+            module,
+            name,
+            sdk,
+            versions: "*",
+            build: Box::new(build),
+        }
+    }
+
+    /// Limits the SDK versions the implementation is linked for.
+    pub(crate) fn versions(mut self, versions: &'static str) -> Self {
+        self.versions = versions;
+
+        self
+    }
+
+    fn supports(&self, version: &Version) -> bool {
+        VersionReq::parse(self.versions)
+            .unwrap_or_else(|_| {
+                panic!(
+                    "BUG: invalid version requirement of \"{}.{}\"",
+                    self.module, self.name
+                )
+            })
+            .matches(version)
+    }
+
+    fn is_linked(&self, versions: &DaemonVersions) -> bool {
+        match versions.get(self.sdk) {
+            Some(version) => self.supports(version),
+            None => true,
+        }
+    }
+}
+
+/// A context without tables, its definitions describe the imports that don't depend on the chain.
+/// Used to check a module before it's known which chain it runs on.
+pub(crate) struct NoChainCtx;
+
+impl BlockchainCtx for NoChainCtx {
+    fn empty() -> Self {
+        Self
+    }
+
+    fn module() -> &'static str {
+        ""
+    }
+
+    fn imports() -> BlockchainSpecificImports<Self> {
+        BlockchainSpecificImports::new()
+    }
+
+    fn as_tables(&self) -> Vec<Box<dyn BlockchainTableItem>> {
+        vec![]
+    }
+}
+
+/// Fails if the daemon declares an SDK version the runtime doesn't support.
+/// The SDKs not listed in [`SUPPORTED_SDKS`] are ignored.
+pub(crate) fn check_versions(versions: &DaemonVersions) -> Result<(), DataError> {
+    for (sdk, supported) in SUPPORTED_SDKS {
+        let version = match versions.get(*sdk) {
+            Some(version) => version,
+            None => continue,
+        };
+
+        let requirement =
+            VersionReq::parse(supported).expect("BUG: invalid SUPPORTED_SDKS requirement");
+
+        if !requirement.matches(version) {
+            return Err(DataError::UnsupportedSdkVersion {
+                sdk: sdk.to_string(),
+                version: version.clone(),
+                supported: supported.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds the implementations supporting the declared SDK versions.
+///
+/// If the SDK version is not declared, the last implementation of each import is linked,
+/// so the definitions must be ordered from the oldest to the newest.
+pub(crate) fn link<T>(
+    defs: Vec<ImportDef<T>>,
+    versions: &DaemonVersions,
+    store: &mut Store,
+    env: &FunctionEnv<WasmEnv<T>>,
+) -> Imports {
+    let mut selected: HashMap<(&str, &str), &ImportDef<T>> = HashMap::new();
+
+    for def in &defs {
+        if def.is_linked(versions) {
+            selected.insert((def.module, def.name), def);
+        }
+    }
+
+    let mut imports = Imports::new();
+
+    for ((module, name), def) in selected {
+        imports.define(module, name, (def.build)(store, env));
+    }
+
+    imports
+}
+
+/// The import modules without the chain getters, see [`crate::BlockchainCtx::module`].
+const CORE_MODULES: &[&str] = &["env", "mamoru"];
+
+/// Fails if the module imports a function [`link`] doesn't link for the declared SDK versions.
+///
+/// If `skip_chain_getters` is set, the imports `defs` don't define are checked in [`CORE_MODULES`] only,
+/// as the chain modules, e.g. `mamoru_evm`, also have the chain getters unknown before the chain is.
+pub(crate) fn check_imports<T>(
+    module: &Module,
+    defs: &[ImportDef<T>],
+    versions: &DaemonVersions,
+    skip_chain_getters: bool,
+) -> Result<(), DataError> {
+    for import in module.imports().functions() {
+        let mut candidates = defs
+            .iter()
+            .filter(|def| def.module == import.module() && def.name == import.name())
+            .peekable();
+
+        let sdk = match candidates.peek() {
+            Some(def) => def.sdk,
+            None => {
+                if skip_chain_getters && !CORE_MODULES.contains(&import.module()) {
+                    continue;
+                }
+
+                return Err(DataError::UnknownImport {
+                    module: import.module().to_string(),
+                    name: import.name().to_string(),
+                });
+            }
+        };
+
+        if !candidates.any(|def| def.is_linked(versions)) {
+            return Err(DataError::UnsupportedImport {
+                module: import.module().to_string(),
+                name: import.name().to_string(),
+                sdk: sdk.to_string(),
+                version: versions
+                    .get(sdk)
+                    .cloned()
+                    .expect("BUG: an import is not linked only for a declared SDK version"),
+            });
+        }
+    }
+
+    Ok(())
+}
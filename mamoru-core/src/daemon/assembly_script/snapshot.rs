@@ -0,0 +1,240 @@
+use wasmer::{AsStoreMut, AsStoreRef, Extern, Global, Instance, Memory, Mutability, Value};
+use wasmparser::{
+    BinaryReader, BinaryReaderError, ExportSectionReader, ExternalKind, GlobalSectionReader,
+    ImportSectionReader, Parser, TypeRef,
+};
+
+/// The prefix of the exports added by [`export_mutable_globals`].
+pub(crate) const GLOBAL_EXPORT_PREFIX: &str = "__mamoru_global_";
+
+/// `\0asm` and the binary format version.
+const HEADER_SIZE: usize = 8;
+
+const IMPORT_SECTION_ID: u8 = 2;
+const GLOBAL_SECTION_ID: u8 = 6;
+const EXPORT_SECTION_ID: u8 = 7;
+
+/// The order of the known sections, custom sections (`0`) may appear anywhere.
+const SECTIONS_ORDER: &[u8] = &[1, 2, 3, 4, 5, 13, 6, 7, 8, 9, 12, 10, 11];
+
+struct Section<'a> {
+    id: u8,
+    data: &'a [u8],
+    offset: usize,
+}
+
+/// Exports the mutable globals defined by the module, so they can be restored by [`Snapshot`].
+///
+/// Compilers keep allocator and GC state in internal globals, e.g. AssemblyScript's runtime,
+/// restoring the memory without them leaves the module in an undefined state.
+pub(crate) fn export_mutable_globals(wasm: &[u8]) -> Result<Vec<u8>, BinaryReaderError> {
+    let sections = read_sections(wasm)?;
+
+    let mut imported_globals = 0;
+    let mut mutable_globals = vec![];
+    let mut exported_globals = vec![];
+
+    for section in &sections {
+        match section.id {
+            IMPORT_SECTION_ID => {
+                for import in ImportSectionReader::new(section.data, section.offset)? {
+                    if let TypeRef::Global(_) = import?.ty {
+                        imported_globals += 1;
+                    }
+                }
+            }
+            GLOBAL_SECTION_ID => {
+                for (index, global) in GlobalSectionReader::new(section.data, section.offset)?
+                    .into_iter()
+                    .enumerate()
+                {
+                    if global?.ty.mutable {
+                        mutable_globals.push(index as u32);
+                    }
+                }
+            }
+            EXPORT_SECTION_ID => {
+                for export in ExportSectionReader::new(section.data, section.offset)? {
+                    let export = export?;
+
+                    if export.kind == ExternalKind::Global {
+                        exported_globals.push(export.index);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let new_exports: Vec<u32> = mutable_globals
+        .into_iter()
+        .map(|index| imported_globals + index)
+        .filter(|index| !exported_globals.contains(index))
+        .collect();
+
+    if new_exports.is_empty() {
+        return Ok(wasm.to_vec());
+    }
+
+    let export_section = |existing: Option<&Section>| -> Result<Vec<u8>, BinaryReaderError> {
+        let (count, entries) = match existing {
+            Some(section) => {
+                let mut reader = BinaryReader::new_with_offset(section.data, section.offset);
+                let count = reader.read_var_u32()?;
+                let position = reader.original_position() - section.offset;
+
+                (count, &section.data[position..])
+            }
+            None => (0, &[][..]),
+        };
+
+        let mut data = vec![];
+        write_u32(&mut data, count + new_exports.len() as u32);
+        data.extend_from_slice(entries);
+
+        for index in &new_exports {
+            let name = format!("{}{}", GLOBAL_EXPORT_PREFIX, index);
+
+            write_u32(&mut data, name.len() as u32);
+            data.extend_from_slice(name.as_bytes());
+            data.push(0x03);
+            write_u32(&mut data, *index);
+        }
+
+        Ok(data)
+    };
+
+    let mut out = wasm[..HEADER_SIZE].to_vec();
+    let mut exports_written = false;
+
+    for section in &sections {
+        if section.id == EXPORT_SECTION_ID {
+            write_section(&mut out, EXPORT_SECTION_ID, &export_section(Some(section))?);
+            exports_written = true;
+
+            continue;
+        }
+
+        if !exports_written && order(section.id) > order(EXPORT_SECTION_ID) {
+            write_section(&mut out, EXPORT_SECTION_ID, &export_section(None)?);
+            exports_written = true;
+        }
+
+        write_section(&mut out, section.id, section.data);
+    }
+
+    if !exports_written {
+        write_section(&mut out, EXPORT_SECTION_ID, &export_section(None)?);
+    }
+
+    Ok(out)
+}
+
+fn read_sections(wasm: &[u8]) -> Result<Vec<Section<'_>>, BinaryReaderError> {
+    // validates the header
+    Parser::new(0).parse(wasm, true)?;
+
+    let mut reader = BinaryReader::new_with_offset(&wasm[HEADER_SIZE..], HEADER_SIZE);
+    let mut sections = vec![];
+
+    while !reader.eof() {
+        let id = reader.read_u8()?;
+        let size = reader.read_var_u32()? as usize;
+        let offset = reader.original_position();
+        let data = reader.read_bytes(size)?;
+
+        sections.push(Section { id, data, offset });
+    }
+
+    Ok(sections)
+}
+
+/// Custom sections keep their position.
+fn order(id: u8) -> usize {
+    SECTIONS_ORDER
+        .iter()
+        .position(|known| *known == id)
+        .unwrap_or(0)
+}
+
+fn write_section(out: &mut Vec<u8>, id: u8, data: &[u8]) {
+    out.push(id);
+    write_u32(out, data.len() as u32);
+    out.extend_from_slice(data);
+}
+
+/// Writes unsigned LEB128.
+fn write_u32(out: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            out.push(byte);
+
+            return;
+        }
+
+        out.push(byte | 0x80);
+    }
+}
+
+/// The state of a freshly initialized instance: memory and mutable globals.
+pub(crate) struct Snapshot {
+    memory: Vec<u8>,
+    globals: Vec<(Global, Value)>,
+}
+
+impl Snapshot {
+    /// Captures the exported mutable globals, see [`export_mutable_globals`].
+    pub(crate) fn take(
+        instance: &Instance,
+        memory: &Memory,
+        store: &impl AsStoreRef,
+    ) -> Result<Self, wasmer::MemoryAccessError> {
+        let view = memory.view(store);
+        let mut data = vec![0; view.data_size() as usize];
+        view.read(0, &mut data)?;
+
+        let globals = instance
+            .exports
+            .iter()
+            .filter_map(|(_, export)| match export {
+                Extern::Global(global) if global.ty(store).mutability == Mutability::Var => {
+                    Some((global.clone(), global.get(store)))
+                }
+                _ => None,
+            })
+            .collect();
+
+        Ok(Self {
+            memory: data,
+            globals,
+        })
+    }
+
+    /// Restores the captured state.
+    /// Returns `false` if the memory has grown, as WASM memory can't shrink.
+    pub(crate) fn restore(
+        &self,
+        memory: &Memory,
+        store: &mut impl AsStoreMut,
+    ) -> Result<bool, wasmer::RuntimeError> {
+        {
+            let view = memory.view(store);
+
+            if view.data_size() != self.memory.len() as u64 {
+                return Ok(false);
+            }
+
+            view.write(0, &self.memory)
+                .map_err(|err| wasmer::RuntimeError::new(err.to_string()))?;
+        }
+
+        for (global, value) in &self.globals {
+            global.set(store, value.clone())?;
+        }
+
+        Ok(true)
+    }
+}
@@ -1,4 +1,11 @@
 use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::blockchain_data::crypto;
+use crate::DataError;
+
+/// The default [`IncidentLimit::max_incidents`].
+const DEFAULT_MAX_INCIDENTS: usize = 128;
 
 /// The incident reported by a Daemon.
 #[derive(Debug, Serialize, Deserialize)]
@@ -8,6 +15,190 @@ pub struct Incident {
     pub tx_hash: String,
     pub address: String,
     pub data: Vec<u8>,
+
+    /// The triage fields of incidents reported as `IncidentV2`.
+    #[serde(default)]
+    pub metadata: Option<IncidentMetadata>,
+}
+
+impl Incident {
+    /// Attaches `IncidentV2` fields, checks the confidence range and fills the missing fingerprint
+    /// with the hash of the incident fields.
+    ///
+    /// The metadata is not attached if no `IncidentV2` field is set,
+    /// e.g. an `IncidentV1` is reported by a daemon not declaring its SDK version.
+    pub(crate) fn attach_metadata(&mut self, mut metadata: IncidentMetadata) -> Result<(), String> {
+        if metadata == IncidentMetadata::default() {
+            return Ok(());
+        }
+
+        if let Some(confidence) = metadata.confidence {
+            if !(0.0..=1.0).contains(&confidence) {
+                return Err(format!(
+                    "Incident confidence must be from 0.0 to 1.0, got {}",
+                    confidence
+                ));
+            }
+        }
+
+        if metadata.fingerprint.is_empty() {
+            let mut addresses: Vec<_> = metadata
+                .addresses
+                .iter()
+                .map(|related| related.address.as_str())
+                .collect();
+            addresses.sort_unstable();
+
+            let source = [
+                self.severity.to_str(),
+                &metadata.title,
+                &self.message,
+                &self.tx_hash,
+                &self.address,
+                &addresses.join(","),
+            ]
+            .join("\n");
+
+            metadata.fingerprint = hex::encode(crypto::sha256(source.as_bytes()));
+        }
+
+        self.metadata = Some(metadata);
+
+        Ok(())
+    }
+
+    /// Folds [`Incident::metadata`] into the fields known to older consumers:
+    /// the title prefixes the message, the first related address is used if `address` is empty
+    /// and the metadata is attached as JSON if `data` is empty.
+    ///
+    /// Incidents without metadata are returned unchanged.
+    pub fn into_v1(mut self) -> Self {
+        let metadata = match self.metadata.take() {
+            Some(metadata) => metadata,
+            None => return self,
+        };
+
+        if !metadata.title.is_empty() {
+            self.message = format!("{}: {}", metadata.title, self.message);
+        }
+
+        if self.address.is_empty() {
+            if let Some(related) = metadata.addresses.first() {
+                self.address = related.address.clone();
+            }
+        }
+
+        if self.data.is_empty() {
+            self.data =
+                serde_json::to_vec(&metadata).expect("BUG: IncidentMetadata serialization failed.");
+        }
+
+        self
+    }
+}
+
+/// The fields added by `IncidentV2`, all of them are optional.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IncidentMetadata {
+    /// A short summary, [`Incident::message`] keeps the description.
+    pub title: String,
+    pub addresses: Vec<IncidentAddress>,
+    pub tags: Vec<String>,
+    /// How sure the daemon is, from `0.0` to `1.0`.
+    pub confidence: Option<f64>,
+    /// Arbitrary structured data, unlike [`Incident::data`] it's readable by triage tools.
+    pub details: Option<serde_json::Value>,
+    /// Identifies the same incident reported multiple times,
+    /// the hash of the incident fields if not set by the daemon.
+    pub fingerprint: String,
+}
+
+/// An address related to the incident, e.g. `attacker` or `victim`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IncidentAddress {
+    pub address: String,
+    pub role: String,
+}
+
+/// Limits the incidents reported by a single daemon run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncidentLimit {
+    pub max_incidents: usize,
+    pub overflow: IncidentOverflow,
+}
+
+/// What happens when a daemon reports more than [`IncidentLimit::max_incidents`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IncidentOverflow {
+    /// Keeps the first incidents and adds a summary incident
+    /// with the number of the suppressed ones.
+    Truncate,
+    /// Fails the run with [`DataError::TooManyIncidents`].
+    #[default]
+    Fail,
+}
+
+impl Default for IncidentLimit {
+    fn default() -> Self {
+        Self {
+            max_incidents: DEFAULT_MAX_INCIDENTS,
+            overflow: IncidentOverflow::default(),
+        }
+    }
+}
+
+impl IncidentLimit {
+    /// Applies the limit to the reported incidents,
+    /// `suppressed` is the number of incidents already dropped by the executor.
+    pub(crate) fn apply(
+        &self,
+        mut incidents: Vec<Incident>,
+        mut suppressed: usize,
+    ) -> Result<Vec<Incident>, DataError> {
+        if incidents.len() > self.max_incidents {
+            suppressed += incidents.len() - self.max_incidents;
+            incidents.truncate(self.max_incidents);
+        }
+
+        if suppressed == 0 {
+            return Ok(incidents);
+        }
+
+        match self.overflow {
+            IncidentOverflow::Fail => Err(DataError::TooManyIncidents {
+                limit: self.max_incidents,
+            }),
+            IncidentOverflow::Truncate => {
+                warn!(
+                    suppressed,
+                    limit = self.max_incidents,
+                    "Incidents are suppressed."
+                );
+
+                let severity = incidents
+                    .iter()
+                    .map(|incident| incident.severity.clone())
+                    .max_by_key(IncidentSeverity::rank)
+                    .unwrap_or(IncidentSeverity::Info);
+
+                incidents.push(Incident {
+                    severity,
+                    message: format!(
+                        "{} incidents suppressed, the limit is {}",
+                        suppressed, self.max_incidents
+                    ),
+                    tx_hash: Default::default(),
+                    address: Default::default(),
+                    data: vec![],
+                    metadata: None,
+                });
+
+                Ok(incidents)
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
@@ -30,6 +221,15 @@ impl IncidentSeverity {
         }
     }
 
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Info => 0,
+            Self::Warning => 1,
+            Self::Error => 2,
+            Self::Alert => 3,
+        }
+    }
+
     pub fn to_str(&self) -> &str {
         match self {
             Self::Info => "info",
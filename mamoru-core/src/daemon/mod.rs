@@ -4,12 +4,18 @@ pub use semver::Version;
 
 use crate::blockchain_data::BlockchainData;
 use crate::{
-    daemon::{assembly_script::AssemblyScriptExecutor, incident::Incident, sql::SqlExecutor},
+    daemon::{
+        assembly_script::AssemblyScriptExecutor,
+        incident::{Incident, IncidentLimit},
+        native::{NativeDaemonRegistry, NativeExecutor},
+        sql::SqlExecutor,
+    },
     BlockchainCtx, DataError, IncidentData,
 };
 
 pub mod assembly_script;
 pub mod incident;
+pub mod native;
 pub mod sql;
 
 #[derive(Debug)]
@@ -31,6 +37,7 @@ pub type DaemonVersions = HashMap<String, Version>;
 pub enum Executor {
     Sql(SqlExecutor),
     AssemblyScript(AssemblyScriptExecutor),
+    Native(NativeExecutor),
 }
 
 /// The Daemon entity.
@@ -38,6 +45,7 @@ pub enum Executor {
 pub struct Daemon {
     id: String,
     executor: Executor,
+    incident_limit: IncidentLimit,
 }
 
 impl Daemon {
@@ -65,6 +73,8 @@ impl Daemon {
         Ok(Self::new(id, executor))
     }
 
+    /// Creates a WASM daemon, the module may be compiled from AssemblyScript
+    /// or use the plain ABI, see [`assembly_script::abi::WasmAbi`].
     pub fn new_assembly_script(
         id: String,
         wasm: impl AsRef<[u8]>,
@@ -77,8 +87,36 @@ impl Daemon {
         Ok(Self::new(id, executor))
     }
 
+    /// Creates a daemon of the given `kind` from `registry` running on the blockchain `T`,
+    /// see [`native::NativeDaemon`].
+    pub fn new_native<T: BlockchainCtx>(
+        id: String,
+        registry: &NativeDaemonRegistry,
+        kind: &str,
+        parameters: &DaemonParameters,
+    ) -> Result<Self, DataError> {
+        let executor = Executor::Native(registry.create::<T>(kind, parameters)?);
+
+        Ok(Self::new(id, executor))
+    }
+
     pub fn new(id: String, executor: Executor) -> Self {
-        Self { id, executor }
+        Self {
+            id,
+            executor,
+            incident_limit: IncidentLimit::default(),
+        }
+    }
+
+    /// Sets the limit of incidents reported by a single run.
+    pub fn with_incident_limit(mut self, incident_limit: IncidentLimit) -> Self {
+        self.incident_limit = incident_limit;
+
+        self
+    }
+
+    pub fn incident_limit(&self) -> IncidentLimit {
+        self.incident_limit
     }
 
     pub fn id(&self) -> String {
@@ -92,8 +130,9 @@ impl Daemon {
         ctx: &BlockchainData<T>,
     ) -> Result<VerifyCtx, DataError> {
         let incidents = match &self.executor {
-            Executor::Sql(sql) => sql.execute(ctx).await?,
-            Executor::AssemblyScript(ass) => ass.execute(ctx).await?,
+            Executor::Sql(sql) => self.incident_limit.apply(sql.execute(ctx).await?, 0)?,
+            Executor::AssemblyScript(ass) => ass.execute(ctx, self.incident_limit).await?,
+            Executor::Native(native) => self.incident_limit.apply(native.execute(ctx).await?, 0)?,
         };
 
         Ok(VerifyCtx {
@@ -0,0 +1,147 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use datafusion::prelude::SessionContext;
+
+use crate::{BlockchainCtx, BlockchainData, DaemonParameters, DataError, Incident};
+
+/// A daemon compiled into the host, for trusted detectors that need native speed.
+///
+/// Unlike SQL and WASM daemons, a native daemon works with the blockchain types directly,
+/// the `session` is the same one SQL daemons query.
+#[async_trait]
+pub trait NativeDaemon<T: BlockchainCtx>: Send + Sync {
+    async fn verify(
+        &self,
+        ctx: &BlockchainData<T>,
+        session: &SessionContext,
+    ) -> Result<Vec<Incident>, DataError>;
+}
+
+/// Executes a [`NativeDaemon`] registered for a single [`BlockchainCtx`] type.
+pub struct NativeExecutor {
+    kind: String,
+
+    /// `Arc<dyn NativeDaemon<T>>`, as [`Executor`](crate::Executor) is not generic over `T`.
+    daemon: Box<dyn Any + Send + Sync>,
+}
+
+impl NativeExecutor {
+    pub fn new<T: BlockchainCtx>(
+        kind: impl Into<String>,
+        daemon: impl NativeDaemon<T> + 'static,
+    ) -> Self {
+        let daemon: Arc<dyn NativeDaemon<T>> = Arc::new(daemon);
+
+        Self {
+            kind: kind.into(),
+            daemon: Box::new(daemon),
+        }
+    }
+
+    /// The key the daemon is registered by in [`NativeDaemonRegistry`].
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// Fails with [`DataError::NativeDaemonCtx`] if the daemon is created for another blockchain.
+    pub async fn execute<T: BlockchainCtx>(
+        &self,
+        ctx: &BlockchainData<T>,
+    ) -> Result<Vec<Incident>, DataError> {
+        let daemon = self
+            .daemon
+            .downcast_ref::<Arc<dyn NativeDaemon<T>>>()
+            .ok_or_else(|| DataError::NativeDaemonCtx(self.kind.clone()))?;
+
+        daemon.verify(ctx, ctx.session()).await
+    }
+}
+
+impl Debug for NativeExecutor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NativeExecutor")
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+type NativeDaemonFactory =
+    dyn Fn(&DaemonParameters) -> Result<NativeExecutor, DataError> + Send + Sync;
+
+#[derive(Clone)]
+struct NativeDaemonEntry {
+    /// The [`TypeId`] of the [`BlockchainCtx`] the daemon is registered for.
+    ctx: TypeId,
+    factory: Arc<NativeDaemonFactory>,
+}
+
+/// The native daemons a host ships, keyed by kind.
+///
+/// A daemon is created per deployment with its [`DaemonParameters`].
+#[derive(Clone, Default)]
+pub struct NativeDaemonRegistry {
+    factories: HashMap<String, NativeDaemonEntry>,
+}
+
+impl NativeDaemonRegistry {
+    /// Registers the daemon factory, replaces the previous one of the same `kind`.
+    pub fn register<T, D, F>(&mut self, kind: impl Into<String>, factory: F)
+    where
+        T: BlockchainCtx,
+        D: NativeDaemon<T> + 'static,
+        F: Fn(&DaemonParameters) -> Result<D, DataError> + Send + Sync + 'static,
+    {
+        let kind = kind.into();
+        let executor_kind = kind.clone();
+
+        self.factories.insert(
+            kind,
+            NativeDaemonEntry {
+                ctx: TypeId::of::<T>(),
+                factory: Arc::new(move |parameters: &DaemonParameters| {
+                    Ok(NativeExecutor::new::<T>(
+                        executor_kind.clone(),
+                        factory(parameters)?,
+                    ))
+                }),
+            },
+        );
+    }
+
+    pub fn contains(&self, kind: &str) -> bool {
+        self.factories.contains_key(kind)
+    }
+
+    /// Creates the daemon running on the blockchain `T`.
+    ///
+    /// Fails with [`DataError::UnknownNativeDaemon`] if `kind` is not registered
+    /// and with [`DataError::NativeDaemonCtx`] if it's registered for another blockchain.
+    pub fn create<T: BlockchainCtx>(
+        &self,
+        kind: &str,
+        parameters: &DaemonParameters,
+    ) -> Result<NativeExecutor, DataError> {
+        let entry = self
+            .factories
+            .get(kind)
+            .ok_or_else(|| DataError::UnknownNativeDaemon(kind.to_string()))?;
+
+        if entry.ctx != TypeId::of::<T>() {
+            return Err(DataError::NativeDaemonCtx(kind.to_string()));
+        }
+
+        (entry.factory)(parameters)
+    }
+}
+
+impl Debug for NativeDaemonRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.factories.keys()).finish()
+    }
+}
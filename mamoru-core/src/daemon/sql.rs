@@ -27,6 +27,8 @@ pub(crate) type SqlQueryOutputs = Vec<Map<String, Value>>;
 lazy_static! {
     static ref FEAT_SELECT_REPORTS: VersionReq =
         VersionReq::parse(">=0.1.0").expect("BUG: Failed to parse FEAT_SELECT_REPORTS version");
+    static ref FEAT_INCIDENT_V2: VersionReq =
+        VersionReq::parse(">=0.11.0").expect("BUG: Failed to parse FEAT_INCIDENT_V2 version");
 }
 
 /// SQL daemon executor.
@@ -70,10 +72,16 @@ impl SqlExecutor {
         ctx: &BlockchainData<T>,
     ) -> Result<Vec<Incident>, DataError> {
         if FEAT_SELECT_REPORTS.matches(&self.version) {
-            let batches = self.query.query(ctx.session().state()).await?;
-            let incidents = extract_incidents(batches);
+            // `report_full_v2` is unknown to older daemons, so they fail on planning
+            let state = if FEAT_INCIDENT_V2.matches(&self.version) {
+                ctx.session_state_v2()
+            } else {
+                ctx.session().state()
+            };
 
-            return Ok(incidents);
+            let batches = self.query.query(state).await?;
+
+            return Ok(extract_incidents(batches));
         }
 
         let matches = self.query.matches(ctx.session().state()).await?;
@@ -85,6 +93,7 @@ impl SqlExecutor {
                 address: Default::default(),
                 tx_hash: Default::default(),
                 data: vec![],
+                metadata: None,
             }])
         } else {
             Ok(vec![])
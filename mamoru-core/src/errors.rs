@@ -12,6 +12,9 @@ pub enum DataError {
     #[error("Failed to covert RecordBatch to json data.")]
     RecordBatchToJson(datafusion::arrow::error::ArrowError),
 
+    #[error("Failed to encode query result: {0}")]
+    EncodeQueryResult(datafusion::arrow::error::ArrowError),
+
     #[error("Failed to register RecordBatch in the internal database.")]
     RegisterRecordBatch(datafusion::error::DataFusionError),
 
@@ -44,6 +47,57 @@ pub enum DataError {
 
     #[error("Failed to execute WASM module: {0}")]
     WasmRuntime(RuntimeError),
+
+    #[error("WASM module aborted: {message} at {file}:{line}:{col}")]
+    WasmAbort {
+        message: String,
+        file: String,
+        line: u32,
+        col: u32,
+    },
+
+    #[error("Unsupported WASM module ABI \"{0}\"")]
+    WasmUnsupportedAbi(String),
+
+    #[error("Daemon reported more than {limit} incidents")]
+    TooManyIncidents { limit: usize },
+
+    #[error("Native daemon \"{0}\" is not registered")]
+    UnknownNativeDaemon(String),
+
+    #[error("Native daemon \"{0}\" is registered for another blockchain")]
+    NativeDaemonCtx(String),
+
+    #[error("Unsupported SDK \"{sdk}\" version {version}, expected {supported}")]
+    UnsupportedSdkVersion {
+        sdk: String,
+        version: semver::Version,
+        supported: String,
+    },
+
+    #[error("Import \"{module}.{name}\" is not supported by SDK \"{sdk}\" version {version}")]
+    UnsupportedImport {
+        module: String,
+        name: String,
+        sdk: String,
+        version: semver::Version,
+    },
+
+    #[error("Unknown import \"{module}.{name}\"")]
+    UnknownImport { module: String, name: String },
+}
+
+#[derive(Error, Debug)]
+pub enum CodegenError {
+    #[error("Table \"{table}\" has no struct name in the schema metadata.")]
+    MissingStructName { table: String },
+
+    #[error("Column \"{table}.{column}\" has unsupported type {data_type}.")]
+    UnsupportedType {
+        table: String,
+        column: String,
+        data_type: String,
+    },
 }
 
 #[derive(Error, Debug)]
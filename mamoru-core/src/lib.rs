@@ -1,16 +1,30 @@
 pub use blockchain_data::{
+    as_sdk::generate_as_sdk,
+    btc_value::{classify_btc_script, derive_btc_address},
+    column::{ArrowColumn, ArrowListItem},
+    evm_value::decode_revert_reason,
+    rust_sdk::generate_rust_sdk,
+    schema::{
+        ColumnSchema, TableSchema, SCHEMA_AS_ALIAS_KEY, SCHEMA_AS_FROM_KEY, SCHEMA_AS_TYPE_KEY,
+        SCHEMA_DOC_KEY, SCHEMA_SKIP_SERIALIZING_KEY, SCHEMA_STRUCT_KEY,
+    },
     serialize::{deserialize_data, serialize_data},
     value::{StructValue, Value, ValueData},
     BlockchainCtx, BlockchainData, BlockchainDataBuilder, BlockchainSpecificImports,
-    BlockchainTableItem, CtxImportError, CtxImportFn, DataSource, Statistics, TableDef,
+    BlockchainTableItem, ByIdImport, ByIdValue, CtxImportError, CtxImportFn, DataSource,
+    Statistics, TableDef,
 };
 pub use daemon::{
     assembly_script,
-    incident::{Incident, IncidentSeverity},
+    incident::{
+        Incident, IncidentAddress, IncidentLimit, IncidentMetadata, IncidentOverflow,
+        IncidentSeverity,
+    },
+    native::{NativeDaemon, NativeDaemonRegistry, NativeExecutor},
     sql::IncidentData,
-    Daemon, DaemonParameters, DaemonVersions, VerifyCtx, Version,
+    Daemon, DaemonParameters, DaemonVersions, Executor, VerifyCtx, Version,
 };
-pub use errors::{DataError, RenderError, ValueError};
+pub use errors::{CodegenError, DataError, RenderError, ValueError};
 
 mod blockchain_data;
 mod daemon;
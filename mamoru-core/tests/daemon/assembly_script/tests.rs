@@ -1,7 +1,7 @@
 use expect_test::expect;
 use test_log::test;
 
-use mamoru_core::{DataError, IncidentSeverity};
+use mamoru_core::{DataError, IncidentLimit, IncidentOverflow, IncidentSeverity};
 use mamoru_core_test_utils::assembly_script::{AssemblyScriptModule, AS_SDK_PATH};
 use mamoru_core_test_utils::test_blockchain_data::data_ctx;
 use mamoru_core_test_utils::{test_daemon, test_daemon_with_parameters};
@@ -117,6 +117,7 @@ async fn generates_many_incidents() {
             tx_hash: "txHash",
             address: "",
             data: [],
+            metadata: None,
         }
     "#]]
     .assert_debug_eq(&result.incidents[0]);
@@ -141,12 +142,80 @@ async fn too_many_incident_generation_fails() {
     );
 
     let daemon = test_daemon(&module);
+    let result = daemon.verify(&ctx).await;
 
-    if let Err(DataError::WasmRuntime(err)) = daemon.verify(&ctx).await {
-        expect!["sending on a full channel"].assert_eq(&err.message());
-    } else {
-        panic!("Expected error, but no error returned.")
-    }
+    assert!(matches!(
+        result,
+        Err(DataError::TooManyIncidents { limit: 128 })
+    ));
+}
+
+#[test(tokio::test)]
+async fn too_many_incidents_are_truncated() {
+    let ctx = data_ctx("DUMMY_HASH");
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {report, IncidentSeverity} from "@mamoru-ai/mamoru-sdk-as/assembly";
+
+        export function main(): void {
+            for (let i = 0; i < 1_000; i++) {
+              report('txHash', IncidentSeverity.Alert, "Test");
+            }
+        }
+    """#,
+        &[AS_SDK_PATH],
+    );
+
+    let daemon = test_daemon(&module).with_incident_limit(IncidentLimit {
+        max_incidents: 10,
+        overflow: IncidentOverflow::Truncate,
+    });
+
+    let result = daemon
+        .verify(&ctx)
+        .await
+        .expect("Failed to run Daemon::verify()");
+
+    assert_eq!(result.incidents.len(), 11);
+
+    let summary = result.incidents.last().unwrap();
+
+    assert_eq!(summary.severity, IncidentSeverity::Alert);
+    assert_eq!(summary.message, "990 incidents suppressed, the limit is 10");
+}
+
+#[test(tokio::test)]
+async fn abort_stops_execution() {
+    let ctx = data_ctx("DUMMY_HASH");
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {report, IncidentSeverity} from "@mamoru-ai/mamoru-sdk-as/assembly";
+
+        export function main(): void {
+            abort("Unexpected value", "daemon.ts", 4, 9);
+
+            report('txHash', IncidentSeverity.Alert, "Unreachable");
+        }
+    """#,
+        &[AS_SDK_PATH],
+    );
+
+    let daemon = test_daemon(&module);
+    let result = daemon.verify(&ctx).await;
+
+    expect![[r#"
+        Err(
+            WasmAbort {
+                message: "Unexpected value",
+                file: "daemon.ts",
+                line: 4,
+                col: 9,
+            },
+        )
+    "#]]
+    .assert_debug_eq(&result);
 }
 
 #[test(tokio::test)]
@@ -176,6 +245,35 @@ async fn invalid_query_fails() {
     }
 }
 
+#[test(tokio::test)]
+async fn top_level_import_call_fails() {
+    let ctx = data_ctx("DUMMY_HASH");
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {query} from "@mamoru-ai/mamoru-sdk-as/assembly";
+
+        const rows = query("SELECT t.seq FROM transactions t");
+
+        export function main(): void {
+            assert(rows.length > 0);
+        }
+    """#,
+        &[AS_SDK_PATH],
+    );
+
+    let daemon = test_daemon(&module);
+
+    match daemon.verify(&ctx).await {
+        Err(DataError::WasmInit(err)) => {
+            assert!(err
+                .to_string()
+                .contains("Host function is called outside of the daemon entrypoint"));
+        }
+        result => panic!("Expected WasmInit error, got {:?}", result),
+    }
+}
+
 #[test(tokio::test)]
 async fn smoke() {
     let ctx = data_ctx("DUMMY_HASH");
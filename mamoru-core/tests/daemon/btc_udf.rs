@@ -0,0 +1,71 @@
+use test_log::test;
+
+use mamoru_core::DataError;
+use mamoru_core_test_utils::test_blockchain_data::data_ctx;
+
+use crate::daemon::test_sql_daemon;
+
+// P2PKH of the genesis block coinbase: 1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa
+const TEST_P2PKH_SCRIPT: &str = "0x76a91462e907b15cbf27d5425399ebf6f0fb50ebb88f1888ac";
+
+// P2WPKH: bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4
+const TEST_P2WPKH_SCRIPT: &str = "0x0014751e76e8199196d454941c45d1b3a323f1433bd6";
+
+// OP_RETURN "Hello"
+const TEST_OP_RETURN_SCRIPT: &str = "0x6a0548656c6c6f";
+
+#[test(tokio::test)]
+async fn btc_script_type() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let rule = test_sql_daemon(format!(
+        r#"
+        WITH test_outputs AS (
+            SELECT
+                hex_to_bytes('{TEST_P2PKH_SCRIPT}') AS p2pkh,
+                hex_to_bytes('{TEST_P2WPKH_SCRIPT}') AS p2wpkh,
+                hex_to_bytes('{TEST_OP_RETURN_SCRIPT}') AS op_return
+        )
+
+        SELECT 1
+        FROM test_outputs o
+        WHERE
+            btc_script_type(o.p2pkh) = 'p2pkh' AND
+            btc_script_type(o.p2wpkh) = 'p2wpkh' AND
+            btc_script_type(o.op_return) = 'op_return'
+    "#
+    ));
+
+    let data = rule.verify(&ctx).await?;
+
+    assert!(data.matched);
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn btc_script_to_address() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let rule = test_sql_daemon(format!(
+        r#"
+        WITH test_outputs AS (
+            SELECT
+                hex_to_bytes('{TEST_P2PKH_SCRIPT}') AS p2pkh,
+                hex_to_bytes('{TEST_P2WPKH_SCRIPT}') AS p2wpkh,
+                hex_to_bytes('{TEST_OP_RETURN_SCRIPT}') AS op_return
+        )
+
+        SELECT 1
+        FROM test_outputs o
+        WHERE
+            btc_script_to_address(o.p2pkh, 'bitcoin') = '1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa' AND
+            btc_script_to_address(o.p2wpkh, 'testnet') = 'tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx' AND
+            btc_script_to_address(o.op_return, 'bitcoin') IS NULL
+    "#
+    ));
+
+    let data = rule.verify(&ctx).await?;
+
+    assert!(data.matched);
+
+    Ok(())
+}
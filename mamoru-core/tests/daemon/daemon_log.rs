@@ -0,0 +1,100 @@
+use test_log::test;
+
+use mamoru_core::assembly_script::{
+    AssemblyScriptExecutor, DaemonLog, ExecutorOptions, LogCapture, LogLevel, ModuleCache,
+};
+use mamoru_core::{Daemon, DataError, Executor};
+use mamoru_core_test_utils::test_blockchain_data::data_ctx;
+
+use super::plain_abi::ALLOC;
+
+/// Logs "message" `count` times with the given level.
+fn log_module(level: u32, count: u32) -> Vec<u8> {
+    let wat = format!(
+        r#"(module
+            (import "mamoru" "log" (func $log (param i32 i32 i32)))
+            {alloc}
+            (data (i32.const 0) "message")
+
+            (func (export "main")
+                (local $i i32)
+                (loop $continue
+                    (if (i32.lt_u (local.get $i) (i32.const {count}))
+                        (then
+                            (call $log (i32.const {level}) (i32.const 0) (i32.const 7))
+                            (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                            (br $continue))))))"#,
+        alloc = ALLOC,
+        level = level,
+        count = count,
+    );
+
+    wat::parse_str(wat).unwrap()
+}
+
+fn capturing_daemon(wasm: &[u8], log_capture: &LogCapture) -> Daemon {
+    let executor = AssemblyScriptExecutor::with_options(
+        wasm,
+        Default::default(),
+        Default::default(),
+        ModuleCache::global(),
+        ExecutorOptions {
+            log_capture: Some(log_capture.clone()),
+            ..Default::default()
+        },
+    )
+    .expect("Failed to create executor.");
+
+    Daemon::new("dummy".to_string(), Executor::AssemblyScript(executor))
+}
+
+#[test(tokio::test)]
+async fn logs_are_captured() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let log_capture = LogCapture::new();
+    let daemon = capturing_daemon(&log_module(1, 2), &log_capture);
+
+    daemon
+        .verify(&ctx)
+        .await
+        .expect("Failed to run Daemon::verify()");
+
+    let expected = DaemonLog {
+        level: LogLevel::Warn,
+        message: "message".to_string(),
+    };
+
+    assert_eq!(log_capture.take(), vec![expected.clone(), expected]);
+    assert!(log_capture.take().is_empty());
+}
+
+#[test(tokio::test)]
+async fn logs_are_limited_per_run() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let log_capture = LogCapture::new();
+    let daemon = capturing_daemon(&log_module(2, 1000), &log_capture);
+
+    for _ in 0..2 {
+        daemon
+            .verify(&ctx)
+            .await
+            .expect("Failed to run Daemon::verify()");
+
+        assert_eq!(log_capture.take().len(), 64);
+    }
+}
+
+#[test(tokio::test)]
+async fn invalid_log_level_fails() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let log_capture = LogCapture::new();
+    let daemon = capturing_daemon(&log_module(42, 1), &log_capture);
+
+    let result = daemon.verify(&ctx).await;
+
+    assert!(matches!(
+        result,
+        Err(DataError::WasmRuntime(err)) if err.message() == "Invalid log level 42"
+    ));
+    assert!(log_capture.take().is_empty());
+}
@@ -35,6 +35,26 @@ const TEST_TX_INPUT_3: &str = "0x5df1695e000000000000000000000000000000000000000
 // 3	isSushi	        bool	    false
 const TEST_TX_INPUT_4: &str = "0xd9627aa400000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000005ed639e1913010000000000000000000000000000000000000000000000000fdf4241253650ee00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002000000000000000000000000eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee0000000000000000000000004c11249814f11b9346808179cf06e71ac328c1b5869584cd000000000000000000000000382ffce2287252f930e1c8dc9328dac5bf282ba10000000000000000000000000000000000000000000000e453e5c29d64a56889";
 
+// Transfer(address indexed,address indexed,uint256)
+// 0	from	address	0x55fe002aeff02f77364de339a1292923a15844b8
+// 1	to	    address	0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48
+// 2	value	uint256	1200000000
+const TEST_TRANSFER_TOPIC_0: &str =
+    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+const TEST_TRANSFER_TOPIC_1: &str =
+    "0x00000000000000000000000055fe002aeff02f77364de339a1292923a15844b8";
+const TEST_TRANSFER_TOPIC_2: &str =
+    "0x000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+const TEST_TRANSFER_DATA: &str =
+    "0x0000000000000000000000000000000000000000000000000000000047868c00";
+
+// Error(string) ("Not enough balance")
+const TEST_REVERT_ERROR: &str = "0x08c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000124e6f7420656e6f7567682062616c616e63650000000000000000000000000000";
+
+// Panic(uint256) (0x11)
+const TEST_REVERT_PANIC: &str =
+    "0x4e487b710000000000000000000000000000000000000000000000000000000000000011";
+
 #[test(tokio::test)]
 async fn evm_parse_tx_input() -> Result<(), DataError> {
     let ctx = data_ctx("DUMMY_HASH");
@@ -337,3 +357,162 @@ async fn evm_as_array() -> Result<(), DataError> {
 
     Ok(())
 }
+
+#[test(tokio::test)]
+async fn evm_parse_event() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let rule = test_sql_daemon(format!(
+        r#"
+        WITH test_events AS (
+            SELECT
+                hex_to_bytes('{TEST_TRANSFER_TOPIC_0}') AS topic0,
+                hex_to_bytes('{TEST_TRANSFER_TOPIC_1}') AS topic1,
+                hex_to_bytes('{TEST_TRANSFER_TOPIC_2}') AS topic2,
+                hex_to_bytes('0x') AS topic3,
+                hex_to_bytes('{TEST_TRANSFER_DATA}') AS data
+        ),
+
+        transfers AS (
+            SELECT
+                evm_parse_event(e.topic0, e.topic1, e.topic2, e.topic3, e.data, 'Transfer(address indexed,address indexed,uint256)') AS transfer
+            FROM test_events e
+        )
+
+        SELECT 1
+        FROM transfers t
+        WHERE
+            evm_as_address(evm_take_token(t.transfer, 0)) = '0x55fe002aeff02f77364de339a1292923a15844b8'
+        AND
+            evm_as_address(evm_take_token(t.transfer, 1)) = '0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48'
+        AND
+            evm_as_uint256(evm_take_token(t.transfer, 2)) = u256_from_str('1200000000')
+    "#
+    ));
+
+    let data = rule.verify(&ctx).await?;
+
+    assert!(data.matched);
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn evm_parse_event_indexed_mismatch() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let rule = test_sql_daemon(format!(
+        r#"
+        WITH test_events AS (
+            SELECT
+                hex_to_bytes('{TEST_TRANSFER_TOPIC_0}') AS topic0,
+                hex_to_bytes('{TEST_TRANSFER_TOPIC_1}') AS topic1,
+                hex_to_bytes('{TEST_TRANSFER_TOPIC_2}') AS topic2,
+                hex_to_bytes('0x') AS topic3,
+                hex_to_bytes('{TEST_TRANSFER_DATA}') AS data
+        )
+
+        SELECT 1
+        FROM test_events e
+        WHERE
+            evm_parse_event(e.topic0, e.topic1, e.topic2, e.topic3, e.data, 'Transfer(address indexed,address indexed,uint256 indexed)') IS NOT NULL
+    "#
+    ));
+
+    let data = rule.verify(&ctx).await?;
+
+    assert!(!data.matched);
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn evm_parse_event_wrong_signature() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let rule = test_sql_daemon(format!(
+        r#"
+        WITH test_events AS (
+            SELECT
+                hex_to_bytes('{TEST_TRANSFER_TOPIC_0}') AS topic0,
+                hex_to_bytes('{TEST_TRANSFER_TOPIC_1}') AS topic1,
+                hex_to_bytes('{TEST_TRANSFER_TOPIC_2}') AS topic2,
+                hex_to_bytes('0x') AS topic3,
+                hex_to_bytes('{TEST_TRANSFER_DATA}') AS data
+        )
+
+        SELECT 1
+        FROM test_events e
+        WHERE
+            evm_parse_event(e.topic0, e.topic1, e.topic2, e.topic3, e.data, 'Approval(address indexed,address indexed,uint256)') IS NOT NULL
+    "#
+    ));
+
+    let data = rule.verify(&ctx).await?;
+
+    assert!(!data.matched);
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn evm_decode_revert() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let rule = test_sql_daemon(format!(
+        r#"
+        WITH test_outputs AS (
+            SELECT
+                hex_to_bytes('{TEST_REVERT_ERROR}') AS error,
+                hex_to_bytes('{TEST_REVERT_PANIC}') AS panic,
+                hex_to_bytes('0x') AS empty
+        )
+
+        SELECT 1
+        FROM test_outputs o
+        WHERE
+            evm_decode_revert(o.error) = 'Not enough balance' AND
+            evm_decode_revert(o.panic) = 'Panic(0x11)' AND
+            evm_decode_revert(o.empty) IS NULL
+    "#
+    ));
+
+    let data = rule.verify(&ctx).await?;
+
+    assert!(data.matched);
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn evm_selector() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let rule = test_sql_daemon(
+        r#"
+        SELECT 1
+        WHERE
+            bytes_to_hex(evm_selector('transfer(address,uint256)')) = '0xa9059cbb'
+    "#,
+    );
+
+    let data = rule.verify(&ctx).await?;
+
+    assert!(data.matched);
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn evm_checksum_address() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let rule = test_sql_daemon(
+        r#"
+        SELECT 1
+        WHERE
+            evm_checksum_address('0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed') = '0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed' AND
+            evm_checksum_address('not-an-address') IS NULL
+    "#,
+    );
+
+    let data = rule.verify(&ctx).await?;
+
+    assert!(data.matched);
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use test_log::test;
+
+use mamoru_core::assembly_script::{AssemblyScriptExecutor, ExecutorOptions, ModuleCache};
+use mamoru_core::IncidentLimit;
+use mamoru_core_test_utils::test_blockchain_data::data_ctx;
+
+use super::plain_abi::{ALLOC, INCIDENT_JSON};
+
+/// Reports an incident if an internal global or the memory keeps the state of the previous run.
+fn stateful_module(grow_memory: bool) -> Vec<u8> {
+    let wat = format!(
+        r#"(module
+            (import "mamoru" "report" (func $report (param i32 i32)))
+            {alloc}
+            (global $runs (mut i32) (i32.const 0))
+            (data (i32.const 1024) "{incident}")
+
+            (func (export "main")
+                (global.set $runs (i32.add (global.get $runs) (i32.const 1)))
+                (i32.store8 (i32.const 2048) (i32.add (i32.load8_u (i32.const 2048)) (i32.const 1)))
+                {grow}
+
+                (if (i32.or
+                        (i32.ne (global.get $runs) (i32.const 1))
+                        (i32.ne (i32.load8_u (i32.const 2048)) (i32.const 1)))
+                    (then (call $report (i32.const 1024) (i32.const {incident_len}))))))"#,
+        alloc = ALLOC,
+        incident = INCIDENT_JSON,
+        incident_len = INCIDENT_JSON.replace('\\', "").len(),
+        grow = if grow_memory {
+            "(drop (memory.grow (i32.const 1)))"
+        } else {
+            ""
+        },
+    );
+
+    wat::parse_str(wat).unwrap()
+}
+
+fn executor(wasm: &[u8], pool_size: usize) -> AssemblyScriptExecutor {
+    AssemblyScriptExecutor::with_options(
+        wasm,
+        Default::default(),
+        Default::default(),
+        ModuleCache::global(),
+        ExecutorOptions {
+            pool_size,
+            ..Default::default()
+        },
+    )
+    .expect("Failed to create executor.")
+}
+
+#[test(tokio::test)]
+async fn pooled_instance_is_restored() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let executor = executor(&stateful_module(false), 1);
+
+    for _ in 0..3 {
+        let incidents = executor
+            .execute(&ctx, IncidentLimit::default())
+            .await
+            .expect("Failed to execute.");
+
+        assert!(incidents.is_empty());
+        assert_eq!(executor.pooled_instances(), 1);
+    }
+}
+
+#[test(tokio::test)]
+async fn grown_instance_is_dropped() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let executor = executor(&stateful_module(true), 1);
+
+    for _ in 0..3 {
+        let incidents = executor
+            .execute(&ctx, IncidentLimit::default())
+            .await
+            .expect("Failed to execute.");
+
+        assert!(incidents.is_empty());
+        assert_eq!(executor.pooled_instances(), 0);
+    }
+}
+
+#[test(tokio::test)]
+async fn pooling_disabled() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let executor = executor(&stateful_module(false), 0);
+
+    for _ in 0..3 {
+        let incidents = executor
+            .execute(&ctx, IncidentLimit::default())
+            .await
+            .expect("Failed to execute.");
+
+        assert!(incidents.is_empty());
+        assert_eq!(executor.pooled_instances(), 0);
+    }
+}
@@ -6,7 +6,14 @@ use mamoru_core::Version;
 use mamoru_core::{Daemon, DaemonParameters, DataError, IncidentData, IncidentSeverity};
 
 mod assembly_script;
+mod btc_udf;
+mod daemon_log;
 mod evm_udf;
+mod instance_pool;
+mod module_cache;
+mod native;
+mod plain_abi;
+mod sdk_versions;
 mod sql;
 mod udf;
 
@@ -0,0 +1,134 @@
+use std::fs;
+
+use mamoru_core::assembly_script::{ModuleCache, ModuleCacheStats};
+
+fn module_wasm(export: &str) -> Vec<u8> {
+    wat::parse_str(format!(r#"(module (func (export "{}")))"#, export)).unwrap()
+}
+
+#[test]
+fn memory_cache_skips_compilation() {
+    let cache = ModuleCache::new(None);
+    let wasm = module_wasm("main");
+
+    cache.get_or_compile(&wasm).unwrap();
+    cache.get_or_compile(&wasm).unwrap();
+    cache.get_or_compile(&module_wasm("other")).unwrap();
+
+    assert_eq!(
+        cache.stats(),
+        ModuleCacheStats {
+            memory_hits: 1,
+            disk_hits: 0,
+            compiled: 2,
+        }
+    );
+}
+
+#[test]
+fn disk_cache_survives_restarts() {
+    let dir = tempfile::tempdir().unwrap();
+    let wasm = module_wasm("main");
+
+    let cache = ModuleCache::new(Some(dir.path().to_path_buf()));
+    cache.get_or_compile(&wasm).unwrap();
+
+    assert!(cache.artifact_path(&wasm).unwrap().exists());
+
+    let restarted = ModuleCache::new(Some(dir.path().to_path_buf()));
+    let module = restarted.get_or_compile(&wasm).unwrap();
+
+    assert!(module.exports().any(|export| export.name() == "main"));
+    assert_eq!(
+        restarted.stats(),
+        ModuleCacheStats {
+            memory_hits: 0,
+            disk_hits: 1,
+            compiled: 0,
+        }
+    );
+}
+
+#[test]
+fn corrupted_artifact_is_recompiled() {
+    let dir = tempfile::tempdir().unwrap();
+    let wasm = module_wasm("main");
+
+    let cache = ModuleCache::new(Some(dir.path().to_path_buf()));
+    cache.get_or_compile(&wasm).unwrap();
+
+    let path = cache.artifact_path(&wasm).unwrap();
+    let mut artifact = fs::read(&path).unwrap();
+    let last = artifact.len() - 1;
+    artifact[last] ^= 0xff;
+    fs::write(&path, artifact).unwrap();
+
+    let restarted = ModuleCache::new(Some(dir.path().to_path_buf()));
+    restarted.get_or_compile(&wasm).unwrap();
+
+    assert_eq!(
+        restarted.stats(),
+        ModuleCacheStats {
+            memory_hits: 0,
+            disk_hits: 0,
+            compiled: 1,
+        }
+    );
+
+    // the artifact is stored again
+    let restarted = ModuleCache::new(Some(dir.path().to_path_buf()));
+    restarted.get_or_compile(&wasm).unwrap();
+
+    assert_eq!(restarted.stats().disk_hits, 1);
+}
+
+#[test]
+fn invalid_wasm_is_not_cached() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = ModuleCache::new(Some(dir.path().to_path_buf()));
+
+    assert!(cache.get_or_compile(b"not wasm").is_err());
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn memory_cache_evicts_least_recently_used() {
+    let cache = ModuleCache::new(None).with_max_modules(2);
+    let (first, second, third) = (
+        module_wasm("first"),
+        module_wasm("second"),
+        module_wasm("third"),
+    );
+
+    cache.get_or_compile(&first).unwrap();
+    cache.get_or_compile(&second).unwrap();
+    cache.get_or_compile(&first).unwrap();
+
+    // evicts `second`, used before `first`
+    cache.get_or_compile(&third).unwrap();
+    cache.get_or_compile(&first).unwrap();
+    cache.get_or_compile(&second).unwrap();
+
+    assert_eq!(
+        cache.stats(),
+        ModuleCacheStats {
+            memory_hits: 2,
+            disk_hits: 0,
+            compiled: 4,
+        }
+    );
+}
+
+#[test]
+fn disk_cache_removes_artifacts_over_size_limit() {
+    let dir = tempfile::tempdir().unwrap();
+    let (first, second) = (module_wasm("first"), module_wasm("second"));
+
+    let cache = ModuleCache::new(Some(dir.path().to_path_buf())).with_max_disk_size(1);
+    cache.get_or_compile(&first).unwrap();
+    cache.get_or_compile(&second).unwrap();
+
+    assert!(!cache.artifact_path(&first).unwrap().exists());
+    assert!(cache.artifact_path(&second).unwrap().exists());
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+}
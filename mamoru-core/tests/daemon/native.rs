@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use datafusion::prelude::SessionContext;
+use maplit::hashmap;
+use test_log::test;
+
+use mamoru_core::{
+    BlockchainCtx, BlockchainData, BlockchainSpecificImports, BlockchainTableItem, Daemon,
+    DaemonParameters, DataError, Incident, IncidentLimit, IncidentOverflow, IncidentSeverity,
+    NativeDaemon, NativeDaemonRegistry,
+};
+use mamoru_core_test_utils::test_blockchain_data::{data_ctx, TestCtx};
+
+/// Reports the call traces of the function from the `function` parameter.
+struct CallTraceDetector {
+    function: String,
+}
+
+#[async_trait]
+impl NativeDaemon<TestCtx> for CallTraceDetector {
+    async fn verify(
+        &self,
+        ctx: &BlockchainData<TestCtx>,
+        session: &SessionContext,
+    ) -> Result<Vec<Incident>, DataError> {
+        let batches = session
+            .sql("SELECT t.digest FROM transactions t")
+            .await
+            .map_err(DataError::PlanQuery)?
+            .collect()
+            .await
+            .map_err(DataError::ExecuteQuery)?;
+
+        assert_eq!(
+            batches.iter().map(|batch| batch.num_rows()).sum::<usize>(),
+            1
+        );
+
+        Ok(ctx
+            .data()
+            .call_traces
+            .iter()
+            .filter(|call_trace| call_trace.function == self.function)
+            .map(|call_trace| Incident {
+                severity: IncidentSeverity::Alert,
+                message: format!("{} is called", call_trace.function),
+                tx_hash: Default::default(),
+                address: Default::default(),
+                data: vec![],
+                metadata: None,
+            })
+            .collect())
+    }
+}
+
+fn registry() -> NativeDaemonRegistry {
+    let mut registry = NativeDaemonRegistry::default();
+
+    registry.register::<TestCtx, _, _>("call_trace", |parameters: &DaemonParameters| {
+        let function = parameters.get("function").cloned().unwrap_or_default();
+
+        Ok(CallTraceDetector { function })
+    });
+
+    registry
+}
+
+#[test(tokio::test)]
+async fn native_daemon_reports_incidents() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let parameters = hashmap! { "function".to_string() => "func1".to_string() };
+
+    let daemon =
+        Daemon::new_native::<TestCtx>("dummy".to_string(), &registry(), "call_trace", &parameters)?;
+    let data = daemon.verify(&ctx).await?;
+
+    assert!(data.matched);
+    assert_eq!(data.incidents.len(), 1);
+    assert_eq!(data.incidents[0].message, "func1 is called");
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn native_daemon_respects_incident_limit() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let parameters = hashmap! { "function".to_string() => "func1".to_string() };
+
+    let daemon =
+        Daemon::new_native::<TestCtx>("dummy".to_string(), &registry(), "call_trace", &parameters)?
+            .with_incident_limit(IncidentLimit {
+                max_incidents: 0,
+                overflow: IncidentOverflow::Truncate,
+            });
+    let data = daemon.verify(&ctx).await?;
+
+    assert_eq!(data.incidents.len(), 1);
+    assert_eq!(
+        data.incidents[0].message,
+        "1 incidents suppressed, the limit is 0"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn unknown_native_daemon_fails() {
+    let result = Daemon::new_native::<TestCtx>(
+        "dummy".to_string(),
+        &registry(),
+        "mev_sandwich",
+        &DaemonParameters::new(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(DataError::UnknownNativeDaemon(kind)) if kind == "mev_sandwich"
+    ));
+}
+
+/// A blockchain the `call_trace` daemon is not registered for.
+struct OtherCtx;
+
+impl BlockchainCtx for OtherCtx {
+    fn empty() -> Self {
+        Self
+    }
+
+    fn module() -> &'static str {
+        "mamoru_other"
+    }
+
+    fn imports() -> BlockchainSpecificImports<Self> {
+        hashmap! {}
+    }
+
+    fn as_tables(&self) -> Vec<Box<dyn BlockchainTableItem>> {
+        vec![]
+    }
+}
+
+#[test]
+fn native_daemon_of_another_blockchain_fails() {
+    let result = Daemon::new_native::<OtherCtx>(
+        "dummy".to_string(),
+        &registry(),
+        "call_trace",
+        &DaemonParameters::new(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(DataError::NativeDaemonCtx(kind)) if kind == "call_trace"
+    ));
+}
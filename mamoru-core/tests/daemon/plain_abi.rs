@@ -0,0 +1,505 @@
+use expect_test::expect;
+use test_log::test;
+
+use mamoru_core::assembly_script::abi::WasmAbi;
+use mamoru_core::{Daemon, DaemonParameters, DataError, IncidentSeverity};
+use mamoru_core_test_utils::test_blockchain_data::data_ctx;
+
+pub const INCIDENT_JSON: &str = r#"{\"severity\":\"alert\",\"message\":\"Plain ABI\"}"#;
+
+/// A bump allocator, the memory is never freed during a single run.
+pub const ALLOC: &str = r#"
+    (memory (export "memory") 1)
+    (global $heap (mut i32) (i32.const 4096))
+
+    (func (export "mamoru_alloc") (param $size i32) (result i32)
+        (local $ptr i32)
+        (local.set $ptr (global.get $heap))
+        (global.set $heap (i32.add (global.get $heap) (local.get $size)))
+        (local.get $ptr))
+"#;
+
+fn plain_daemon(wat: &str, parameters: DaemonParameters) -> Result<Daemon, DataError> {
+    let wasm = wat::parse_str(wat).expect("Failed to parse WAT.");
+
+    Daemon::new_assembly_script("dummy".to_string(), wasm, parameters, Default::default())
+}
+
+/// Reports an incident if the query returns any rows.
+fn query_module(query: &str) -> String {
+    format!(
+        r#"(module
+            (import "mamoru" "query" (func $query (param i32 i32) (result i64)))
+            (import "mamoru" "report" (func $report (param i32 i32)))
+            {alloc}
+            (data (i32.const 0) "{query}")
+            (data (i32.const 1024) "{incident}")
+
+            (func (export "main")
+                ;; the result is "[]" if there are no rows
+                (if (i32.gt_u (i32.wrap_i64 (call $query (i32.const 0) (i32.const {query_len}))) (i32.const 2))
+                    (then (call $report (i32.const 1024) (i32.const {incident_len}))))))"#,
+        alloc = ALLOC,
+        query = query,
+        query_len = query.len(),
+        incident = INCIDENT_JSON,
+        incident_len = INCIDENT_JSON.replace('\\', "").len(),
+    )
+}
+
+#[test(tokio::test)]
+async fn query_and_report() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let query = "SELECT t.seq FROM transactions t WHERE t.digest = 'DUMMY_HASH'";
+
+    let daemon = plain_daemon(&query_module(query), DaemonParameters::new())
+        .expect("Failed to create daemon.");
+
+    let result = daemon
+        .verify(&ctx)
+        .await
+        .expect("Failed to run Daemon::verify()");
+
+    assert!(result.matched);
+    assert_eq!(result.incidents.len(), 1);
+    assert_eq!(result.incidents[0].severity, IncidentSeverity::Alert);
+    assert_eq!(result.incidents[0].message, "Plain ABI");
+}
+
+#[test(tokio::test)]
+async fn query_no_rows() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let query = "SELECT t.seq FROM transactions t WHERE t.digest = 'OTHER_HASH'";
+
+    let daemon = plain_daemon(&query_module(query), DaemonParameters::new())
+        .expect("Failed to create daemon.");
+
+    let result = daemon
+        .verify(&ctx)
+        .await
+        .expect("Failed to run Daemon::verify()");
+
+    assert!(!result.matched);
+}
+
+/// Reports an incident if the MessagePack result of `query_v2` has any rows.
+fn query_v2_module(query: &str) -> String {
+    format!(
+        r#"(module
+            (import "mamoru" "query_v2" (func $query_v2 (param i32 i32 i32) (result i64)))
+            (import "mamoru" "report" (func $report (param i32 i32)))
+            {alloc}
+            (data (i32.const 0) "{query}")
+            (data (i32.const 1024) "{incident}")
+
+            (func (export "main")
+                (local $result i64)
+                (local $last i32)
+                (local.set $result (call $query_v2 (i32.const 0) (i32.const {query_len}) (i32.const 0)))
+                (local.set $last
+                    (i32.sub
+                        (i32.add
+                            (i32.wrap_i64 (i64.shr_u (local.get $result) (i64.const 32)))
+                            (i32.wrap_i64 (local.get $result)))
+                        (i32.const 1)))
+                ;; the rows are the last field, an empty array is encoded as 0x90
+                (if (i32.ne (i32.load8_u (local.get $last)) (i32.const 0x90))
+                    (then (call $report (i32.const 1024) (i32.const {incident_len}))))))"#,
+        alloc = ALLOC,
+        query = query,
+        query_len = query.len(),
+        incident = INCIDENT_JSON,
+        incident_len = INCIDENT_JSON.replace('\\', "").len(),
+    )
+}
+
+#[test(tokio::test)]
+async fn query_v2_message_pack() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let query = "SELECT t.seq FROM transactions t WHERE t.digest = 'DUMMY_HASH'";
+
+    let daemon = plain_daemon(&query_v2_module(query), DaemonParameters::new())
+        .expect("Failed to create daemon.");
+
+    let result = daemon
+        .verify(&ctx)
+        .await
+        .expect("Failed to run Daemon::verify()");
+
+    assert_eq!(result.incidents.len(), 1);
+}
+
+#[test(tokio::test)]
+async fn query_v2_no_rows() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let query = "SELECT t.seq FROM transactions t WHERE t.digest = 'OTHER_HASH'";
+
+    let daemon = plain_daemon(&query_v2_module(query), DaemonParameters::new())
+        .expect("Failed to create daemon.");
+
+    let result = daemon
+        .verify(&ctx)
+        .await
+        .expect("Failed to run Daemon::verify()");
+
+    assert!(result.incidents.is_empty());
+}
+
+#[test(tokio::test)]
+async fn keccak256() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let wat = format!(
+        r#"(module
+            (import "mamoru" "keccak256" (func $keccak256 (param i32 i32) (result i64)))
+            (import "mamoru" "report" (func $report (param i32 i32)))
+            {alloc}
+            (data (i32.const 1024) "{incident}")
+
+            (func (export "main")
+                (local $hash i64)
+                (local.set $hash (call $keccak256 (i32.const 0) (i32.const 0)))
+                ;; keccak256("") starts with 0xc5
+                (if (i32.and
+                        (i32.eq (i32.wrap_i64 (local.get $hash)) (i32.const 32))
+                        (i32.eq
+                            (i32.load8_u (i32.wrap_i64 (i64.shr_u (local.get $hash) (i64.const 32))))
+                            (i32.const 0xc5)))
+                    (then (call $report (i32.const 1024) (i32.const {incident_len}))))))"#,
+        alloc = ALLOC,
+        incident = INCIDENT_JSON,
+        incident_len = INCIDENT_JSON.replace('\\', "").len(),
+    );
+
+    let daemon = plain_daemon(&wat, DaemonParameters::new()).expect("Failed to create daemon.");
+
+    let result = daemon
+        .verify(&ctx)
+        .await
+        .expect("Failed to run Daemon::verify()");
+
+    assert_eq!(result.incidents.len(), 1);
+}
+
+/// Escapes bytes for a WAT data segment.
+fn wat_bytes(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("\\{:02x}", byte)).collect()
+}
+
+/// Reports an incident if `call` returns the `expected` bytes.
+///
+/// `call` is an expression of the `import` function result, `data` is placed at offset 0.
+fn expect_bytes_module(import: &str, data: &[u8], call: &str, expected: &[u8]) -> String {
+    format!(
+        r#"(module
+            {import}
+            (import "mamoru" "report" (func $report (param i32 i32)))
+            {alloc}
+            (data (i32.const 0) "{data}")
+            (data (i32.const 512) "{expected}")
+            (data (i32.const 1024) "{incident}")
+
+            (func (export "main")
+                (local $result i64)
+                (local $ptr i32)
+                (local $i i32)
+                (local.set $result {call})
+                (local.set $ptr (i32.wrap_i64 (i64.shr_u (local.get $result) (i64.const 32))))
+                (if (i32.ne (i32.wrap_i64 (local.get $result)) (i32.const {expected_len}))
+                    (then (return)))
+                (block $done
+                    (loop $next
+                        (br_if $done (i32.eq (local.get $i) (i32.const {expected_len})))
+                        (if (i32.ne
+                                (i32.load8_u (i32.add (local.get $ptr) (local.get $i)))
+                                (i32.load8_u (i32.add (i32.const 512) (local.get $i))))
+                            (then (return)))
+                        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                        (br $next)))
+                (call $report (i32.const 1024) (i32.const {incident_len}))))"#,
+        import = import,
+        alloc = ALLOC,
+        data = wat_bytes(data),
+        expected = wat_bytes(expected),
+        call = call,
+        expected_len = expected.len(),
+        incident = INCIDENT_JSON,
+        incident_len = INCIDENT_JSON.replace('\\', "").len(),
+    )
+}
+
+async fn reported_incidents(wat: &str) -> usize {
+    let ctx = data_ctx("DUMMY_HASH");
+    let daemon = plain_daemon(wat, DaemonParameters::new()).expect("Failed to create daemon.");
+
+    let result = daemon
+        .verify(&ctx)
+        .await
+        .expect("Failed to run Daemon::verify()");
+
+    result.incidents.len()
+}
+
+#[test(tokio::test)]
+async fn hashes() {
+    let cases = [
+        (
+            "sha256",
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        ),
+        (
+            "blake2b",
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319",
+        ),
+    ];
+
+    for (name, expected) in cases {
+        let wat = expect_bytes_module(
+            &format!(
+                r#"(import "mamoru" "{name}" (func $hash (param i32 i32) (result i64)))"#,
+                name = name
+            ),
+            b"abc",
+            "(call $hash (i32.const 0) (i32.const 3))",
+            &hex::decode(expected).unwrap(),
+        );
+
+        assert_eq!(reported_incidents(&wat).await, 1, "{}", name);
+    }
+}
+
+#[test(tokio::test)]
+async fn ecrecover() {
+    use k256::ecdsa::{Signature, SigningKey};
+    use sha3::{Digest, Keccak256};
+
+    const IMPORT: &str =
+        r#"(import "mamoru" "ecrecover" (func $ecrecover (param i32 i32 i32 i32) (result i64)))"#;
+    const CALL: &str =
+        "(call $ecrecover (i32.const 0) (i32.const 32) (i32.const 32) (i32.const 65))";
+
+    let hash = Keccak256::digest(b"mamoru");
+    let key = SigningKey::from_slice(&[1; 32]).unwrap();
+    let (signature, recovery_id) = key.sign_prehash_recoverable(&hash).unwrap();
+
+    let public = key.verifying_key().to_encoded_point(false);
+    let address = Keccak256::digest(&public.as_bytes()[1..])[12..].to_vec();
+
+    // The same signature in the high-S form `(r, n - s)` with the flipped parity.
+    let (r, s) = signature.split_scalars();
+    let high_s = Signature::from_scalars(r, -s).unwrap();
+
+    let signatures = [
+        (signature, recovery_id.to_byte() + 27),
+        (high_s, (recovery_id.to_byte() ^ 1) + 27),
+    ];
+
+    for (signature, v) in signatures {
+        let mut data = hash.to_vec();
+        data.extend_from_slice(&signature.to_bytes());
+        data.push(v);
+
+        let wat = expect_bytes_module(IMPORT, &data, CALL, &address);
+
+        assert_eq!(reported_incidents(&wat).await, 1);
+    }
+
+    // Invalid signatures return the `0` pointer.
+    let wat = expect_bytes_module(IMPORT, &[0; 97], CALL, &[]);
+
+    assert_eq!(reported_incidents(&wat).await, 1);
+}
+
+#[test(tokio::test)]
+async fn report_incident_v2() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let incident = r#"{"severity":"alert","message":"Plain ABI","title":"Exploit","tags":["defi"],"fingerprint":"fp"}"#;
+
+    let wat = format!(
+        r#"(module
+            (import "mamoru" "report" (func $report (param i32 i32)))
+            {alloc}
+            (data (i32.const 0) "{incident}")
+
+            (func (export "main")
+                (call $report (i32.const 0) (i32.const {incident_len}))))"#,
+        alloc = ALLOC,
+        incident = incident.replace('"', "\\\""),
+        incident_len = incident.len(),
+    );
+
+    let daemon = plain_daemon(&wat, DaemonParameters::new()).expect("Failed to create daemon.");
+
+    let result = daemon
+        .verify(&ctx)
+        .await
+        .expect("Failed to run Daemon::verify()");
+
+    assert_eq!(result.incidents.len(), 1);
+
+    let metadata = result.incidents[0]
+        .metadata
+        .as_ref()
+        .expect("Missing incident metadata.");
+
+    assert_eq!(metadata.title, "Exploit");
+    assert_eq!(metadata.tags, vec!["defi".to_string()]);
+    assert_eq!(metadata.fingerprint, "fp");
+}
+
+#[test(tokio::test)]
+async fn unversioned_v1_report_is_unchanged() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let wat = format!(
+        r#"(module
+            (import "mamoru" "report" (func $report (param i32 i32)))
+            {alloc}
+            (data (i32.const 0) "{incident}")
+
+            (func (export "main")
+                (call $report (i32.const 0) (i32.const {incident_len}))))"#,
+        alloc = ALLOC,
+        incident = INCIDENT_JSON,
+        incident_len = INCIDENT_JSON.replace('\\', "").len(),
+    );
+
+    // No SDK version is declared, so the newest `report` accepting `IncidentV2` is linked.
+    let daemon = plain_daemon(&wat, DaemonParameters::new()).expect("Failed to create daemon.");
+
+    let mut result = daemon
+        .verify(&ctx)
+        .await
+        .expect("Failed to run Daemon::verify()");
+
+    assert_eq!(result.incidents.len(), 1);
+
+    expect![[r#"
+        Incident {
+            severity: Alert,
+            message: "Plain ABI",
+            tx_hash: "",
+            address: "",
+            data: [],
+            metadata: None,
+        }
+    "#]]
+    .assert_debug_eq(&result.incidents.remove(0).into_v1());
+}
+
+#[test(tokio::test)]
+async fn report_parameter() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let wat = format!(
+        r#"(module
+            (import "mamoru" "parameter" (func $parameter (param i32 i32) (result i64)))
+            (import "mamoru" "report" (func $report (param i32 i32)))
+            {alloc}
+            (data (i32.const 0) "incident")
+
+            (func (export "main")
+                (local $value i64)
+                (local.set $value (call $parameter (i32.const 0) (i32.const 8)))
+                (call $report
+                    (i32.wrap_i64 (i64.shr_u (local.get $value) (i64.const 32)))
+                    (i32.wrap_i64 (local.get $value)))))"#,
+        alloc = ALLOC,
+    );
+
+    let daemon = plain_daemon(
+        &wat,
+        maplit::hashmap! {
+            "incident".to_string() => INCIDENT_JSON.replace('\\', ""),
+        },
+    )
+    .expect("Failed to create daemon.");
+
+    let result = daemon
+        .verify(&ctx)
+        .await
+        .expect("Failed to run Daemon::verify()");
+
+    assert_eq!(result.incidents.len(), 1);
+    assert_eq!(result.incidents[0].message, "Plain ABI");
+}
+
+#[test(tokio::test)]
+async fn start_function_import_call_fails() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let wat = format!(
+        r#"(module
+            (import "mamoru" "report" (func $report (param i32 i32)))
+            {alloc}
+            (data (i32.const 1024) "{incident}")
+
+            (func $init
+                (call $report (i32.const 1024) (i32.const {incident_len})))
+            (start $init)
+
+            (func (export "main")))"#,
+        alloc = ALLOC,
+        incident = INCIDENT_JSON,
+        incident_len = INCIDENT_JSON.replace('\\', "").len(),
+    );
+
+    let daemon = plain_daemon(&wat, DaemonParameters::new()).expect("Failed to create daemon.");
+
+    match daemon.verify(&ctx).await {
+        Err(DataError::WasmInit(err)) => {
+            assert!(err
+                .to_string()
+                .contains("Host function is called outside of the daemon entrypoint"));
+        }
+        result => panic!("Expected WasmInit error, got {:?}", result),
+    }
+}
+
+#[test]
+fn abi_is_detected_from_exports() {
+    let wasm = wat::parse_str(format!(r#"(module {})"#, ALLOC)).unwrap();
+    let module = wasmer::Module::new(&wasmer::Store::default(), wasm).unwrap();
+
+    assert_eq!(WasmAbi::detect(&module).unwrap(), WasmAbi::Plain);
+
+    let wasm = wat::parse_str(r#"(module (memory (export "memory") 1))"#).unwrap();
+    let module = wasmer::Module::new(&wasmer::Store::default(), wasm).unwrap();
+
+    assert_eq!(WasmAbi::detect(&module).unwrap(), WasmAbi::AssemblyScript);
+}
+
+#[test(tokio::test)]
+async fn abi_is_detected_from_custom_section() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let wat = r#"(module
+        (@custom "mamoru_abi" "plain")
+        (memory (export "memory") 1)
+        (func (export "main")))"#;
+
+    let daemon = plain_daemon(wat, DaemonParameters::new()).expect("Failed to create daemon.");
+    let result = daemon.verify(&ctx).await;
+
+    expect![[r#"
+        Err(
+            WasmExport {
+                source: Missing(
+                    "mamoru_alloc",
+                ),
+                export: "mamoru_alloc",
+            },
+        )
+    "#]]
+    .assert_debug_eq(&result);
+}
+
+#[test]
+fn unknown_abi_fails() {
+    let wat = r#"(module (@custom "mamoru_abi" "wasi"))"#;
+    let result = plain_daemon(wat, DaemonParameters::new());
+
+    expect![[r#"
+        Err(
+            WasmUnsupportedAbi(
+                "wasi",
+            ),
+        )
+    "#]]
+    .assert_debug_eq(&result);
+}
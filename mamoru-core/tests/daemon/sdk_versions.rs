@@ -0,0 +1,173 @@
+use expect_test::expect;
+use test_log::test;
+
+use mamoru_core::assembly_script::{MAMORU_EVM_SDK, MAMORU_SDK};
+use mamoru_core::{Daemon, DaemonVersions, DataError};
+use mamoru_core_test_utils::test_blockchain_data::data_ctx;
+
+use super::plain_abi::ALLOC;
+
+/// An AssemblyScript ABI module importing `mamoru.log`, which is available since SDK 0.8.0.
+const LOG_IMPORT_WAT: &str = r#"(module
+    (import "mamoru" "log" (func $log (param i32 i32)))
+    (memory (export "memory") 1)
+
+    (func (export "__new") (param i32 i32) (result i32) (i32.const 1024))
+    (func (export "__pin") (param i32) (result i32) (local.get 0))
+    (func (export "__unpin") (param i32))
+    (func (export "__collect"))
+    (func (export "main")))"#;
+
+fn versions(sdk: &str, version: &str) -> DaemonVersions {
+    maplit::hashmap! {
+        sdk.to_string() => version.parse().unwrap(),
+    }
+}
+
+fn log_import_daemon(versions: DaemonVersions) -> Result<Daemon, DataError> {
+    let wasm = wat::parse_str(LOG_IMPORT_WAT).unwrap();
+
+    Daemon::new_assembly_script("dummy".to_string(), wasm, Default::default(), versions)
+}
+
+#[test]
+fn unsupported_version_fails() {
+    let result = log_import_daemon(versions(MAMORU_SDK, "1.0.0"));
+
+    expect![[r#"
+        Err(
+            UnsupportedSdkVersion {
+                sdk: "mamoru",
+                version: Version {
+                    major: 1,
+                    minor: 0,
+                    patch: 0,
+                },
+                supported: ">=0.1.0, <0.12.0",
+            },
+        )
+    "#]]
+    .assert_debug_eq(&result);
+}
+
+#[test]
+fn unknown_sdk_is_ignored() {
+    let result = log_import_daemon(versions("unknown_sdk", "42.0.0"));
+
+    assert!(result.is_ok());
+}
+
+#[test(tokio::test)]
+async fn import_is_linked_for_supported_versions() {
+    let ctx = data_ctx("DUMMY_HASH");
+
+    for versions in [versions(MAMORU_SDK, "0.8.0"), DaemonVersions::new()] {
+        let daemon = log_import_daemon(versions).expect("Failed to create daemon.");
+        let result = daemon.verify(&ctx).await;
+
+        assert!(result.is_ok(), "{:?}", result);
+    }
+}
+
+#[test]
+fn import_is_not_linked_for_older_versions() {
+    let result = log_import_daemon(versions(MAMORU_SDK, "0.7.1"));
+
+    expect![[r#"
+        Err(
+            UnsupportedImport {
+                module: "mamoru",
+                name: "log",
+                sdk: "mamoru",
+                version: Version {
+                    major: 0,
+                    minor: 7,
+                    patch: 1,
+                },
+            },
+        )
+    "#]]
+    .assert_debug_eq(&result);
+}
+
+#[test]
+fn unknown_import_fails() {
+    let wat = LOG_IMPORT_WAT.replace(r#""mamoru" "log""#, r#""mamoru" "unknown""#);
+    let wasm = wat::parse_str(wat).unwrap();
+
+    let result = Daemon::new_assembly_script(
+        "dummy".to_string(),
+        wasm,
+        Default::default(),
+        DaemonVersions::new(),
+    );
+
+    expect![[r#"
+        Err(
+            UnknownImport {
+                module: "mamoru",
+                name: "unknown",
+            },
+        )
+    "#]]
+    .assert_debug_eq(&result);
+}
+
+/// Imports a chain getter and a helper of the same `mamoru_evm` module.
+const EVM_IMPORTS: &str = r#"
+    (import "mamoru_evm" "get_transactions" (func $get_transactions (result i64)))
+    (import "mamoru_evm" "parse_tx_input" (func $parse_tx_input (param i32 i32) (result i32)))"#;
+
+#[test]
+fn chain_getters_are_not_checked_before_run() {
+    let as_wat = LOG_IMPORT_WAT.replace(
+        r#"(import "mamoru" "log" (func $log (param i32 i32)))"#,
+        EVM_IMPORTS,
+    );
+    let plain_wat = format!(
+        r#"(module {imports} {alloc} (func (export "main")))"#,
+        imports = EVM_IMPORTS,
+        alloc = ALLOC,
+    );
+
+    for wat in [as_wat, plain_wat] {
+        let wasm = wat::parse_str(wat).unwrap();
+
+        let result = Daemon::new_assembly_script(
+            "dummy".to_string(),
+            wasm,
+            Default::default(),
+            versions(MAMORU_EVM_SDK, "0.1.0"),
+        );
+
+        assert!(result.is_ok(), "{:?}", result);
+    }
+}
+
+#[test(tokio::test)]
+async fn unknown_chain_import_fails_on_run() {
+    let ctx = data_ctx("DUMMY_HASH");
+    let wat = LOG_IMPORT_WAT.replace(r#""mamoru" "log""#, r#""mamoru_test" "get_blocks""#);
+    let wasm = wat::parse_str(wat).unwrap();
+
+    // The chain is not known until the daemon runs.
+    let daemon = Daemon::new_assembly_script(
+        "dummy".to_string(),
+        wasm,
+        Default::default(),
+        DaemonVersions::new(),
+    )
+    .expect("Failed to create daemon.");
+
+    let result = daemon.verify(&ctx).await;
+
+    assert!(
+        matches!(
+            &result,
+            Err(DataError::UnknownImport { module, name })
+                if module == "mamoru_test" && name == "get_blocks"
+        ),
+        "{:?}",
+        result
+    );
+}
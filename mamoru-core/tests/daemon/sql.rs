@@ -2,8 +2,8 @@ use expect_test::expect;
 use maplit::hashmap;
 use test_log::test;
 
-use mamoru_core::DataError;
-use mamoru_core_test_utils::test_blockchain_data::data_ctx;
+use mamoru_core::{BlockchainCtx, DataError, IncidentAddress, IncidentLimit, IncidentOverflow};
+use mamoru_core_test_utils::test_blockchain_data::{data_ctx, TestCtx};
 
 use crate::daemon::{test_sql_daemon, TestDaemon};
 
@@ -40,6 +40,58 @@ async fn cte_expressions_are_supported() -> Result<(), DataError> {
     Ok(())
 }
 
+#[test(tokio::test)]
+async fn nested_columns_are_queryable() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let rule = test_sql_daemon(
+        "SELECT e.seq FROM events e WHERE e.transfer['amount'] = 100 AND e.memo IS NULL",
+    );
+
+    let data = rule.verify(&ctx).await?;
+    assert!(data.matched);
+
+    let rule = test_sql_daemon("SELECT e.seq FROM events e WHERE e.label IS NULL AND e.seq = 1");
+
+    let data = rule.verify(&ctx).await?;
+    assert!(data.matched);
+
+    let rule = test_sql_daemon("SELECT e.seq FROM events e WHERE e.transfer['to'] = 'alice'");
+
+    let data = rule.verify(&ctx).await?;
+    assert!(!data.matched);
+
+    Ok(())
+}
+
+#[test]
+fn inferred_columns_schema() {
+    let events = TestCtx::schema()
+        .into_iter()
+        .find(|table| table.name == "events")
+        .expect("events table");
+
+    let columns: Vec<_> = events
+        .columns
+        .iter()
+        .map(|column| (column.name.as_str(), column.nullable))
+        .collect();
+
+    assert_eq!(
+        columns,
+        vec![
+            ("seq", false),
+            ("tx_seq", false),
+            ("topics", false),
+            ("tags", false),
+            ("memo", true),
+            ("transfer", true),
+            ("label", true),
+        ]
+    );
+    assert!(events.columns[2].data_type.starts_with("List"));
+    assert!(events.columns[5].data_type.starts_with("Struct"));
+}
+
 #[test(tokio::test)]
 async fn write_expressions_are_not_allowed() -> Result<(), DataError> {
     let statements = [
@@ -114,6 +166,7 @@ async fn select_report_incident() -> Result<(), DataError> {
                 tx_hash: "some-tx-hash",
                 address: "",
                 data: [],
+                metadata: None,
             },
             Incident {
                 severity: Info,
@@ -121,6 +174,7 @@ async fn select_report_incident() -> Result<(), DataError> {
                 tx_hash: "some-tx-hash",
                 address: "",
                 data: [],
+                metadata: None,
             },
         ]
     "#]]
@@ -231,6 +285,139 @@ async fn select_report_incident_full() -> Result<(), DataError> {
                     140,
                     0,
                 ],
+                metadata: None,
+            },
+        ]
+    "#]]
+    .assert_debug_eq(&data.incidents);
+
+    Ok(())
+}
+
+const REPORT_FULL_V2: &str = r#"
+    SELECT
+        report_full_v2(
+            'some-tx-hash',
+            'alert',
+            'Drained',
+            '',
+            hex_to_bytes('0x'),
+            '{"title": "Exploit", "addresses": [{"address": "0xdead", "role": "attacker"}], "tags": ["defi"], "confidence": 0.9, "details": {"amount": 42} }'
+        )
+"#;
+
+#[test(tokio::test)]
+async fn select_report_incident_v2() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+
+    let daemon = TestDaemon::builder()
+        .expression(REPORT_FULL_V2)
+        .build()
+        .set_core_version("0.11.0".parse().unwrap())
+        .into_daemon()?;
+
+    let data = daemon.verify(&ctx).await?;
+
+    assert_eq!(data.incidents.len(), 1);
+
+    let incident = &data.incidents[0];
+    let metadata = incident
+        .metadata
+        .as_ref()
+        .expect("Missing incident metadata.");
+
+    assert_eq!(incident.message, "Drained");
+    assert_eq!(metadata.title, "Exploit");
+    assert_eq!(
+        metadata.addresses,
+        vec![IncidentAddress {
+            address: "0xdead".to_string(),
+            role: "attacker".to_string(),
+        }]
+    );
+    assert_eq!(metadata.tags, vec!["defi".to_string()]);
+    assert_eq!(metadata.confidence, Some(0.9));
+    assert_eq!(metadata.details, Some(serde_json::json!({ "amount": 42 })));
+    assert_eq!(metadata.fingerprint.len(), 64);
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn select_report_incident_v2_is_unknown_to_old_daemons() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+
+    let daemon = TestDaemon::builder()
+        .expression(REPORT_FULL_V2)
+        .build()
+        .set_core_version("0.10.0".parse().unwrap())
+        .into_daemon()?;
+
+    let result = daemon.verify(&ctx).await;
+
+    assert!(matches!(result, Err(DataError::PlanQuery(_))));
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn select_report_incident_v2_invalid_confidence() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+
+    let daemon = TestDaemon::builder()
+        .expression(
+            r#"
+            SELECT report_full_v2('some-tx-hash', 'alert', 'Drained', '', hex_to_bytes('0x'), '{"confidence": 2.0}')
+        "#,
+        )
+        .build()
+        .set_core_version("0.11.0".parse().unwrap())
+        .into_daemon()?;
+
+    let data = daemon.verify(&ctx).await?;
+
+    assert!(data.incidents.is_empty());
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn select_report_incident_limit_truncates() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+
+    let daemon = TestDaemon::builder()
+        .expression(
+            r#"
+            SELECT report('some-tx-hash', 'warning', ct.function) FROM call_traces ct
+        "#,
+        )
+        .build()
+        .set_core_version("0.1.0".parse().unwrap())
+        .into_daemon()?
+        .with_incident_limit(IncidentLimit {
+            max_incidents: 1,
+            overflow: IncidentOverflow::Truncate,
+        });
+
+    let data = daemon.verify(&ctx).await?;
+
+    expect![[r#"
+        [
+            Incident {
+                severity: Warning,
+                message: "func1",
+                tx_hash: "some-tx-hash",
+                address: "",
+                data: [],
+                metadata: None,
+            },
+            Incident {
+                severity: Warning,
+                message: "1 incidents suppressed, the limit is 1",
+                tx_hash: "",
+                address: "",
+                data: [],
+                metadata: None,
             },
         ]
     "#]]
@@ -238,3 +425,31 @@ async fn select_report_incident_full() -> Result<(), DataError> {
 
     Ok(())
 }
+
+#[test(tokio::test)]
+async fn select_report_incident_limit_fails() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+
+    let daemon = TestDaemon::builder()
+        .expression(
+            r#"
+            SELECT report('some-tx-hash', 'info', ct.function) FROM call_traces ct
+        "#,
+        )
+        .build()
+        .set_core_version("0.1.0".parse().unwrap())
+        .into_daemon()?
+        .with_incident_limit(IncidentLimit {
+            max_incidents: 1,
+            overflow: IncidentOverflow::Fail,
+        });
+
+    let result = daemon.verify(&ctx).await;
+
+    assert!(matches!(
+        result,
+        Err(DataError::TooManyIncidents { limit: 1 })
+    ));
+
+    Ok(())
+}
@@ -73,6 +73,48 @@ async fn bytes_to_hex() -> Result<(), DataError> {
     Ok(())
 }
 
+#[test(tokio::test)]
+async fn base58_encode_decode() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let rule = test_sql_daemon(
+        r#"
+        SELECT 1
+        WHERE
+            base58_encode(hex_to_bytes('0x0000287fb4cd')) = '11233QC4'
+        AND
+            base58_decode('11233QC4') = hex_to_bytes('0x0000287fb4cd')
+        AND
+            base58_decode('11233QC4') <> hex_to_bytes('0x287fb4cd')
+        AND
+            base58_decode('not-base58') IS NULL;
+    "#,
+    );
+
+    let data = rule.verify(&ctx).await?;
+
+    assert!(data.matched);
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn keccak256() -> Result<(), DataError> {
+    let ctx = data_ctx("DUMMY_HASH");
+    let rule = test_sql_daemon(
+        r#"
+        SELECT 1
+        WHERE
+            bytes_to_hex(keccak256(hex_to_bytes('0x'))) = '0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470';
+    "#,
+    );
+
+    let data = rule.verify(&ctx).await?;
+
+    assert!(data.matched);
+
+    Ok(())
+}
+
 #[test(tokio::test)]
 async fn u256_from_str() -> Result<(), DataError> {
     let ctx = data_ctx("DUMMY_HASH");
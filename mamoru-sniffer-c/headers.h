@@ -78,44 +78,75 @@ bool value_struct_add_field (
     char const * key,
     FfiValue_t * data);
 
-typedef struct FfiEvmBlockchainDataBuilder FfiEvmBlockchainDataBuilder_t;
+typedef struct FfiBitcoinBlockchainDataBuilder FfiBitcoinBlockchainDataBuilder_t;
 
-FfiEvmBlockchainDataBuilder_t * new_evm_blockchain_data_builder (void);
+FfiBitcoinBlockchainDataBuilder_t * new_bitcoin_blockchain_data_builder (void);
 
-void evm_blockchain_data_builder_set_tx (
-    FfiEvmBlockchainDataBuilder_t * builder,
+void bitcoin_blockchain_data_builder_set_tx (
+    FfiBitcoinBlockchainDataBuilder_t * builder,
     char const * tx_id,
     char const * tx_hash);
 
-void evm_blockchain_data_builder_set_block (
-    FfiEvmBlockchainDataBuilder_t * builder,
+void bitcoin_blockchain_data_builder_set_block (
+    FfiBitcoinBlockchainDataBuilder_t * builder,
     char const * block_id,
     char const * block_hash);
 
-void evm_blockchain_data_builder_set_mempool_source (
-    FfiEvmBlockchainDataBuilder_t * builder);
+void bitcoin_blockchain_data_builder_set_mempool_source (
+    FfiBitcoinBlockchainDataBuilder_t * builder);
 
-void evm_blockchain_data_builder_set_statistics (
-    FfiEvmBlockchainDataBuilder_t * builder,
+void bitcoin_blockchain_data_builder_set_statistics (
+    FfiBitcoinBlockchainDataBuilder_t * builder,
     uint64_t blocks,
-    uint64_t transactions,
-    uint64_t events,
-    uint64_t call_traces);
+    uint64_t transactions);
 
-typedef struct FfiEvmBlockchainDataCtx FfiEvmBlockchainDataCtx_t;
+/** \brief
+ *  Sets the network used to derive output addresses:
+ *  `bitcoin` (default), `testnet`, `signet` or `regtest`.
+ */
+void bitcoin_blockchain_data_builder_set_network (
+    FfiBitcoinBlockchainDataBuilder_t * builder,
+    char const * network);
+
+typedef struct FfiBitcoinBlockchainDataCtx FfiBitcoinBlockchainDataCtx_t;
 
 /** \brief
  *  Frees `builder` argument.
  */
-FfiEvmBlockchainDataCtx_t * evm_blockchain_data_builder_finish (
-    FfiEvmBlockchainDataBuilder_t * builder);
+FfiBitcoinBlockchainDataCtx_t * bitcoin_blockchain_data_builder_finish (
+    FfiBitcoinBlockchainDataBuilder_t * builder);
 
 /** \brief
  *  Frees `data` argument.
  */
-void evm_sniffer_observe_data (
+void bitcoin_sniffer_observe_data (
     FfiSniffer_t const * sniffer,
-    FfiEvmBlockchainDataCtx_t * data);
+    FfiBitcoinBlockchainDataCtx_t * data);
+
+void bitcoin_block_set (
+    FfiBitcoinBlockchainDataBuilder_t * builder,
+    uint64_t height,
+    char const * hash,
+    char const * prev_hash,
+    char const * merkle_root,
+    uint32_t version,
+    uint64_t timestamp,
+    uint32_t bits,
+    uint32_t nonce,
+    uint64_t size,
+    uint64_t weight);
+
+void bitcoin_transaction_append (
+    FfiBitcoinBlockchainDataBuilder_t * builder,
+    uint32_t index,
+    char const * txid,
+    char const * wtxid,
+    uint32_t version,
+    uint32_t lock_time,
+    uint64_t size,
+    uint64_t vsize,
+    uint64_t weight,
+    uint64_t fee);
 
 /** \brief
  *  `&'lt [T]` but with a guaranteed `#[repr(C)]` layout.
@@ -144,6 +175,72 @@ typedef struct slice_ref_uint8 {
 
 } slice_ref_uint8_t;
 
+void bitcoin_input_append (
+    FfiBitcoinBlockchainDataBuilder_t * builder,
+    uint32_t tx_index,
+    uint32_t index,
+    char const * prev_txid,
+    uint32_t prev_vout,
+    slice_ref_uint8_t script_sig,
+    uint32_t sequence);
+
+/** \brief
+ *  `script_type` and `address` are derived from `script_pubkey` on `bitcoin_blockchain_data_builder_finish`.
+ */
+void bitcoin_output_append (
+    FfiBitcoinBlockchainDataBuilder_t * builder,
+    uint32_t tx_index,
+    uint32_t index,
+    uint64_t value,
+    slice_ref_uint8_t script_pubkey);
+
+typedef struct FfiEvmBlockchainDataBuilder FfiEvmBlockchainDataBuilder_t;
+
+FfiEvmBlockchainDataBuilder_t * new_evm_blockchain_data_builder (void);
+
+void evm_blockchain_data_builder_set_tx (
+    FfiEvmBlockchainDataBuilder_t * builder,
+    char const * tx_id,
+    char const * tx_hash);
+
+void evm_blockchain_data_builder_set_block (
+    FfiEvmBlockchainDataBuilder_t * builder,
+    char const * block_id,
+    char const * block_hash);
+
+void evm_blockchain_data_builder_set_mempool_source (
+    FfiEvmBlockchainDataBuilder_t * builder);
+
+void evm_blockchain_data_builder_set_statistics (
+    FfiEvmBlockchainDataBuilder_t * builder,
+    uint64_t blocks,
+    uint64_t transactions,
+    uint64_t events,
+    uint64_t call_traces);
+
+/** \brief
+ *  Enables or disables decoding `token_transfers` and `approvals` from events.
+ *  Disabled by default.
+ */
+void evm_blockchain_data_builder_set_derive_token_tables (
+    FfiEvmBlockchainDataBuilder_t * builder,
+    bool enabled);
+
+typedef struct FfiEvmBlockchainDataCtx FfiEvmBlockchainDataCtx_t;
+
+/** \brief
+ *  Frees `builder` argument.
+ */
+FfiEvmBlockchainDataCtx_t * evm_blockchain_data_builder_finish (
+    FfiEvmBlockchainDataBuilder_t * builder);
+
+/** \brief
+ *  Frees `data` argument.
+ */
+void evm_sniffer_observe_data (
+    FfiSniffer_t const * sniffer,
+    FfiEvmBlockchainDataCtx_t * data);
+
 void evm_transaction_append (
     FfiEvmBlockchainDataBuilder_t * builder,
     uint32_t tx_index,
@@ -174,7 +271,9 @@ void evm_call_trace_append (
     uint64_t value,
     uint64_t gas_limit,
     uint64_t gas_used,
-    slice_ref_uint8_t input);
+    slice_ref_uint8_t input,
+    slice_ref_uint8_t output,
+    char const * error);
 
 void evm_block_set (
     FfiEvmBlockchainDataBuilder_t * builder,
@@ -207,6 +306,120 @@ void evm_event_append (
     slice_ref_uint8_t topic4,
     slice_ref_uint8_t data);
 
+void evm_storage_change_append (
+    FfiEvmBlockchainDataBuilder_t * builder,
+    uint32_t tx_index,
+    char const * address,
+    slice_ref_uint8_t slot,
+    slice_ref_uint8_t previous_value,
+    slice_ref_uint8_t new_value);
+
+void evm_balance_change_append (
+    FfiEvmBlockchainDataBuilder_t * builder,
+    uint32_t tx_index,
+    char const * address,
+    slice_ref_uint8_t previous_balance,
+    slice_ref_uint8_t new_balance);
+
+typedef struct FfiSolanaBlockchainDataBuilder FfiSolanaBlockchainDataBuilder_t;
+
+FfiSolanaBlockchainDataBuilder_t * new_solana_blockchain_data_builder (void);
+
+void solana_blockchain_data_builder_set_tx (
+    FfiSolanaBlockchainDataBuilder_t * builder,
+    char const * tx_id,
+    char const * tx_hash);
+
+void solana_blockchain_data_builder_set_block (
+    FfiSolanaBlockchainDataBuilder_t * builder,
+    char const * block_id,
+    char const * block_hash);
+
+void solana_blockchain_data_builder_set_mempool_source (
+    FfiSolanaBlockchainDataBuilder_t * builder);
+
+void solana_blockchain_data_builder_set_statistics (
+    FfiSolanaBlockchainDataBuilder_t * builder,
+    uint64_t blocks,
+    uint64_t transactions);
+
+typedef struct FfiSolanaBlockchainDataCtx FfiSolanaBlockchainDataCtx_t;
+
+/** \brief
+ *  Frees `builder` argument.
+ */
+FfiSolanaBlockchainDataCtx_t * solana_blockchain_data_builder_finish (
+    FfiSolanaBlockchainDataBuilder_t * builder);
+
+/** \brief
+ *  Frees `data` argument.
+ */
+void solana_sniffer_observe_data (
+    FfiSniffer_t const * sniffer,
+    FfiSolanaBlockchainDataCtx_t * data);
+
+void solana_block_set (
+    FfiSolanaBlockchainDataBuilder_t * builder,
+    uint64_t slot,
+    char const * blockhash,
+    char const * previous_blockhash,
+    uint64_t parent_slot,
+    uint64_t block_height,
+    uint64_t block_time);
+
+/** \brief
+ *  `error` is NULL if the transaction succeeded.
+ */
+void solana_transaction_append (
+    FfiSolanaBlockchainDataBuilder_t * builder,
+    uint32_t index,
+    char const * signature,
+    uint64_t slot,
+    char const * fee_payer,
+    uint64_t fee,
+    uint64_t compute_units_consumed,
+    char const * error);
+
+/** \brief
+ *  `accounts` is a comma-separated list of base58 account keys.
+ */
+void solana_instruction_append (
+    FfiSolanaBlockchainDataBuilder_t * builder,
+    uint32_t tx_index,
+    uint32_t index,
+    char const * program_id,
+    char const * accounts,
+    slice_ref_uint8_t data);
+
+/** \brief
+ *  `accounts` is a comma-separated list of base58 account keys.
+ */
+void solana_inner_instruction_append (
+    FfiSolanaBlockchainDataBuilder_t * builder,
+    uint32_t tx_index,
+    uint32_t instruction_index,
+    uint32_t index,
+    uint32_t stack_height,
+    char const * program_id,
+    char const * accounts,
+    slice_ref_uint8_t data);
+
+void solana_token_balance_change_append (
+    FfiSolanaBlockchainDataBuilder_t * builder,
+    uint32_t tx_index,
+    char const * account,
+    char const * mint,
+    char const * owner,
+    uint8_t decimals,
+    uint64_t pre_amount,
+    uint64_t post_amount);
+
+void solana_log_message_append (
+    FfiSolanaBlockchainDataBuilder_t * builder,
+    uint32_t tx_index,
+    uint32_t index,
+    char const * message);
+
 
 #ifdef __cplusplus
 } /* extern "C" */
@@ -0,0 +1,199 @@
+use crate::{FfiSniffer, RUNTIME};
+use mamoru_bitcoin_types::{BitcoinCtx, Block, Input, Output, Transaction};
+use mamoru_sniffer::core::{BlockchainData, BlockchainDataBuilder};
+use safer_ffi::prelude::*;
+
+#[derive_ReprC]
+#[ReprC::opaque]
+pub struct FfiBitcoinBlockchainDataCtx {
+    pub(crate) inner: BlockchainData<BitcoinCtx>,
+}
+
+#[derive_ReprC]
+#[ReprC::opaque]
+pub struct FfiBitcoinBlockchainDataBuilder {
+    pub(crate) inner: BlockchainDataBuilder<BitcoinCtx>,
+}
+
+#[ffi_export]
+fn new_bitcoin_blockchain_data_builder() -> repr_c::Box<FfiBitcoinBlockchainDataBuilder> {
+    let inner = BlockchainDataBuilder::new();
+    repr_c::Box::new(FfiBitcoinBlockchainDataBuilder { inner })
+}
+
+#[ffi_export]
+fn bitcoin_blockchain_data_builder_set_tx(
+    builder: &mut FfiBitcoinBlockchainDataBuilder,
+    tx_id: char_p::Ref<'_>,
+    tx_hash: char_p::Ref<'_>,
+) {
+    let tx_id = tx_id.to_str();
+    let tx_hash = tx_hash.to_str();
+
+    builder.inner.set_tx_data(tx_id, tx_hash);
+}
+
+#[ffi_export]
+fn bitcoin_blockchain_data_builder_set_block(
+    builder: &mut FfiBitcoinBlockchainDataBuilder,
+    block_id: char_p::Ref<'_>,
+    block_hash: char_p::Ref<'_>,
+) {
+    let block_id = block_id.to_str();
+    let block_hash = block_hash.to_str();
+
+    builder.inner.set_block_data(block_id, block_hash);
+}
+
+#[ffi_export]
+fn bitcoin_blockchain_data_builder_set_mempool_source(
+    builder: &mut FfiBitcoinBlockchainDataBuilder,
+) {
+    builder.inner.set_mempool_source();
+}
+
+#[ffi_export]
+fn bitcoin_blockchain_data_builder_set_statistics(
+    builder: &mut FfiBitcoinBlockchainDataBuilder,
+    blocks: u64,
+    transactions: u64,
+) {
+    builder.inner.set_statistics(blocks, transactions, 0, 0);
+}
+
+/// Sets the network used to derive output addresses:
+/// `bitcoin` (default), `testnet`, `signet` or `regtest`.
+#[ffi_export]
+fn bitcoin_blockchain_data_builder_set_network(
+    builder: &mut FfiBitcoinBlockchainDataBuilder,
+    network: char_p::Ref<'_>,
+) {
+    builder.inner.data_mut().set_network(network.to_str());
+}
+
+/// Frees `builder` argument.
+#[ffi_export]
+fn bitcoin_blockchain_data_builder_finish(
+    builder: repr_c::Box<FfiBitcoinBlockchainDataBuilder>,
+) -> repr_c::Box<FfiBitcoinBlockchainDataCtx> {
+    let builder = builder.into().inner;
+
+    repr_c::Box::new(FfiBitcoinBlockchainDataCtx {
+        inner: builder
+            .build()
+            .expect("BUG: failed to build `BlockchainData`"),
+    })
+}
+
+/// Frees `data` argument.
+#[ffi_export]
+fn bitcoin_sniffer_observe_data(
+    sniffer: &FfiSniffer,
+    data: repr_c::Box<FfiBitcoinBlockchainDataCtx>,
+) {
+    let sniffer = &sniffer.inner;
+    let data = data.into();
+
+    RUNTIME.block_on(async { sniffer.observe_data(data.inner).await });
+}
+
+#[ffi_export]
+fn bitcoin_block_set<'a>(
+    builder: &mut FfiBitcoinBlockchainDataBuilder,
+    height: u64,
+    hash: char_p::Ref<'a>,
+    prev_hash: char_p::Ref<'a>,
+    merkle_root: char_p::Ref<'a>,
+    version: u32,
+    timestamp: u64,
+    bits: u32,
+    nonce: u32,
+    size: u64,
+    weight: u64,
+) {
+    let data = builder.inner.data_mut();
+
+    data.set_block(Block {
+        height,
+        hash: hash.to_str().to_string(),
+        prev_hash: prev_hash.to_str().to_string(),
+        merkle_root: merkle_root.to_str().to_string(),
+        version,
+        timestamp,
+        bits,
+        nonce,
+        size,
+        weight,
+    });
+}
+
+#[ffi_export]
+fn bitcoin_transaction_append<'a>(
+    builder: &mut FfiBitcoinBlockchainDataBuilder,
+    index: u32,
+    txid: char_p::Ref<'a>,
+    wtxid: char_p::Ref<'a>,
+    version: u32,
+    lock_time: u32,
+    size: u64,
+    vsize: u64,
+    weight: u64,
+    fee: u64,
+) {
+    let transactions = &mut builder.inner.data_mut().transactions;
+
+    transactions.push(Transaction {
+        index,
+        txid: txid.to_str().to_string(),
+        wtxid: wtxid.to_str().to_string(),
+        version,
+        lock_time,
+        size,
+        vsize,
+        weight,
+        fee,
+    });
+}
+
+#[ffi_export]
+fn bitcoin_input_append<'a>(
+    builder: &mut FfiBitcoinBlockchainDataBuilder,
+    tx_index: u32,
+    index: u32,
+    prev_txid: char_p::Ref<'a>,
+    prev_vout: u32,
+    script_sig: c_slice::Ref<'a, u8>,
+    sequence: u32,
+) {
+    let inputs = &mut builder.inner.data_mut().inputs;
+
+    inputs.push(Input {
+        tx_index,
+        index,
+        prev_txid: prev_txid.to_str().to_string(),
+        prev_vout,
+        script_sig: script_sig.to_vec(),
+        sequence,
+    });
+}
+
+/// `script_type` and `address` are derived from `script_pubkey` on `bitcoin_blockchain_data_builder_finish`.
+#[ffi_export]
+fn bitcoin_output_append(
+    builder: &mut FfiBitcoinBlockchainDataBuilder,
+    tx_index: u32,
+    index: u32,
+    value: u64,
+    script_pubkey: c_slice::Ref<'_, u8>,
+) {
+    let outputs = &mut builder.inner.data_mut().outputs;
+
+    outputs.push(Output {
+        tx_index,
+        index,
+        value,
+        script_pubkey: script_pubkey.to_vec(),
+        script_type: String::new(),
+        address: None,
+    });
+}
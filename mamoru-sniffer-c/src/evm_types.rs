@@ -1,5 +1,7 @@
 use crate::{FfiSniffer, RUNTIME};
-use mamoru_evm_types::{Block, CallTrace, Event, EvmCtx, Transaction};
+use mamoru_evm_types::{
+    BalanceChange, Block, CallTrace, Event, EvmCtx, StorageChange, Transaction,
+};
 use mamoru_sniffer::core::{BlockchainData, BlockchainDataBuilder};
 use safer_ffi::prelude::*;
 
@@ -63,6 +65,16 @@ fn evm_blockchain_data_builder_set_statistics(
         .set_statistics(blocks, transactions, events, call_traces);
 }
 
+/// Enables or disables decoding `token_transfers` and `approvals` from events.
+/// Disabled by default.
+#[ffi_export]
+fn evm_blockchain_data_builder_set_derive_token_tables(
+    builder: &mut FfiEvmBlockchainDataBuilder,
+    enabled: bool,
+) {
+    builder.inner.data_mut().set_derive_token_tables(enabled);
+}
+
 /// Frees `builder` argument.
 #[ffi_export]
 fn evm_blockchain_data_builder_finish(
@@ -140,6 +152,8 @@ fn evm_call_trace_append<'a>(
     gas_limit: u64,
     gas_used: u64,
     input: c_slice::Ref<'a, u8>,
+    output: c_slice::Ref<'a, u8>,
+    error: Option<char_p::Ref<'a>>,
 ) {
     let call_traces = &mut builder.inner.data_mut().call_traces;
 
@@ -155,6 +169,9 @@ fn evm_call_trace_append<'a>(
         gas_limit,
         gas_used,
         input: input.to_vec(),
+        output: output.to_vec(),
+        error: error.map(|error| error.to_str().to_string()),
+        revert_reason: None,
     });
 }
 
@@ -227,3 +244,41 @@ fn evm_event_append<'a>(
         data: data.to_vec(),
     });
 }
+
+#[ffi_export]
+fn evm_storage_change_append<'a>(
+    builder: &mut FfiEvmBlockchainDataBuilder,
+    tx_index: u32,
+    address: char_p::Ref<'a>,
+    slot: c_slice::Ref<'a, u8>,
+    previous_value: c_slice::Ref<'a, u8>,
+    new_value: c_slice::Ref<'a, u8>,
+) {
+    let storage_changes = &mut builder.inner.data_mut().storage_changes;
+
+    storage_changes.push(StorageChange {
+        tx_index,
+        address: address.to_str().to_string(),
+        slot: slot.to_vec(),
+        previous_value: previous_value.to_vec(),
+        new_value: new_value.to_vec(),
+    });
+}
+
+#[ffi_export]
+fn evm_balance_change_append<'a>(
+    builder: &mut FfiEvmBlockchainDataBuilder,
+    tx_index: u32,
+    address: char_p::Ref<'a>,
+    previous_balance: c_slice::Ref<'a, u8>,
+    new_balance: c_slice::Ref<'a, u8>,
+) {
+    let balance_changes = &mut builder.inner.data_mut().balance_changes;
+
+    balance_changes.push(BalanceChange {
+        tx_index,
+        address: address.to_str().to_string(),
+        previous_balance: previous_balance.to_vec(),
+        new_balance: new_balance.to_vec(),
+    });
+}
@@ -1,5 +1,7 @@
+mod bitcoin_types;
 mod evm_types;
 mod ffi_types;
+mod solana_types;
 
 use lazy_static::lazy_static;
 use mamoru_sniffer::{
@@ -9,8 +11,10 @@ use mamoru_sniffer::{
 use safer_ffi::prelude::*;
 use std::collections::HashMap;
 
+pub use bitcoin_types::*;
 pub use evm_types::*;
 pub use ffi_types::*;
+pub use solana_types::*;
 
 lazy_static! {
     static ref RUNTIME: tokio::runtime::Runtime = {
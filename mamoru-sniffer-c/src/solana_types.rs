@@ -0,0 +1,221 @@
+use crate::{FfiSniffer, RUNTIME};
+use mamoru_sniffer::core::{BlockchainData, BlockchainDataBuilder};
+use mamoru_solana_types::{
+    Block, InnerInstruction, Instruction, LogMessage, SolanaCtx, TokenBalanceChange, Transaction,
+};
+use safer_ffi::prelude::*;
+
+#[derive_ReprC]
+#[ReprC::opaque]
+pub struct FfiSolanaBlockchainDataCtx {
+    pub(crate) inner: BlockchainData<SolanaCtx>,
+}
+
+#[derive_ReprC]
+#[ReprC::opaque]
+pub struct FfiSolanaBlockchainDataBuilder {
+    pub(crate) inner: BlockchainDataBuilder<SolanaCtx>,
+}
+
+#[ffi_export]
+fn new_solana_blockchain_data_builder() -> repr_c::Box<FfiSolanaBlockchainDataBuilder> {
+    let inner = BlockchainDataBuilder::new();
+    repr_c::Box::new(FfiSolanaBlockchainDataBuilder { inner })
+}
+
+#[ffi_export]
+fn solana_blockchain_data_builder_set_tx(
+    builder: &mut FfiSolanaBlockchainDataBuilder,
+    tx_id: char_p::Ref<'_>,
+    tx_hash: char_p::Ref<'_>,
+) {
+    let tx_id = tx_id.to_str();
+    let tx_hash = tx_hash.to_str();
+
+    builder.inner.set_tx_data(tx_id, tx_hash);
+}
+
+#[ffi_export]
+fn solana_blockchain_data_builder_set_block(
+    builder: &mut FfiSolanaBlockchainDataBuilder,
+    block_id: char_p::Ref<'_>,
+    block_hash: char_p::Ref<'_>,
+) {
+    let block_id = block_id.to_str();
+    let block_hash = block_hash.to_str();
+
+    builder.inner.set_block_data(block_id, block_hash);
+}
+
+#[ffi_export]
+fn solana_blockchain_data_builder_set_mempool_source(builder: &mut FfiSolanaBlockchainDataBuilder) {
+    builder.inner.set_mempool_source();
+}
+
+#[ffi_export]
+fn solana_blockchain_data_builder_set_statistics(
+    builder: &mut FfiSolanaBlockchainDataBuilder,
+    blocks: u64,
+    transactions: u64,
+) {
+    builder.inner.set_statistics(blocks, transactions, 0, 0);
+}
+
+/// Frees `builder` argument.
+#[ffi_export]
+fn solana_blockchain_data_builder_finish(
+    builder: repr_c::Box<FfiSolanaBlockchainDataBuilder>,
+) -> repr_c::Box<FfiSolanaBlockchainDataCtx> {
+    let builder = builder.into().inner;
+
+    repr_c::Box::new(FfiSolanaBlockchainDataCtx {
+        inner: builder
+            .build()
+            .expect("BUG: failed to build `BlockchainData`"),
+    })
+}
+
+/// Frees `data` argument.
+#[ffi_export]
+fn solana_sniffer_observe_data(
+    sniffer: &FfiSniffer,
+    data: repr_c::Box<FfiSolanaBlockchainDataCtx>,
+) {
+    let sniffer = &sniffer.inner;
+    let data = data.into();
+
+    RUNTIME.block_on(async { sniffer.observe_data(data.inner).await });
+}
+
+#[ffi_export]
+fn solana_block_set<'a>(
+    builder: &mut FfiSolanaBlockchainDataBuilder,
+    slot: u64,
+    blockhash: char_p::Ref<'a>,
+    previous_blockhash: char_p::Ref<'a>,
+    parent_slot: u64,
+    block_height: u64,
+    block_time: u64,
+) {
+    let data = builder.inner.data_mut();
+
+    data.set_block(Block {
+        slot,
+        blockhash: blockhash.to_str().to_string(),
+        previous_blockhash: previous_blockhash.to_str().to_string(),
+        parent_slot,
+        block_height,
+        block_time,
+    });
+}
+
+/// `error` is NULL if the transaction succeeded.
+#[ffi_export]
+fn solana_transaction_append<'a>(
+    builder: &mut FfiSolanaBlockchainDataBuilder,
+    index: u32,
+    signature: char_p::Ref<'a>,
+    slot: u64,
+    fee_payer: char_p::Ref<'a>,
+    fee: u64,
+    compute_units_consumed: u64,
+    error: Option<char_p::Ref<'a>>,
+) {
+    let transactions = &mut builder.inner.data_mut().transactions;
+
+    transactions.push(Transaction {
+        index,
+        signature: signature.to_str().to_string(),
+        slot,
+        fee_payer: fee_payer.to_str().to_string(),
+        fee,
+        compute_units_consumed,
+        error: error.map(|error| error.to_str().to_string()),
+    });
+}
+
+/// `accounts` is a comma-separated list of base58 account keys.
+#[ffi_export]
+fn solana_instruction_append<'a>(
+    builder: &mut FfiSolanaBlockchainDataBuilder,
+    tx_index: u32,
+    index: u32,
+    program_id: char_p::Ref<'a>,
+    accounts: char_p::Ref<'a>,
+    data: c_slice::Ref<'a, u8>,
+) {
+    let instructions = &mut builder.inner.data_mut().instructions;
+
+    instructions.push(Instruction {
+        tx_index,
+        index,
+        program_id: program_id.to_str().to_string(),
+        accounts: accounts.to_str().to_string(),
+        data: data.to_vec(),
+    });
+}
+
+/// `accounts` is a comma-separated list of base58 account keys.
+#[ffi_export]
+fn solana_inner_instruction_append<'a>(
+    builder: &mut FfiSolanaBlockchainDataBuilder,
+    tx_index: u32,
+    instruction_index: u32,
+    index: u32,
+    stack_height: u32,
+    program_id: char_p::Ref<'a>,
+    accounts: char_p::Ref<'a>,
+    data: c_slice::Ref<'a, u8>,
+) {
+    let inner_instructions = &mut builder.inner.data_mut().inner_instructions;
+
+    inner_instructions.push(InnerInstruction {
+        tx_index,
+        instruction_index,
+        index,
+        stack_height,
+        program_id: program_id.to_str().to_string(),
+        accounts: accounts.to_str().to_string(),
+        data: data.to_vec(),
+    });
+}
+
+#[ffi_export]
+fn solana_token_balance_change_append<'a>(
+    builder: &mut FfiSolanaBlockchainDataBuilder,
+    tx_index: u32,
+    account: char_p::Ref<'a>,
+    mint: char_p::Ref<'a>,
+    owner: char_p::Ref<'a>,
+    decimals: u8,
+    pre_amount: u64,
+    post_amount: u64,
+) {
+    let token_balance_changes = &mut builder.inner.data_mut().token_balance_changes;
+
+    token_balance_changes.push(TokenBalanceChange {
+        tx_index,
+        account: account.to_str().to_string(),
+        mint: mint.to_str().to_string(),
+        owner: owner.to_str().to_string(),
+        decimals,
+        pre_amount,
+        post_amount,
+    });
+}
+
+#[ffi_export]
+fn solana_log_message_append(
+    builder: &mut FfiSolanaBlockchainDataBuilder,
+    tx_index: u32,
+    index: u32,
+    message: char_p::Ref<'_>,
+) {
+    let log_messages = &mut builder.inner.data_mut().log_messages;
+
+    log_messages.push(LogMessage {
+        tx_index,
+        index,
+        message: message.to_str().to_string(),
+    });
+}
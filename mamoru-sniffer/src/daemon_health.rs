@@ -0,0 +1,108 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+};
+
+use mamoru_core::DataError;
+
+/// How the daemon runs on this sniffer, see [`crate::Sniffer::daemons_health`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DaemonHealth {
+    pub runs: u64,
+    pub failures: u64,
+
+    /// The failures caused by the daemon calling `abort`, e.g. an AssemblyScript panic.
+    pub aborts: u64,
+
+    /// The error of the last failed run.
+    pub last_error: Option<String>,
+}
+
+/// The health of all daemons, keyed by the daemon id.
+#[derive(Default)]
+pub(crate) struct DaemonHealthRegistry {
+    daemons: Mutex<HashMap<String, DaemonHealth>>,
+}
+
+impl DaemonHealthRegistry {
+    pub(crate) fn record_success(&self, daemon_id: &str) {
+        self.daemons()
+            .entry(daemon_id.to_string())
+            .or_default()
+            .runs += 1;
+    }
+
+    pub(crate) fn record_failure(&self, daemon_id: &str, err: &DataError) {
+        let mut daemons = self.daemons();
+        let health = daemons.entry(daemon_id.to_string()).or_default();
+
+        health.runs += 1;
+        health.failures += 1;
+        health.last_error = Some(err.to_string());
+
+        if matches!(err, DataError::WasmAbort { .. }) {
+            health.aborts += 1;
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> HashMap<String, DaemonHealth> {
+        self.daemons().clone()
+    }
+
+    /// Forgets the daemons that are not in `daemon_ids`.
+    pub(crate) fn retain(&self, daemon_ids: &[String]) {
+        self.daemons().retain(|id, _| daemon_ids.contains(id));
+    }
+
+    fn daemons(&self) -> MutexGuard<HashMap<String, DaemonHealth>> {
+        self.daemons
+            .lock()
+            .expect("BUG: DaemonHealthRegistry lock is poisoned.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aborts_are_counted_as_failures() {
+        let registry = DaemonHealthRegistry::default();
+
+        registry.record_success("daemon");
+        registry.record_failure(
+            "daemon",
+            &DataError::WasmAbort {
+                message: "Unexpected value".to_string(),
+                file: "assembly/index.ts".to_string(),
+                line: 4,
+                col: 9,
+            },
+        );
+        registry.record_failure("daemon", &DataError::WrongStatementsNumber);
+
+        assert_eq!(
+            registry.snapshot().remove("daemon"),
+            Some(DaemonHealth {
+                runs: 3,
+                failures: 2,
+                aborts: 1,
+                last_error: Some("We only support a single SQL statement.".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn removed_daemons_are_forgotten() {
+        let registry = DaemonHealthRegistry::default();
+
+        registry.record_success("first");
+        registry.record_success("second");
+        registry.retain(&["second".to_string()]);
+
+        assert_eq!(
+            registry.snapshot().keys().collect::<Vec<_>>(),
+            vec!["second"]
+        );
+    }
+}
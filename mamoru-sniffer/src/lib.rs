@@ -1,10 +1,14 @@
+mod daemon_health;
 mod errors;
+mod native_daemons;
 mod sniffer;
 
 mod statistics_bg_task;
 pub mod validation_chain;
 
+pub use daemon_health::DaemonHealth;
 pub use errors::*;
+pub use native_daemons::NativeDaemons;
 pub use sniffer::*;
 pub mod core {
     pub use mamoru_core::*;
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use mamoru_core::{BlockchainCtx, Daemon, DaemonParameters, DataError, NativeDaemonRegistry};
+
+/// [`Daemon::new_native`] of the blockchain the sniffer observes.
+type NativeDaemonFn =
+    fn(String, &NativeDaemonRegistry, &str, &DaemonParameters) -> Result<Daemon, DataError>;
+
+/// The native daemons a [`Sniffer`](crate::Sniffer) runs.
+///
+/// The daemon metadata is published by the daemon authors, so it can't select the host code to run.
+/// Instead, the operator assigns the registered kinds to the daemon ids, see [`NativeDaemons::assign`].
+#[derive(Clone)]
+pub struct NativeDaemons {
+    registry: NativeDaemonRegistry,
+    /// The kinds by daemon id.
+    assigned: HashMap<String, String>,
+    new_daemon: NativeDaemonFn,
+}
+
+impl NativeDaemons {
+    /// The daemons of `registry` running on the blockchain `T` the sniffer observes,
+    /// the daemons registered for other blockchains fail on creation.
+    pub fn new<T: BlockchainCtx>(registry: NativeDaemonRegistry) -> Self {
+        Self {
+            registry,
+            assigned: HashMap::new(),
+            new_daemon: Daemon::new_native::<T>,
+        }
+    }
+
+    /// Runs the daemon `daemon_id` deployed on the Validation Chain as the native daemon `kind`,
+    /// its metadata content is ignored.
+    pub fn assign(mut self, daemon_id: impl Into<String>, kind: impl Into<String>) -> Self {
+        self.assigned.insert(daemon_id.into(), kind.into());
+
+        self
+    }
+
+    /// Returns `None` if no native daemon is assigned to `daemon_id`.
+    pub(crate) fn create(
+        &self,
+        daemon_id: &str,
+        parameters: &DaemonParameters,
+    ) -> Option<Result<Daemon, DataError>> {
+        let kind = self.assigned.get(daemon_id)?;
+
+        Some((self.new_daemon)(
+            daemon_id.to_string(),
+            &self.registry,
+            kind,
+            parameters,
+        ))
+    }
+}
+
+impl Default for NativeDaemons {
+    /// No native daemons, any assigned kind is unknown.
+    fn default() -> Self {
+        Self {
+            registry: NativeDaemonRegistry::default(),
+            assigned: HashMap::new(),
+            new_daemon: |_, _, kind, _| Err(DataError::UnknownNativeDaemon(kind.to_string())),
+        }
+    }
+}
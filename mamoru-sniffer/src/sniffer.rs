@@ -1,4 +1,4 @@
-use std::{ops::Add, sync::Arc, time::Duration};
+use std::{collections::HashMap, ops::Add, path::PathBuf, sync::Arc, time::Duration};
 
 use futures::TryStreamExt;
 use serde::Deserialize;
@@ -12,7 +12,12 @@ use tokio::{
 };
 use tracing::{debug, error, info, warn};
 
-use mamoru_core::{BlockchainCtx, BlockchainData, Daemon, DataSource};
+use mamoru_core::{
+    assembly_script::ModuleCache, BlockchainCtx, BlockchainData, Daemon, DataError, DataSource,
+};
+
+use crate::daemon_health::{DaemonHealth, DaemonHealthRegistry};
+use crate::native_daemons::NativeDaemons;
 
 use crate::statistics_bg_task::{BgStatisticsConfig, StatisticBgTask};
 use crate::validation_chain::{BlockId, SourceType, StatisticsReport};
@@ -20,8 +25,8 @@ use crate::{
     errors::SnifferError,
     from_env,
     validation_chain::{
-        ChainType, DaemonQueryResponseDto, IncidentReport, MessageClient, MessageClientConfig,
-        QueryClient, QueryClientConfig, TransactionId,
+        make_daemons, ChainType, DaemonQueryResponseDto, IncidentReport, MessageClient,
+        MessageClientConfig, QueryClient, QueryClientConfig, TransactionId,
     },
 };
 
@@ -52,6 +57,15 @@ pub struct SnifferConfig {
 
     #[serde(default = "SnifferConfig::default_statistics_buffer_size")]
     pub statistics_buffer_size: usize,
+
+    /// The directory to store compiled WASM daemons in, so restarts skip recompilation.
+    /// The compiled daemons are cached in memory only if not set.
+    #[serde(default)]
+    pub wasm_cache_dir: Option<PathBuf>,
+
+    /// The size limit of `wasm_cache_dir` in MiB, the oldest compiled daemons are removed over it.
+    #[serde(default = "SnifferConfig::default_wasm_cache_max_size_mb")]
+    pub wasm_cache_max_size_mb: u64,
 }
 
 impl SnifferConfig {
@@ -82,6 +96,9 @@ impl SnifferConfig {
     pub fn default_statistics_buffer_size() -> usize {
         256
     }
+    pub fn default_wasm_cache_max_size_mb() -> u64 {
+        ModuleCache::DEFAULT_MAX_DISK_SIZE / (1024 * 1024)
+    }
 }
 
 pub type SnifferResult<T> = Result<T, SnifferError>;
@@ -91,6 +108,7 @@ pub struct Sniffer {
     report_tx: Sender<IncidentReport>,
     rules: Arc<RwLock<Vec<Daemon>>>,
     chain_type: ChainType,
+    health: Arc<DaemonHealthRegistry>,
 
     statistic_tx: Sender<StatisticsReport>,
 }
@@ -99,7 +117,25 @@ impl Sniffer {
     /// Bootstraps new [`Sniffer`] instance.
     /// Call [`SnifferConfig::from_env`] to create `config` parameter from environment variables.
     pub async fn new(config: SnifferConfig) -> SnifferResult<Self> {
+        Self::with_native_daemons(config, NativeDaemons::default()).await
+    }
+
+    /// Bootstraps new [`Sniffer`] instance that runs the native daemons from `native_daemons`.
+    pub async fn with_native_daemons(
+        config: SnifferConfig,
+        native_daemons: NativeDaemons,
+    ) -> SnifferResult<Self> {
+        if let Some(dir) = &config.wasm_cache_dir {
+            let cache = ModuleCache::new(Some(dir.clone()))
+                .with_max_disk_size(config.wasm_cache_max_size_mb * 1024 * 1024);
+
+            if !ModuleCache::init_global(cache) {
+                warn!("WASM module cache is already initialized, ignoring `wasm_cache_dir`.");
+            }
+        }
+
         let rules = Arc::new(RwLock::new(vec![]));
+        let health = Arc::new(DaemonHealthRegistry::default());
         let (report_tx, report_rx) = tokio::sync::mpsc::channel(config.incident_buffer_size);
 
         let bg_task_config = BgTaskConfig {
@@ -114,6 +150,8 @@ impl Sniffer {
             message_client.clone(),
             QueryClient::connect(config.query_config).await?,
             Arc::clone(&rules),
+            Arc::clone(&health),
+            native_daemons,
             config.chain_type,
             report_rx,
             bg_task_config,
@@ -139,10 +177,16 @@ impl Sniffer {
             report_tx,
             rules,
             chain_type: config.chain_type,
+            health,
             statistic_tx,
         })
     }
 
+    /// The execution health of the current daemons, keyed by the daemon id.
+    pub fn daemons_health(&self) -> HashMap<String, DaemonHealth> {
+        self.health.snapshot()
+    }
+
     /// Reports to Validation Chain if the provided transaction matches
     /// any rule from the internal storage.
     #[tracing::instrument(
@@ -156,7 +200,14 @@ impl Sniffer {
             .map(|daemon| async {
                 let daemon_id = daemon.id();
 
-                match daemon.verify(&ctx).await {
+                let result = daemon.verify(&ctx).await;
+
+                match &result {
+                    Ok(_) => self.health.record_success(&daemon_id),
+                    Err(err) => self.health.record_failure(&daemon_id, err),
+                }
+
+                match result {
                     Ok(verify_ctx) => {
                         if !verify_ctx.matched {
                             debug!(%daemon_id, "Daemon is NOT matched");
@@ -193,6 +244,9 @@ impl Sniffer {
                             }
                         }
                     },
+                    Err(err @ DataError::WasmAbort { .. }) => {
+                        error!(%err, %daemon_id, "Daemon aborted, skipping...");
+                    }
                     Err(err) => {
                         error!(?err, %daemon_id, "Failed to verify daemon, skipping...");
                     }
@@ -242,6 +296,8 @@ struct SnifferBgTask {
     message_client: MessageClient,
     query_client: QueryClient,
     daemons: Arc<RwLock<Vec<Daemon>>>,
+    health: Arc<DaemonHealthRegistry>,
+    native_daemons: NativeDaemons,
     chain_type: ChainType,
     report_rx: Receiver<IncidentReport>,
     task_config: BgTaskConfig,
@@ -252,6 +308,8 @@ impl SnifferBgTask {
         message_client: MessageClient,
         query_client: QueryClient,
         daemons: Arc<RwLock<Vec<Daemon>>>,
+        health: Arc<DaemonHealthRegistry>,
+        native_daemons: NativeDaemons,
         chain_type: ChainType,
         report_rx: Receiver<IncidentReport>,
         task_config: BgTaskConfig,
@@ -262,6 +320,8 @@ impl SnifferBgTask {
             message_client,
             query_client,
             daemons,
+            health,
+            native_daemons,
             chain_type,
             report_rx,
             task_config,
@@ -368,18 +428,15 @@ impl SnifferBgTask {
 
         let new_daemons: Vec<Daemon> = daemon_response
             .into_iter()
-            .flat_map(|daemon_response_dto| {
-                let daemons: Vec<Daemon> = daemon_response_dto.into();
-
-                daemons
-            })
+            .flat_map(|daemon_response_dto| make_daemons(daemon_response_dto, &self.native_daemons))
             .collect();
 
         debug!(len = new_daemons.len(), "Parsed daemons");
 
-        self.message_client
-            .subscribe_daemons(new_daemons.iter().map(|rule| rule.id()).collect())
-            .await?;
+        let daemon_ids: Vec<String> = new_daemons.iter().map(|rule| rule.id()).collect();
+
+        self.health.retain(&daemon_ids);
+        self.message_client.subscribe_daemons(daemon_ids).await?;
 
         {
             let mut daemons_guard = self.daemons.write().await;
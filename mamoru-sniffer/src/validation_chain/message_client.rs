@@ -245,25 +245,23 @@ impl MessageClient {
         let report_messages: Vec<_> = reports
             .into_iter()
             .map(|report: IncidentReport| {
+                // `MsgReportIncident` has no `IncidentV2` fields yet
+                let incident = report.incident.into_v1();
+
                 let (severity, message, address, data) = {
-                    let severity = match report.incident.severity {
+                    let severity = match incident.severity {
                         MamoruIncidentSeverity::Info => IncidentSeverity::SeverityInfo,
                         MamoruIncidentSeverity::Warning => IncidentSeverity::SeverityWarning,
                         MamoruIncidentSeverity::Error => IncidentSeverity::SeverityError,
                         MamoruIncidentSeverity::Alert => IncidentSeverity::SeverityAlert,
                     };
 
-                    (
-                        severity,
-                        report.incident.message,
-                        report.incident.address,
-                        report.incident.data,
-                    )
+                    (severity, incident.message, incident.address, incident.data)
                 };
 
                 let tx = {
                     let sniffer_tx_info = report.tx.unwrap_or_default();
-                    let reported_tx_hash = report.incident.tx_hash;
+                    let reported_tx_hash = incident.tx_hash;
 
                     Transaction {
                         tx_id: sniffer_tx_info.tx_id,
@@ -8,4 +8,7 @@ pub type ClientResult<T> = Result<T, crate::errors::ValidationClientError>;
 
 pub use config::*;
 pub use message_client::*;
+pub use proto::{INCIDENT_OVERFLOW_PARAMETER, MAX_INCIDENTS_LIMIT, MAX_INCIDENTS_PARAMETER};
 pub use query_client::*;
+
+pub(crate) use proto::make_daemons;
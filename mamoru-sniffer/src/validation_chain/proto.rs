@@ -5,13 +5,16 @@ use serde::{Deserialize, Deserializer};
 use strum::VariantNames;
 use tracing::{error, warn};
 
-use mamoru_core::{Daemon, IncidentData, Version};
+use mamoru_core::{
+    Daemon, DaemonParameters, IncidentData, IncidentLimit, IncidentOverflow, Version,
+};
 
-use crate::validation_chain::proto::validation_chain::MetadataSdkVersion;
+use crate::validation_chain::proto::validation_chain::{DaemonMetadata, MetadataSdkVersion};
 use crate::validation_chain::{
     proto::validation_chain::DaemonMetadataContentType, ChainType, DaemonParameter,
     DaemonQueryResponseDto, IncidentSeverity,
 };
+use crate::NativeDaemons;
 
 pub mod validation_chain {
     pub use super::includes::validationchain::validationchain::*;
@@ -28,64 +31,111 @@ mod includes {
     tonic::include_proto!("includes");
 }
 
+/// The daemon parameter overriding [`IncidentLimit::max_incidents`],
+/// from `1` to [`MAX_INCIDENTS_LIMIT`].
+pub const MAX_INCIDENTS_PARAMETER: &str = "mamoru_max_incidents";
+
+/// The greatest [`MAX_INCIDENTS_PARAMETER`] value, as the parameters are set by the daemon authors.
+pub const MAX_INCIDENTS_LIMIT: usize = 1024;
+
+/// The daemon parameter overriding [`IncidentLimit::overflow`], either `truncate` or `fail`.
+pub const INCIDENT_OVERFLOW_PARAMETER: &str = "mamoru_incident_overflow";
+
 impl From<DaemonQueryResponseDto> for Vec<Daemon> {
     fn from(value: DaemonQueryResponseDto) -> Self {
-        let metadata = value.daemon_metadata.expect("BUG: Missing DaemonMetadata.");
-        let content = metadata
-            .content
-            .expect("BUG: Missing DaemonMetadataContent.");
-        let parameters = make_daemon_parameters(value.parameters);
-        let sdk_versions = make_sdk_versions(metadata.sdk_versions);
-
-        match content.r#type() {
-            DaemonMetadataContentType::Sql => content
-                .query
-                .into_iter()
-                .filter_map(|query| {
-                    let incident_data = IncidentData {
-                        message: query.incident_message.clone(),
-                        severity: query.severity().into(),
-                    };
-
-                    match Daemon::new_sql(
-                        value.daemon_id.clone(),
-                        &query.query,
-                        incident_data,
-                        parameters.clone(),
-                        sdk_versions.clone(),
-                    ) {
-                        Ok(daemon) => Some(daemon),
-                        Err(err) => {
-                            error!(?err, %value.daemon_id, "Failed to parse SQL daemon.");
-
-                            None
-                        }
-                    }
-                })
-                .collect(),
-            DaemonMetadataContentType::Wasm => {
-                let wasm_bytes = match base64::decode(&content.wasm_module) {
-                    Ok(wasm_bytes) => wasm_bytes,
-                    Err(err) => {
-                        error!(?err, %value.daemon_id, "Failed to decode WASM base64-encoded payload.");
+        make_daemons(value, &NativeDaemons::default())
+    }
+}
 
-                        return vec![];
-                    }
+/// Creates daemons from the Validation Chain response.
+/// The daemons assigned in `native_daemons` are created as native ones.
+///
+/// The incident limit is read from the daemon parameters,
+/// see [`MAX_INCIDENTS_PARAMETER`] and [`INCIDENT_OVERFLOW_PARAMETER`].
+pub(crate) fn make_daemons(
+    value: DaemonQueryResponseDto,
+    native_daemons: &NativeDaemons,
+) -> Vec<Daemon> {
+    let metadata = value.daemon_metadata.expect("BUG: Missing DaemonMetadata.");
+    let parameters = make_daemon_parameters(value.parameters);
+    let incident_limit = make_incident_limit(&value.daemon_id, &parameters);
+
+    make_metadata_daemons(value.daemon_id, metadata, parameters, native_daemons)
+        .into_iter()
+        .map(|daemon| daemon.with_incident_limit(incident_limit))
+        .collect()
+}
+
+fn make_metadata_daemons(
+    daemon_id: String,
+    metadata: DaemonMetadata,
+    parameters: DaemonParameters,
+    native_daemons: &NativeDaemons,
+) -> Vec<Daemon> {
+    if let Some(result) = native_daemons.create(&daemon_id, &parameters) {
+        return match result {
+            Ok(daemon) => vec![daemon],
+            Err(err) => {
+                error!(?err, %daemon_id, "Failed to create native daemon.");
+
+                vec![]
+            }
+        };
+    }
+
+    let content = metadata
+        .content
+        .expect("BUG: Missing DaemonMetadataContent.");
+    let sdk_versions = make_sdk_versions(metadata.sdk_versions);
+
+    match content.r#type() {
+        DaemonMetadataContentType::Sql => content
+            .query
+            .into_iter()
+            .filter_map(|query| {
+                let incident_data = IncidentData {
+                    message: query.incident_message.clone(),
+                    severity: query.severity().into(),
                 };
 
-                match Daemon::new_assembly_script(
-                    value.daemon_id.clone(),
-                    wasm_bytes,
-                    parameters,
-                    sdk_versions,
+                match Daemon::new_sql(
+                    daemon_id.clone(),
+                    &query.query,
+                    incident_data,
+                    parameters.clone(),
+                    sdk_versions.clone(),
                 ) {
-                    Ok(daemon) => vec![daemon],
+                    Ok(daemon) => Some(daemon),
                     Err(err) => {
-                        error!(?err, %value.daemon_id, "Failed to parse WASM daemon.");
+                        error!(?err, %daemon_id, "Failed to parse SQL daemon.");
 
-                        vec![]
+                        None
                     }
                 }
+            })
+            .collect(),
+        DaemonMetadataContentType::Wasm => {
+            let wasm_bytes = match base64::decode(&content.wasm_module) {
+                Ok(wasm_bytes) => wasm_bytes,
+                Err(err) => {
+                    error!(?err, %daemon_id, "Failed to decode WASM base64-encoded payload.");
+
+                    return vec![];
+                }
+            };
+
+            match Daemon::new_assembly_script(
+                daemon_id.clone(),
+                wasm_bytes,
+                parameters,
+                sdk_versions,
+            ) {
+                Ok(daemon) => vec![daemon],
+                Err(err) => {
+                    error!(?err, %daemon_id, "Failed to parse WASM daemon.");
+
+                    vec![]
+                }
             }
         }
     }
@@ -127,8 +177,47 @@ fn make_sdk_versions(values: Vec<MetadataSdkVersion>) -> HashMap<String, Version
     results
 }
 
-fn make_daemon_parameters(values: Vec<DaemonParameter>) -> mamoru_core::DaemonParameters {
-    let mut results = mamoru_core::DaemonParameters::new();
+/// The default limit is used for the missing or invalid parameters.
+fn make_incident_limit(daemon_id: &str, parameters: &DaemonParameters) -> IncidentLimit {
+    let mut limit = IncidentLimit::default();
+
+    if let Some(value) = parameters.get(MAX_INCIDENTS_PARAMETER) {
+        match value.parse::<usize>() {
+            Ok(0) => {
+                warn!(%daemon_id, "The max incidents parameter must be positive");
+            }
+            Ok(max_incidents) if max_incidents > MAX_INCIDENTS_LIMIT => {
+                warn!(
+                    %daemon_id,
+                    max_incidents,
+                    limit = MAX_INCIDENTS_LIMIT,
+                    "The max incidents parameter is over the limit"
+                );
+
+                limit.max_incidents = MAX_INCIDENTS_LIMIT;
+            }
+            Ok(max_incidents) => limit.max_incidents = max_incidents,
+            Err(err) => {
+                warn!(%daemon_id, ?err, %value, "Failed to parse the max incidents parameter");
+            }
+        }
+    }
+
+    if let Some(value) = parameters.get(INCIDENT_OVERFLOW_PARAMETER) {
+        match value.as_str() {
+            "truncate" => limit.overflow = IncidentOverflow::Truncate,
+            "fail" => limit.overflow = IncidentOverflow::Fail,
+            _ => {
+                warn!(%daemon_id, %value, "Unknown incident overflow parameter");
+            }
+        }
+    }
+
+    limit
+}
+
+fn make_daemon_parameters(values: Vec<DaemonParameter>) -> DaemonParameters {
+    let mut results = DaemonParameters::new();
 
     for parameter_dto in values {
         if results.get(&parameter_dto.key).is_some() {
@@ -160,8 +249,166 @@ impl<'de> Deserialize<'de> for ChainType {
 
 #[cfg(test)]
 mod tests {
+    use async_trait::async_trait;
+    use datafusion::prelude::SessionContext;
+    use mamoru_core::{BlockchainData, DataError, Incident, NativeDaemon, NativeDaemonRegistry};
+    use mamoru_core_test_utils::test_blockchain_data::{data_ctx, TestCtx};
+
+    use super::validation_chain::{DaemonMetadataContent, DaemonMetadataContentQuery};
     use super::*;
 
+    fn sql_daemon_response(parameters: &[(&str, &str)]) -> DaemonQueryResponseDto {
+        DaemonQueryResponseDto {
+            daemon_id: "daemon".to_string(),
+            daemon_metadata: Some(DaemonMetadata {
+                tags: vec!["native:alert".to_string()],
+                content: Some(DaemonMetadataContent {
+                    r#type: DaemonMetadataContentType::Sql as i32,
+                    query: vec![DaemonMetadataContentQuery {
+                        query: "SELECT * FROM transactions".to_string(),
+                        incident_message: "Test".to_string(),
+                        severity: IncidentSeverity::SeverityAlert as i32,
+                    }],
+                    wasm_module: "".to_string(),
+                }),
+                ..Default::default()
+            }),
+            parameters: parameters
+                .iter()
+                .map(|(key, value)| DaemonParameter {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn incident_limit_from_parameters() {
+        let response = sql_daemon_response(&[
+            (MAX_INCIDENTS_PARAMETER, "5"),
+            (INCIDENT_OVERFLOW_PARAMETER, "truncate"),
+        ]);
+
+        let daemons = make_daemons(response, &NativeDaemons::default());
+
+        assert_eq!(daemons.len(), 1);
+        assert_eq!(
+            daemons[0].incident_limit(),
+            IncidentLimit {
+                max_incidents: 5,
+                overflow: IncidentOverflow::Truncate,
+            }
+        );
+    }
+
+    #[test]
+    fn incident_limit_is_clamped() {
+        let response = sql_daemon_response(&[(MAX_INCIDENTS_PARAMETER, "18446744073709551615")]);
+
+        let daemons = make_daemons(response, &NativeDaemons::default());
+
+        assert_eq!(
+            daemons[0].incident_limit().max_incidents,
+            MAX_INCIDENTS_LIMIT
+        );
+
+        let response = sql_daemon_response(&[(MAX_INCIDENTS_PARAMETER, "0")]);
+
+        let daemons = make_daemons(response, &NativeDaemons::default());
+
+        assert_eq!(daemons[0].incident_limit(), IncidentLimit::default());
+    }
+
+    #[test]
+    fn invalid_incident_limit_is_default() {
+        let response = sql_daemon_response(&[
+            (MAX_INCIDENTS_PARAMETER, "many"),
+            (INCIDENT_OVERFLOW_PARAMETER, "ignore"),
+        ]);
+
+        let daemons = make_daemons(response, &NativeDaemons::default());
+
+        assert_eq!(daemons.len(), 1);
+        assert_eq!(daemons[0].incident_limit(), IncidentLimit::default());
+    }
+
+    /// Reports an incident on every run.
+    struct AlertDaemon;
+
+    #[async_trait]
+    impl NativeDaemon<TestCtx> for AlertDaemon {
+        async fn verify(
+            &self,
+            _ctx: &BlockchainData<TestCtx>,
+            _session: &SessionContext,
+        ) -> Result<Vec<Incident>, DataError> {
+            Ok(vec![Incident {
+                severity: mamoru_core::IncidentSeverity::Alert,
+                message: "Native".to_string(),
+                tx_hash: Default::default(),
+                address: Default::default(),
+                data: vec![],
+                metadata: None,
+            }])
+        }
+    }
+
+    fn native_daemons() -> NativeDaemons {
+        let mut registry = NativeDaemonRegistry::default();
+        registry.register::<TestCtx, _, _>("alert", |_: &DaemonParameters| Ok(AlertDaemon));
+
+        NativeDaemons::new::<TestCtx>(registry)
+    }
+
+    async fn incident_messages(daemons: &[Daemon]) -> Vec<String> {
+        let ctx = data_ctx("DUMMY_HASH");
+        let mut messages = vec![];
+
+        for daemon in daemons {
+            let result = daemon.verify(&ctx).await.expect("Failed to run daemon.");
+            messages.extend(
+                result
+                    .incidents
+                    .into_iter()
+                    .map(|incident| incident.message),
+            );
+        }
+
+        messages
+    }
+
+    #[tokio::test]
+    async fn assigned_daemon_is_native() {
+        let response = sql_daemon_response(&[(MAX_INCIDENTS_PARAMETER, "5")]);
+
+        let daemons = make_daemons(response, &native_daemons().assign("daemon", "alert"));
+
+        assert_eq!(daemons.len(), 1);
+        assert_eq!(daemons[0].incident_limit().max_incidents, 5);
+        assert_eq!(incident_messages(&daemons).await, vec!["Native"]);
+    }
+
+    #[tokio::test]
+    async fn metadata_tag_does_not_select_native_daemon() {
+        let response = sql_daemon_response(&[]);
+
+        let daemons = make_daemons(response, &native_daemons().assign("other_daemon", "alert"));
+
+        assert_eq!(daemons.len(), 1);
+        assert_ne!(incident_messages(&daemons).await, vec!["Native"]);
+    }
+
+    #[test]
+    fn unknown_native_daemon_is_skipped() {
+        let response = sql_daemon_response(&[]);
+
+        let daemons = make_daemons(response, &native_daemons().assign("daemon", "unknown"));
+
+        assert!(daemons.is_empty());
+    }
+
     #[test]
     fn chain_type_from_str() {
         let chain = ChainType::from_str("SUI_DEVNET").unwrap();
@@ -75,6 +75,7 @@ async fn smoke() {
                 address: "".to_string(),
                 tx_hash: "tx_hash_from_report".to_string(),
                 data: Default::default(),
+                metadata: None,
             },
         })
         .collect();
@@ -97,6 +97,8 @@ async fn sniffer(chain_type: ChainType) -> Sniffer {
         max_incident_batch_size: SnifferConfig::default_max_incident_batch_size(),
         statistics_send_interval_secs: SnifferConfig::default_statistics_send_interval_secs(),
         statistics_buffer_size: SnifferConfig::default_statistics_buffer_size(),
+        wasm_cache_dir: None,
+        wasm_cache_max_size_mb: SnifferConfig::default_wasm_cache_max_size_mb(),
     })
     .await
     .expect("Failed to create Sniffer")
@@ -113,6 +115,8 @@ async fn sniffer_with_interval(chain_type: ChainType) -> Sniffer {
         max_incident_batch_size: SnifferConfig::default_max_incident_batch_size(),
         statistics_send_interval_secs: Some(5u64),
         statistics_buffer_size: SnifferConfig::default_statistics_buffer_size(),
+        wasm_cache_dir: None,
+        wasm_cache_max_size_mb: SnifferConfig::default_wasm_cache_max_size_mb(),
     })
     .await
     .expect("Failed to create Sniffer")
@@ -31,6 +31,12 @@ typedef uint8_t FfiChainType_t; enum
     FFI_CHAIN_TYPE_EVM = 1,
     /** . */
     FFI_CHAIN_TYPE_APTOS = 2,
+    /** . */
+    FFI_CHAIN_TYPE_COSMOS = 3,
+    /** . */
+    FFI_CHAIN_TYPE_BITCOIN = 4,
+    /** . */
+    FFI_CHAIN_TYPE_SOLANA = 5,
 }
 #ifdef DOXYGEN
 FfiChainType_t
@@ -121,6 +127,15 @@ FfiValidationResult_t ffi_append_daemon_version (
 void ffi_drop_daemon_versions (
     FfiDaemonVersions_t * versions);
 
+/** \brief
+ *  Returns tables and columns of the chain as JSON in `message`:
+ *  `[{"name", "doc", "columns": [{"name", "data_type", "nullable", "doc"}]}]`.
+ *  `is_error` is set if the schema can't be passed as a C string.
+ *  Free the result with `ffi_drop_validation_result`.
+ */
+FfiValidationResult_t ffi_chain_schema_json (
+    FfiChainType_t chain);
+
 
 #ifdef __cplusplus
 } /* extern "C" */
@@ -1,7 +1,7 @@
 use lazy_static::lazy_static;
 use query_validator::{
-    validate_assembly_script, validate_sql, validate_sql_renders, ChainType, DaemonParameters,
-    DaemonVersions, ValidateError,
+    chain_schema_json, validate_assembly_script, validate_sql, validate_sql_renders, ChainType,
+    DaemonParameters, DaemonVersions, ValidateError,
 };
 use safer_ffi::prelude::*;
 
@@ -11,6 +11,9 @@ pub enum FfiChainType {
     Sui = 0,
     Evm = 1,
     Aptos = 2,
+    Cosmos = 3,
+    Bitcoin = 4,
+    Solana = 5,
 }
 
 #[derive_ReprC]
@@ -31,9 +34,16 @@ impl From<Result<(), ValidateError>> for FfiValidationResult {
 
 impl FfiValidationResult {
     fn from_validate_error(value: ValidateError) -> Self {
+        Self::error(format!("{:#?}", value))
+    }
+
+    fn error(message: String) -> Self {
+        // A C string can't contain NUL bytes, the message may come from the user input.
+        let message = message.replace('\0', "\\0");
+
         FfiValidationResult {
             is_error: true,
-            message: format!("{:#?}", value).try_into().unwrap(),
+            message: message.try_into().expect("BUG: NUL bytes are escaped."),
         }
     }
 
@@ -63,6 +73,9 @@ impl From<FfiChainType> for ChainType {
             FfiChainType::Sui => Self::Sui,
             FfiChainType::Evm => Self::Evm,
             FfiChainType::Aptos => Self::Aptos,
+            FfiChainType::Cosmos => Self::Cosmos,
+            FfiChainType::Bitcoin => Self::Bitcoin,
+            FfiChainType::Solana => Self::Solana,
         }
     }
 }
@@ -195,6 +208,21 @@ fn ffi_drop_daemon_versions(versions: repr_c::Box<FfiDaemonVersions>) {
     drop(versions)
 }
 
+/// Returns tables and columns of the chain as JSON in `message`:
+/// `[{"name", "doc", "columns": [{"name", "data_type", "nullable", "doc"}]}]`.
+/// `is_error` is set if the schema can't be passed as a C string.
+/// Free the result with `ffi_drop_validation_result`.
+#[ffi_export]
+fn ffi_chain_schema_json(chain: FfiChainType) -> FfiValidationResult {
+    match char_p::Box::try_from(chain_schema_json(chain.into())) {
+        Ok(message) => FfiValidationResult {
+            is_error: false,
+            message,
+        },
+        Err(_) => FfiValidationResult::error("The chain schema contains a NUL byte.".to_string()),
+    }
+}
+
 #[safer_ffi::cfg_headers]
 #[test]
 fn generate_headers() -> ::std::io::Result<()> {
@@ -202,3 +230,24 @@ fn generate_headers() -> ::std::io::Result<()> {
         .to_file("headers.h")?
         .generate()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chain_schema_json_ok() {
+        let result = ffi_chain_schema_json(FfiChainType::Sui);
+
+        assert!(!result.is_error);
+        assert!(result.message.to_str().starts_with(r#"[{"name":"#));
+    }
+
+    #[test]
+    fn error_message_with_nul_byte() {
+        let result = FfiValidationResult::error("invalid\0query".to_string());
+
+        assert!(result.is_error);
+        assert_eq!(result.message.to_str(), "invalid\\0query");
+    }
+}
@@ -0,0 +1,72 @@
+//! Generates Daemon author documentation and editor autocompletion data
+//! from the chain schemas.
+//!
+//! ```shell
+//! cargo run -p query-validator --example chain_schema -- <output-dir>
+//! ```
+//!
+//! Writes `<chain>.json` (see [`chain_schema_json`]) and `<chain>.md` for every chain.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use query_validator::{chain_schema, chain_schema_json, ChainType, TableSchema};
+
+fn main() -> std::io::Result<()> {
+    let output_dir: PathBuf = std::env::args()
+        .nth(1)
+        .expect("Usage: chain_schema <output-dir>")
+        .into();
+
+    std::fs::create_dir_all(&output_dir)?;
+
+    let chains = [
+        ("sui", ChainType::Sui),
+        ("evm", ChainType::Evm),
+        ("aptos", ChainType::Aptos),
+        ("cosmos", ChainType::Cosmos),
+        ("bitcoin", ChainType::Bitcoin),
+        ("solana", ChainType::Solana),
+    ];
+
+    for (name, chain) in chains {
+        let json = chain_schema_json(chain);
+        let markdown = render_markdown(name, &chain_schema(chain));
+
+        std::fs::write(output_dir.join(format!("{}.json", name)), json)?;
+        std::fs::write(output_dir.join(format!("{}.md", name)), markdown)?;
+    }
+
+    Ok(())
+}
+
+fn render_markdown(chain: &str, tables: &[TableSchema]) -> String {
+    let mut out = String::new();
+
+    writeln!(out, "# `{}` tables", chain).unwrap();
+
+    for table in tables {
+        writeln!(out, "\n## `{}`\n", table.name).unwrap();
+
+        if let Some(doc) = &table.doc {
+            writeln!(out, "{}\n", doc).unwrap();
+        }
+
+        writeln!(out, "| Column | Type | Nullable | Description |").unwrap();
+        writeln!(out, "|---|---|---|---|").unwrap();
+
+        for column in &table.columns {
+            writeln!(
+                out,
+                "| `{}` | `{}` | {} | {} |",
+                column.name,
+                column.data_type,
+                if column.nullable { "yes" } else { "no" },
+                column.doc.as_deref().unwrap_or("").replace('|', "\\|"),
+            )
+            .unwrap();
+        }
+    }
+
+    out
+}
@@ -1,21 +1,31 @@
 pub use error::*;
 use mamoru_aptos_types::AptosCtx;
+use mamoru_bitcoin_types::BitcoinCtx;
+use mamoru_core::assembly_script::{
+    AssemblyScriptExecutor, ExecutorOptions, LogCapture, ModuleCache,
+};
+pub use mamoru_core::assembly_script::{DaemonLog, LogLevel};
 use mamoru_core::{
-    BlockchainCtx, BlockchainData, BlockchainDataBuilder, Daemon, DataError, IncidentData,
-    IncidentSeverity,
+    BlockchainCtx, BlockchainData, BlockchainDataBuilder, Daemon, DataError, Executor,
+    IncidentData, IncidentSeverity,
 };
-pub use mamoru_core::{DaemonParameters, DaemonVersions};
+pub use mamoru_core::{ColumnSchema, DaemonParameters, DaemonVersions, TableSchema};
+use mamoru_cosmos_types::CosmosCtx;
 use mamoru_evm_types::EvmCtx;
+use mamoru_solana_types::SolanaCtx;
 use mamoru_sui_types::SuiCtx;
 
 mod error;
 
 /// Represents possible blockchains as each one has different schema
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum ChainType {
     Sui,
     Evm,
     Aptos,
+    Cosmos,
+    Bitcoin,
+    Solana,
 }
 
 /// Validates if the query renders without errors.
@@ -54,6 +64,24 @@ pub async fn validate_sql(
             let ctx = empty_ctx::<AptosCtx>();
             let daemon = sql_validation_daemon(query, parameters, versions)?;
 
+            daemon.verify(&ctx).await?
+        }
+        ChainType::Cosmos => {
+            let ctx = empty_ctx::<CosmosCtx>();
+            let daemon = sql_validation_daemon(query, parameters, versions)?;
+
+            daemon.verify(&ctx).await?
+        }
+        ChainType::Bitcoin => {
+            let ctx = empty_ctx::<BitcoinCtx>();
+            let daemon = sql_validation_daemon(query, parameters, versions)?;
+
+            daemon.verify(&ctx).await?
+        }
+        ChainType::Solana => {
+            let ctx = empty_ctx::<SolanaCtx>();
+            let daemon = sql_validation_daemon(query, parameters, versions)?;
+
             daemon.verify(&ctx).await?
         }
     };
@@ -71,26 +99,50 @@ pub async fn validate_assembly_script(
     bytes: &[u8],
     versions: DaemonVersions,
 ) -> Result<(), ValidateError> {
-    let result = match chain {
-        ChainType::Sui => {
-            let ctx = empty_ctx::<SuiCtx>();
-            let daemon = assembly_script_validation_daemon(bytes, versions)?;
+    run_assembly_script(chain, bytes, versions).await.result
+}
 
-            daemon.verify(&ctx).await?
-        }
-        ChainType::Evm => {
-            let ctx = empty_ctx::<EvmCtx>();
-            let daemon = assembly_script_validation_daemon(bytes, versions)?;
+/// The result of [`run_assembly_script`].
+#[derive(Debug)]
+pub struct AssemblyScriptRun {
+    pub result: Result<(), ValidateError>,
 
-            daemon.verify(&ctx).await?
-        }
-        ChainType::Aptos => {
-            let ctx = empty_ctx::<AptosCtx>();
-            let daemon = assembly_script_validation_daemon(bytes, versions)?;
+    /// The messages logged by the Daemon via `mamoru.log`, kept if the run fails.
+    pub logs: Vec<DaemonLog>,
+}
 
-            daemon.verify(&ctx).await?
-        }
+/// Same as [`validate_assembly_script`], also returns the messages logged by the Daemon.
+pub async fn run_assembly_script(
+    chain: ChainType,
+    bytes: &[u8],
+    versions: DaemonVersions,
+) -> AssemblyScriptRun {
+    let log_capture = LogCapture::new();
+    let result = verify_assembly_script(chain, bytes, versions, log_capture.clone()).await;
+
+    AssemblyScriptRun {
+        result,
+        logs: log_capture.take(),
+    }
+}
+
+async fn verify_assembly_script(
+    chain: ChainType,
+    bytes: &[u8],
+    versions: DaemonVersions,
+    log_capture: LogCapture,
+) -> Result<(), ValidateError> {
+    let daemon = assembly_script_validation_daemon(bytes, versions, log_capture)?;
+
+    let result = match chain {
+        ChainType::Sui => daemon.verify(&empty_ctx::<SuiCtx>()).await?,
+        ChainType::Evm => daemon.verify(&empty_ctx::<EvmCtx>()).await?,
+        ChainType::Aptos => daemon.verify(&empty_ctx::<AptosCtx>()).await?,
+        ChainType::Cosmos => daemon.verify(&empty_ctx::<CosmosCtx>()).await?,
+        ChainType::Bitcoin => daemon.verify(&empty_ctx::<BitcoinCtx>()).await?,
+        ChainType::Solana => daemon.verify(&empty_ctx::<SolanaCtx>()).await?,
     };
+
     if result.matched {
         return Err(ValidateError::MatchesEmptyDatabase);
     }
@@ -98,6 +150,23 @@ pub async fn validate_assembly_script(
     Ok(())
 }
 
+/// Describes the tables and columns the chain exposes to Daemons.
+pub fn chain_schema(chain: ChainType) -> Vec<TableSchema> {
+    match chain {
+        ChainType::Sui => SuiCtx::schema(),
+        ChainType::Evm => EvmCtx::schema(),
+        ChainType::Aptos => AptosCtx::schema(),
+        ChainType::Cosmos => CosmosCtx::schema(),
+        ChainType::Bitcoin => BitcoinCtx::schema(),
+        ChainType::Solana => SolanaCtx::schema(),
+    }
+}
+
+/// [`chain_schema`] as JSON, the source for Daemon author documentation and editor autocompletion.
+pub fn chain_schema_json(chain: ChainType) -> String {
+    serde_json::to_string(&chain_schema(chain)).expect("BUG: failed to serialize `TableSchema`.")
+}
+
 fn sql_validation_daemon(
     query: &str,
     parameters: DaemonParameters,
@@ -118,13 +187,24 @@ fn sql_validation_daemon(
 fn assembly_script_validation_daemon(
     bytes: &[u8],
     versions: DaemonVersions,
+    log_capture: LogCapture,
 ) -> Result<Daemon, DataError> {
-    Daemon::new_assembly_script(
-        "WASM_VALIDATE".to_string(),
+    let executor = AssemblyScriptExecutor::with_options(
         bytes,
         DaemonParameters::default(),
         versions,
-    )
+        ModuleCache::global(),
+        ExecutorOptions {
+            // the Daemon runs once
+            pool_size: 0,
+            log_capture: Some(log_capture),
+        },
+    )?;
+
+    Ok(Daemon::new(
+        "WASM_VALIDATE".to_string(),
+        Executor::AssemblyScript(executor),
+    ))
 }
 
 fn empty_ctx<T: BlockchainCtx>() -> BlockchainData<T> {
@@ -159,6 +239,50 @@ mod tests {
         empty_ctx::<AptosCtx>();
     }
 
+    #[test]
+    fn cosmos_empty_ctx_does_not_fail() {
+        empty_ctx::<CosmosCtx>();
+    }
+
+    #[test]
+    fn bitcoin_empty_ctx_does_not_fail() {
+        empty_ctx::<BitcoinCtx>();
+    }
+
+    #[test]
+    fn solana_empty_ctx_does_not_fail() {
+        empty_ctx::<SolanaCtx>();
+    }
+
+    #[test]
+    fn evm_schema_has_docs() {
+        let schema = chain_schema(ChainType::Evm);
+        let token_transfers = schema
+            .iter()
+            .find(|table| table.name == "token_transfers")
+            .expect("`token_transfers` table is missing");
+        let token_id = token_transfers
+            .columns
+            .iter()
+            .find(|column| column.name == "token_id")
+            .expect("`token_id` column is missing");
+
+        assert_eq!(token_id.data_type, "Binary");
+        assert!(token_id.nullable);
+        assert_eq!(
+            token_id.doc.as_deref(),
+            Some("big-endian u256, null for ERC-20")
+        );
+    }
+
+    #[test]
+    fn chain_schema_json_ok() {
+        let json = chain_schema_json(ChainType::Sui);
+
+        assert!(json.starts_with(r#"[{"name":"#));
+        assert!(json.contains(r#""name":"transactions""#));
+    }
+
     #[tokio::test]
     async fn valid_expression_ok() {
         let result = validate_sql(
@@ -247,6 +371,74 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn assembly_script_logs_are_kept_on_failure() {
+        let wasm = wat::parse_str(
+            r#"(module
+                (import "mamoru" "log" (func $log (param i32 i32 i32)))
+                (memory (export "memory") 1)
+                (data (i32.const 0) "before trap")
+
+                (func (export "mamoru_alloc") (param i32) (result i32) (i32.const 1024))
+                (func (export "main")
+                    (call $log (i32.const 3) (i32.const 0) (i32.const 11))
+                    (unreachable)))"#,
+        )
+        .unwrap();
+
+        let run = run_assembly_script(ChainType::Evm, &wasm, DaemonVersions::default()).await;
+
+        assert!(matches!(
+            run.result,
+            Err(ValidateError::DataError(DataError::WasmRuntime(_)))
+        ));
+        assert_eq!(
+            run.logs,
+            vec![DaemonLog {
+                level: LogLevel::Debug,
+                message: "before trap".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn aborted_assembly_script_fails() {
+        let wasm = wat::parse_str(format!(
+            r#"(module
+                (import "env" "abort" (func $abort (param i32 i32 i32 i32)))
+                (memory (export "memory") 1)
+                (data (i32.const 1020) "{message}")
+                (data (i32.const 2044) "{file}")
+
+                (func (export "__new") (param i32 i32) (result i32) (i32.const 4096))
+                (func (export "__pin") (param i32) (result i32) (local.get 0))
+                (func (export "__unpin") (param i32))
+                (func (export "__collect"))
+                (func (export "main")
+                    (call $abort (i32.const 1024) (i32.const 2048) (i32.const 4) (i32.const 9))))"#,
+            message = asc_string("Unexpected value"),
+            file = asc_string("daemon.ts"),
+        ))
+        .unwrap();
+
+        let result =
+            validate_assembly_script(ChainType::Sui, &wasm, DaemonVersions::default()).await;
+
+        assert!(matches!(
+            result,
+            Err(ValidateError::DataError(DataError::WasmAbort { message, file, line: 4, col: 9 }))
+                if message == "Unexpected value" && file == "daemon.ts"
+        ));
+    }
+
+    /// AssemblyScript string as WAT data: the byte length followed by UTF-16 code units.
+    fn asc_string(value: &str) -> String {
+        let mut bytes = ((value.len() * 2) as u32).to_le_bytes().to_vec();
+        bytes.extend(value.encode_utf16().flat_map(u16::to_le_bytes));
+
+        bytes.iter().map(|byte| format!("\\{:02x}", byte)).collect()
+    }
+
     // export function main(): void {}
     const ASC_EMPTY_MAIN_NO_RUNTIME: &[u8] = &[
         0, 97, 115, 109, 1, 0, 0, 0, 1, 4, 1, 96, 0, 0, 3, 2, 1, 0, 5, 3, 1, 0, 0, 7, 17, 2, 4,
@@ -1,67 +1,40 @@
-import { _mamoru_get_blocks } from "./imports";
 import { readMemory, unpackValues } from "@mamoru-ai/mamoru-sdk-as/assembly/util";
 import { Decoder } from "@wapc/as-msgpack/assembly";
+import { Block as BlockRow } from "./generated";
+import { _mamoru_get_blocks } from "./imports";
 import { AptosCtx, Transaction } from "./index";
 
-export class Block {
-    public readonly hash: string
-    public readonly epoch: u64
-    public readonly timestampUsecs: u64
-
+export class Block extends BlockRow {
     private _ctx: AptosCtx
-    private _txs: Transaction[] | null
+    private _txs: Transaction[] | null = null
 
-    private constructor(ctx: AptosCtx, hash: string, epoch: u64, timestamp_usecs: u64) {
-        this._ctx = ctx
-        this._txs = null
+    constructor(ctx: AptosCtx, decoder: Decoder) {
+        super(decoder)
 
-        this.hash = hash
-        this.epoch = epoch
-        this.timestampUsecs = timestamp_usecs
+        this._ctx = ctx
     }
 
-    /// All transactions in the current block
+    /// All transactions in the current block,
+    /// deprecated, use `AptosCtx.txsOf` instead
     public get txs(): Transaction[] {
         if (this._txs == null) {
-            let txs = new Array<Transaction>();
-            for (let i = 0; i < this._ctx.txs.length; i++) {
-                const tx = this._ctx.txs[i];
-
-                if (tx.blockHash == this.hash) {
-                    txs.push(tx);
-                }
-            }
-
-            this._txs = txs
+            this._txs = this._ctx.txsOf(this)
         }
 
         return this._txs!
     }
 
-    public static loadAll(ctx: AptosCtx): Block[] {
-        let ptr_len = unpackValues(_mamoru_get_blocks());
+    public static loadWith(ctx: AptosCtx): Block[] {
+        const ptr_len = unpackValues(_mamoru_get_blocks());
+        const decoder = new Decoder(readMemory(ptr_len[0], ptr_len[1]).buffer);
 
-        return Block.fromHost(ctx, ptr_len[0], ptr_len[1]);
-    }
-
-    private static fromHost(ctx: AptosCtx, ptr: u32, len: u32): Block[] {
-        const decoder = new Decoder(readMemory(ptr, len).buffer);
-
-        let blocks = new Array<Block>();
-        let size = decoder.readArraySize();
+        const blocks = new Array<Block>();
+        const size = decoder.readArraySize();
 
         for (let i: u32 = 0; i < size; i++) {
-            // consume array size (we can't parse data otherwise)
-            let _ = decoder.readArraySize();
-
-            let hash = decoder.readString();
-            let epoch = decoder.readUInt64();
-            let timestamp_usecs = decoder.readUInt64();
-
-            blocks.push(new Block(ctx, hash, epoch, timestamp_usecs));
+            blocks.push(new Block(ctx, decoder));
         }
 
         return blocks;
     }
-
-}
\ No newline at end of file
+}
@@ -0,0 +1,369 @@
+// This file is generated from the Rust types by `mamoru_core::generate_as_sdk`, do not edit it.
+// Run the SDK tests with `UPDATE_EXPECT=1` to update it.
+import { _mamoru_get_blocks, _mamoru_get_transactions, _mamoru_get_events, _mamoru_get_call_traces, _mamoru_get_call_trace_type_args, _mamoru_get_call_trace_args, _mamoru_get_call_trace_arg_by_id, _mamoru_get_write_set_changes, _mamoru_get_write_set_change_data_by_id, _mamoru_get_coin_balance_changes } from "./imports";
+import { msgPackReadUint8Array, readMemory, unpackValues } from "@mamoru-ai/mamoru-sdk-as/assembly/util";
+import { Value } from "@mamoru-ai/mamoru-sdk-as/assembly";
+import { Decoder } from "@wapc/as-msgpack/assembly";
+
+export class Block {
+    public readonly hash: string
+    public readonly epoch: u64
+    public readonly timestampUsecs: u64
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let hash = decoder.readString();
+        let epoch = decoder.readUInt64();
+        let timestamp_usecs = decoder.readUInt64();
+
+        this.hash = hash
+        this.epoch = epoch
+        this.timestampUsecs = timestamp_usecs
+    }
+
+    public static loadAll(): Block[] {
+        let ptr_len = unpackValues(_mamoru_get_blocks());
+
+        return Block.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Block[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Block>((decoder: Decoder) => new Block(decoder));
+    }
+}
+
+export class Transaction {
+    public readonly seq: u64
+    public readonly blockHash: string
+    public readonly hash: string
+    public readonly eventRootHash: string
+    public readonly stateChangeHash: string
+    public readonly gasUsed: u64
+    public readonly maxGasAmount: u64
+    public readonly gasUnitPrice: u64
+    public readonly expirationTimestampSecs: u64
+    public readonly status: u64
+    public readonly sender: string
+    public readonly sequenceNumber: u64
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt64();
+        let block_hash = decoder.readString();
+        let hash = decoder.readString();
+        let event_root_hash = decoder.readString();
+        let state_change_hash = decoder.readString();
+        let gas_used = decoder.readUInt64();
+        let max_gas_amount = decoder.readUInt64();
+        let gas_unit_price = decoder.readUInt64();
+        let expiration_timestamp_secs = decoder.readUInt64();
+        let status = decoder.readUInt64();
+        let sender = decoder.readString();
+        let sequence_number = decoder.readUInt64();
+
+        this.seq = seq
+        this.blockHash = block_hash
+        this.hash = hash
+        this.eventRootHash = event_root_hash
+        this.stateChangeHash = state_change_hash
+        this.gasUsed = gas_used
+        this.maxGasAmount = max_gas_amount
+        this.gasUnitPrice = gas_unit_price
+        this.expirationTimestampSecs = expiration_timestamp_secs
+        this.status = status
+        this.sender = sender
+        this.sequenceNumber = sequence_number
+    }
+
+    public static loadAll(): Transaction[] {
+        let ptr_len = unpackValues(_mamoru_get_transactions());
+
+        return Transaction.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Transaction[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Transaction>((decoder: Decoder) => new Transaction(decoder));
+    }
+}
+
+export class Event {
+    public readonly txSeq: u64
+    public readonly key: string
+    public readonly sequenceNumber: u64
+    public readonly type: string
+    public readonly data: Uint8Array
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let tx_seq = decoder.readUInt64();
+        let key = decoder.readString();
+        let sequence_number = decoder.readUInt64();
+        let type_ = decoder.readString();
+        let data = msgPackReadUint8Array(decoder);
+
+        this.txSeq = tx_seq
+        this.key = key
+        this.sequenceNumber = sequence_number
+        this.type = type_
+        this.data = data
+    }
+
+    // Deprecated, use `type` instead.
+    public get typ(): string {
+        return this.type
+    }
+
+    public static loadAll(): Event[] {
+        let ptr_len = unpackValues(_mamoru_get_events());
+
+        return Event.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Event[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Event>((decoder: Decoder) => new Event(decoder));
+    }
+}
+
+export class CallTrace {
+    public readonly seq: u64
+    public readonly txSeq: u64
+    public readonly depth: u32
+    public readonly callType: u8
+    public readonly gasUsed: u64
+    public readonly transactionModule: string | null
+    public readonly function: string
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt64();
+        let tx_seq = decoder.readUInt64();
+        let depth = decoder.readUInt32();
+        let call_type = decoder.readUInt8();
+        let gas_used = decoder.readUInt64();
+        let transaction_module: string | null = null;
+        if (!decoder.isNextNil()) {
+            transaction_module = decoder.readString();
+        }
+        let function_ = decoder.readString();
+
+        this.seq = seq
+        this.txSeq = tx_seq
+        this.depth = depth
+        this.callType = call_type
+        this.gasUsed = gas_used
+        this.transactionModule = transaction_module
+        this.function = function_
+    }
+
+    // Deprecated, use `function` instead.
+    public get func(): string {
+        return this.function
+    }
+
+    public static loadAll(): CallTrace[] {
+        let ptr_len = unpackValues(_mamoru_get_call_traces());
+
+        return CallTrace.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): CallTrace[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<CallTrace>((decoder: Decoder) => new CallTrace(decoder));
+    }
+}
+
+export class CallTraceTypeArg {
+    public readonly seq: u64
+    public readonly callTraceSeq: u64
+    public readonly arg: string
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt64();
+        let call_trace_seq = decoder.readUInt64();
+        let arg = decoder.readString();
+
+        this.seq = seq
+        this.callTraceSeq = call_trace_seq
+        this.arg = arg
+    }
+
+    public static loadAll(): CallTraceTypeArg[] {
+        let ptr_len = unpackValues(_mamoru_get_call_trace_type_args());
+
+        return CallTraceTypeArg.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): CallTraceTypeArg[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<CallTraceTypeArg>((decoder: Decoder) => new CallTraceTypeArg(decoder));
+    }
+}
+
+export class CallTraceArg {
+    public readonly seq: u64
+    public readonly callTraceSeq: u64
+
+    private _arg: Value | null = null
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt64();
+        let call_trace_seq = decoder.readUInt64();
+
+        this.seq = seq
+        this.callTraceSeq = call_trace_seq
+    }
+
+    public get arg(): Value {
+        if (this._arg == null) {
+            const ptr_len = unpackValues(_mamoru_get_call_trace_arg_by_id(this.seq));
+            const buffer = readMemory(ptr_len[0], ptr_len[1]).buffer;
+
+            this._arg = Value.fromBytes(buffer);
+        }
+
+        return this._arg!;
+    }
+
+    // Deprecated, use `arg` instead.
+    public get value(): Value {
+        return this.arg
+    }
+
+    public static loadAll(): CallTraceArg[] {
+        let ptr_len = unpackValues(_mamoru_get_call_trace_args());
+
+        return CallTraceArg.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): CallTraceArg[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<CallTraceArg>((decoder: Decoder) => new CallTraceArg(decoder));
+    }
+}
+
+export class WriteSetChange {
+    public readonly seq: u64
+    public readonly txSeq: u64
+    public readonly address: string
+    public readonly stateKeyHash: string
+    // one of `write_resource`, `delete_resource`, `write_module`, `delete_module`, `write_table_item`, `delete_table_item`
+    public readonly changeType: string
+    // null for module and table item changes
+    public readonly resourceType: string | null
+
+    private _data: Value | null = null
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt64();
+        let tx_seq = decoder.readUInt64();
+        let address = decoder.readString();
+        let state_key_hash = decoder.readString();
+        let change_type = decoder.readString();
+        let resource_type: string | null = null;
+        if (!decoder.isNextNil()) {
+            resource_type = decoder.readString();
+        }
+
+        this.seq = seq
+        this.txSeq = tx_seq
+        this.address = address
+        this.stateKeyHash = state_key_hash
+        this.changeType = change_type
+        this.resourceType = resource_type
+    }
+
+    // null for deletions
+    public get data(): Value | null {
+        if (this._data == null) {
+            const ptr_len = unpackValues(_mamoru_get_write_set_change_data_by_id(this.seq));
+            const buffer = readMemory(ptr_len[0], ptr_len[1]).buffer;
+
+            if (new Decoder(buffer).isNextNil()) {
+                return null;
+            }
+
+            this._data = Value.fromBytes(buffer);
+        }
+
+        return this._data;
+    }
+
+    public static loadAll(): WriteSetChange[] {
+        let ptr_len = unpackValues(_mamoru_get_write_set_changes());
+
+        return WriteSetChange.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): WriteSetChange[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<WriteSetChange>((decoder: Decoder) => new WriteSetChange(decoder));
+    }
+}
+
+// A `0x1::coin::CoinStore<T>` balance written by a transaction.
+export class CoinBalanceChange {
+    // `seq` of the write set change it is decoded from
+    public readonly writeSetChangeSeq: u64
+    public readonly txSeq: u64
+    public readonly address: string
+    // `T` of the `CoinStore<T>`, e.g. `0x1::aptos_coin::AptosCoin`
+    public readonly coinType: string
+    // balance after the write
+    public readonly balance: u64
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let write_set_change_seq = decoder.readUInt64();
+        let tx_seq = decoder.readUInt64();
+        let address = decoder.readString();
+        let coin_type = decoder.readString();
+        let balance = decoder.readUInt64();
+
+        this.writeSetChangeSeq = write_set_change_seq
+        this.txSeq = tx_seq
+        this.address = address
+        this.coinType = coin_type
+        this.balance = balance
+    }
+
+    public static loadAll(): CoinBalanceChange[] {
+        let ptr_len = unpackValues(_mamoru_get_coin_balance_changes());
+
+        return CoinBalanceChange.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): CoinBalanceChange[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<CoinBalanceChange>((decoder: Decoder) => new CoinBalanceChange(decoder));
+    }
+}
@@ -18,3 +18,12 @@ export declare function _mamoru_get_events(): u64
 
 @external("mamoru_aptos", "get_call_trace_arg_by_id")
 export declare function _mamoru_get_call_trace_arg_by_id(id: u64): u64
+
+@external("mamoru_aptos", "get_write_set_changes")
+export declare function _mamoru_get_write_set_changes(): u64
+
+@external("mamoru_aptos", "get_write_set_change_data_by_id")
+export declare function _mamoru_get_write_set_change_data_by_id(id: u64): u64
+
+@external("mamoru_aptos", "get_coin_balance_changes")
+export declare function _mamoru_get_coin_balance_changes(): u64
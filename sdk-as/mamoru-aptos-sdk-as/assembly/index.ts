@@ -1,7 +1,14 @@
-import { Transaction } from "./tx";
-import { Event } from "./event";
-import { CallTrace, CallTraceArg, CallTraceTypeArg } from "./call_trace";
+import { Value } from "@mamoru-ai/mamoru-sdk-as/assembly";
+import {
+    CallTraceArg,
+    CallTraceTypeArg,
+    CoinBalanceChange,
+    Event,
+    WriteSetChange,
+} from "./generated";
 import { Block } from "./block";
+import { Transaction } from "./tx";
+import { CallTrace } from "./call_trace";
 
 export {
     Transaction,
@@ -10,6 +17,8 @@ export {
     CallTraceArg,
     CallTraceTypeArg,
     Block,
+    WriteSetChange,
+    CoinBalanceChange,
 }
 
 export class AptosCtx {
@@ -19,6 +28,8 @@ export class AptosCtx {
     private _callTraces: CallTrace[] | null
     private _callTraceTypeArgs: CallTraceTypeArg[] | null
     private _callTraceArgs: CallTraceArg[] | null
+    private _writeSetChanges: WriteSetChange[] | null
+    private _coinBalanceChanges: CoinBalanceChange[] | null
 
     private constructor() {
         this._block = null
@@ -27,6 +38,8 @@ export class AptosCtx {
         this._callTraces = null
         this._callTraceTypeArgs = null
         this._callTraceArgs = null
+        this._writeSetChanges = null
+        this._coinBalanceChanges = null
     }
 
     public static load(): AptosCtx {
@@ -36,7 +49,7 @@ export class AptosCtx {
     /// The block of the current context
     public get block(): Block {
         if (this._block == null) {
-            const blocks = Block.loadAll(this);
+            const blocks = Block.loadWith(this);
 
             this._block = blocks[0]
         }
@@ -47,7 +60,7 @@ export class AptosCtx {
     /// All transactions in the current context
     public get txs(): Transaction[] {
         if (this._txs == null) {
-            this._txs = Transaction.loadAll(this)
+            this._txs = Transaction.loadWith(this)
         }
 
         return this._txs!
@@ -65,7 +78,7 @@ export class AptosCtx {
     /// All call traces in the current context
     public get callTraces(): CallTrace[] {
         if (this._callTraces == null) {
-            this._callTraces = CallTrace.loadAll(this)
+            this._callTraces = CallTrace.loadWith(this)
         }
 
         return this._callTraces!
@@ -88,4 +101,82 @@ export class AptosCtx {
 
         return this._callTraceArgs!
     }
+
+    /// All write set changes in the current context
+    public get writeSetChanges(): WriteSetChange[] {
+        if (this._writeSetChanges == null) {
+            this._writeSetChanges = WriteSetChange.loadAll()
+        }
+
+        return this._writeSetChanges!
+    }
+
+    /// All `CoinStore` balances written in the current context
+    public get coinBalanceChanges(): CoinBalanceChange[] {
+        if (this._coinBalanceChanges == null) {
+            this._coinBalanceChanges = CoinBalanceChange.loadAll()
+        }
+
+        return this._coinBalanceChanges!
+    }
+
+    /// All transactions in the given block
+    public txsOf(block: Block): Transaction[] {
+        const txs = new Array<Transaction>();
+
+        for (let i = 0; i < this.txs.length; i++) {
+            const tx = this.txs[i];
+
+            if (tx.blockHash == block.hash) {
+                txs.push(tx);
+            }
+        }
+
+        return txs
+    }
+
+    /// All call traces of the given transaction
+    public callTracesOf(tx: Transaction): CallTrace[] {
+        const callTraces = new Array<CallTrace>();
+
+        for (let i = 0; i < this.callTraces.length; i++) {
+            const callTrace = this.callTraces[i];
+
+            if (callTrace.txSeq == tx.seq) {
+                callTraces.push(callTrace);
+            }
+        }
+
+        return callTraces
+    }
+
+    /// All arguments of the given call trace
+    public argsOf(callTrace: CallTrace): Value[] {
+        const args = new Array<Value>();
+
+        for (let i = 0; i < this.callTraceArgs.length; i++) {
+            const arg = this.callTraceArgs[i];
+
+            if (arg.callTraceSeq == callTrace.seq) {
+                args.push(arg.arg);
+            }
+        }
+
+        return args
+    }
+
+    /// All type arguments of the given call trace
+    public typeArgsOf(callTrace: CallTrace): string[] {
+        const typeArgs = new Array<string>();
+
+        for (let i = 0; i < this.callTraceTypeArgs.length; i++) {
+            const arg = this.callTraceTypeArgs[i];
+
+            if (arg.callTraceSeq == callTrace.seq) {
+                typeArgs.push(arg.arg);
+            }
+        }
+
+        return typeArgs
+    }
 }
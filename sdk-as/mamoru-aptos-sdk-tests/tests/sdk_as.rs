@@ -1,9 +1,11 @@
 use test_log::test;
 
+use std::collections::HashMap;
+
 use mamoru_aptos_types::{
-    AptosCtx, Block, CallTrace, CallTraceArg, CallTraceTypeArg, Event, Transaction,
+    AptosCtx, Block, CallTrace, CallTraceArg, CallTraceTypeArg, Event, Transaction, WriteSetChange,
 };
-use mamoru_core::{BlockchainData, BlockchainDataBuilder, Value, ValueData};
+use mamoru_core::{BlockchainData, BlockchainDataBuilder, StructValue, Value, ValueData};
 use mamoru_core_test_utils::assembly_script::{
     AssemblyScriptModule, AS_APTOS_SDK_PATH, AS_SDK_PATH,
 };
@@ -104,12 +106,48 @@ fn aptos_ctx() -> BlockchainData<AptosCtx> {
         },
     ]);
 
+    builder.data_mut().write_set_changes.extend(vec![
+        WriteSetChange {
+            seq: 0,
+            tx_seq: 3,
+            address: "0x42".to_string(),
+            state_key_hash: "state-key-hash-1".to_string(),
+            change_type: "write_resource".to_string(),
+            resource_type: Some("0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>".to_string()),
+            data: ValueData::new(coin_store(1000)),
+        },
+        WriteSetChange {
+            seq: 1,
+            tx_seq: 3,
+            address: "0x43".to_string(),
+            state_key_hash: "state-key-hash-2".to_string(),
+            change_type: "delete_resource".to_string(),
+            resource_type: Some("0x1::account::Account".to_string()),
+            data: None,
+        },
+    ]);
+
     builder.set_tx_data("test_tx", "test_digest");
     builder.set_statistics(0, 1, 2, 3);
 
     builder.build().unwrap()
 }
 
+fn coin_store(value: u64) -> Value {
+    let coin = StructValue::new(
+        "0x1::coin::Coin<0x1::aptos_coin::AptosCoin>".to_string(),
+        HashMap::from([("value".to_string(), Value::U64(value))]),
+    );
+
+    Value::Struct(StructValue::new(
+        "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>".to_string(),
+        HashMap::from([
+            ("coin".to_string(), Value::Struct(coin)),
+            ("frozen".to_string(), Value::Bool(false)),
+        ]),
+    ))
+}
+
 #[test(tokio::test)]
 async fn smoke() {
     let ctx = aptos_ctx();
@@ -224,3 +262,59 @@ async fn smoke() {
         .await
         .expect("Assertion failed");
 }
+
+#[test(tokio::test)]
+async fn write_set_changes() {
+    let ctx = aptos_ctx();
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {assert, query} from "@mamoru-ai/mamoru-sdk-as/assembly";
+        import {AptosCtx} from "@mamoru-ai/mamoru-aptos-sdk-as/assembly";
+
+        export function main(): void {
+            const ctx = AptosCtx.load();
+
+            assert(ctx.writeSetChanges.length == 2, "ctx.writeSetChanges.length == 2");
+
+            const write = ctx.writeSetChanges[0];
+
+            assert(write.seq == 0, "write.seq == 0");
+            assert(write.txSeq == 3, "write.tx_seq == 3");
+            assert(write.address == "0x42", "write.address == \"0x42\"");
+            assert(write.stateKeyHash == "state-key-hash-1", "write.state_key_hash == \"state-key-hash-1\"");
+            assert(write.changeType == "write_resource", "write.change_type == \"write_resource\"");
+            assert(write.resourceType == "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>", "write.resource_type == CoinStore<AptosCoin>");
+
+            const coin = write.data!.asStruct().fields.get("coin").asStruct();
+            assert(coin.fields.get("value").asU64() == 1000, "write.data.coin.value == 1000");
+
+            const deletion = ctx.writeSetChanges[1];
+
+            assert(deletion.changeType == "delete_resource", "deletion.change_type == \"delete_resource\"");
+            assert(deletion.data == null, "deletion.data == null");
+
+            assert(ctx.coinBalanceChanges.length == 1, "ctx.coinBalanceChanges.length == 1");
+
+            const balance = ctx.coinBalanceChanges[0];
+
+            assert(balance.writeSetChangeSeq == 0, "balance.write_set_change_seq == 0");
+            assert(balance.txSeq == 3, "balance.tx_seq == 3");
+            assert(balance.address == "0x42", "balance.address == \"0x42\"");
+            assert(balance.coinType == "0x1::aptos_coin::AptosCoin", "balance.coin_type == \"0x1::aptos_coin::AptosCoin\"");
+            assert(balance.balance == 1000, "balance.balance == 1000");
+
+            const rows = query("SELECT c.balance FROM coin_balance_changes c WHERE c.address = '0x42'");
+
+            assert(rows.length == 1, "rows.length == 1");
+            assert(rows[0].getInteger("balance")!.valueOf() == 1000, "rows[0].balance == 1000");
+        }
+    """#,
+        &[AS_SDK_PATH, AS_APTOS_SDK_PATH],
+    );
+
+    test_daemon(&module)
+        .verify(&ctx)
+        .await
+        .expect("Assertion failed");
+}
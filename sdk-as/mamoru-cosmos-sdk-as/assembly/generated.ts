@@ -0,0 +1,212 @@
+// This file is generated from the Rust types by `mamoru_core::generate_as_sdk`, do not edit it.
+// Run the SDK tests with `UPDATE_EXPECT=1` to update it.
+import { _mamoru_get_blocks, _mamoru_get_transactions, _mamoru_get_messages, _mamoru_get_events, _mamoru_get_event_attributes } from "./imports";
+import { msgPackReadUint8Array, readMemory, unpackValues } from "@mamoru-ai/mamoru-sdk-as/assembly/util";
+import { Decoder } from "@wapc/as-msgpack/assembly";
+
+export class Block {
+    public readonly height: u64
+    public readonly hash: string
+    public readonly chainId: string
+    // unix timestamp in seconds
+    public readonly timestamp: u64
+    public readonly proposerAddress: string
+    public readonly appHash: string
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let height = decoder.readUInt64();
+        let hash = decoder.readString();
+        let chain_id = decoder.readString();
+        let timestamp = decoder.readUInt64();
+        let proposer_address = decoder.readString();
+        let app_hash = decoder.readString();
+
+        this.height = height
+        this.hash = hash
+        this.chainId = chain_id
+        this.timestamp = timestamp
+        this.proposerAddress = proposer_address
+        this.appHash = app_hash
+    }
+
+    public static loadAll(): Block[] {
+        let ptr_len = unpackValues(_mamoru_get_blocks());
+
+        return Block.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Block[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Block>((decoder: Decoder) => new Block(decoder));
+    }
+}
+
+export class Transaction {
+    // index of the transaction in the block
+    public readonly index: u32
+    public readonly hash: string
+    public readonly blockHeight: u64
+    public readonly gasWanted: u64
+    public readonly gasUsed: u64
+    // ABCI result code, 0 means success
+    public readonly code: u32
+    // namespace of the `code`, empty on success
+    public readonly codespace: string
+    // fee coins, e.g. "5000uatom"
+    public readonly fee: string
+    public readonly memo: string
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let index = decoder.readUInt32();
+        let hash = decoder.readString();
+        let block_height = decoder.readUInt64();
+        let gas_wanted = decoder.readUInt64();
+        let gas_used = decoder.readUInt64();
+        let code = decoder.readUInt32();
+        let codespace = decoder.readString();
+        let fee = decoder.readString();
+        let memo = decoder.readString();
+
+        this.index = index
+        this.hash = hash
+        this.blockHeight = block_height
+        this.gasWanted = gas_wanted
+        this.gasUsed = gas_used
+        this.code = code
+        this.codespace = codespace
+        this.fee = fee
+        this.memo = memo
+    }
+
+    public static loadAll(): Transaction[] {
+        let ptr_len = unpackValues(_mamoru_get_transactions());
+
+        return Transaction.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Transaction[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Transaction>((decoder: Decoder) => new Transaction(decoder));
+    }
+}
+
+// A message included into a transaction body.
+export class Message {
+    // hash of the transaction
+    public readonly txHash: string
+    // index of the message in the transaction
+    public readonly index: u32
+    // protobuf type URL, e.g. "/cosmos.bank.v1beta1.MsgSend"
+    public readonly typeUrl: string
+    // the message decoded to JSON
+    public readonly json: string
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let tx_hash = decoder.readString();
+        let index = decoder.readUInt32();
+        let type_url = decoder.readString();
+        let json = decoder.readString();
+
+        this.txHash = tx_hash
+        this.index = index
+        this.typeUrl = type_url
+        this.json = json
+    }
+
+    public static loadAll(): Message[] {
+        let ptr_len = unpackValues(_mamoru_get_messages());
+
+        return Message.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Message[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Message>((decoder: Decoder) => new Message(decoder));
+    }
+}
+
+// An ABCI event emitted by a transaction or by the block itself.
+export class Event {
+    // index of the event in the block
+    public readonly seq: u32
+    // hash of the transaction, null for `BeginBlock`/`EndBlock` events
+    public readonly txHash: string | null
+    // e.g. "transfer"
+    public readonly type: string
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt32();
+        let tx_hash: string | null = null;
+        if (!decoder.isNextNil()) {
+            tx_hash = decoder.readString();
+        }
+        let type_ = decoder.readString();
+
+        this.seq = seq
+        this.txHash = tx_hash
+        this.type = type_
+    }
+
+    // Deprecated, use `type` instead.
+    public get typ(): string {
+        return this.type
+    }
+
+    public static loadAll(): Event[] {
+        let ptr_len = unpackValues(_mamoru_get_events());
+
+        return Event.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Event[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Event>((decoder: Decoder) => new Event(decoder));
+    }
+}
+
+export class EventAttribute {
+    public readonly eventSeq: u32
+    public readonly key: string
+    public readonly value: string
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let event_seq = decoder.readUInt32();
+        let key = decoder.readString();
+        let value = decoder.readString();
+
+        this.eventSeq = event_seq
+        this.key = key
+        this.value = value
+    }
+
+    public static loadAll(): EventAttribute[] {
+        let ptr_len = unpackValues(_mamoru_get_event_attributes());
+
+        return EventAttribute.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): EventAttribute[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<EventAttribute>((decoder: Decoder) => new EventAttribute(decoder));
+    }
+}
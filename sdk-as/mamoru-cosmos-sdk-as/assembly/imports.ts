@@ -0,0 +1,14 @@
+@external("mamoru_cosmos", "get_blocks")
+export declare function _mamoru_get_blocks(): u64
+
+@external("mamoru_cosmos", "get_transactions")
+export declare function _mamoru_get_transactions(): u64
+
+@external("mamoru_cosmos", "get_messages")
+export declare function _mamoru_get_messages(): u64
+
+@external("mamoru_cosmos", "get_events")
+export declare function _mamoru_get_events(): u64
+
+@external("mamoru_cosmos", "get_event_attributes")
+export declare function _mamoru_get_event_attributes(): u64
@@ -0,0 +1,76 @@
+import { Block, Event, EventAttribute, Message, Transaction } from "./generated";
+
+export {
+    Block,
+    Transaction,
+    Message,
+    Event,
+    EventAttribute,
+}
+
+export class CosmosCtx {
+    private _block: Block | null
+    private _txs: Transaction[] | null
+    private _messages: Message[] | null
+    private _events: Event[] | null
+    private _eventAttributes: EventAttribute[] | null
+
+    private constructor() {
+        this._block = null
+        this._txs = null
+        this._messages = null
+        this._events = null
+        this._eventAttributes = null
+    }
+
+    public static load(): CosmosCtx {
+        return new CosmosCtx()
+    }
+
+    /// The block of the current context
+    public get block(): Block {
+        if (this._block == null) {
+            const blocks = Block.loadAll();
+
+            this._block = blocks[0]
+        }
+
+        return this._block!
+    }
+
+    /// All transactions in the current context
+    public get txs(): Transaction[] {
+        if (this._txs == null) {
+            this._txs = Transaction.loadAll()
+        }
+
+        return this._txs!
+    }
+
+    /// All transaction messages in the current context
+    public get messages(): Message[] {
+        if (this._messages == null) {
+            this._messages = Message.loadAll()
+        }
+
+        return this._messages!
+    }
+
+    /// All ABCI events in the current context
+    public get events(): Event[] {
+        if (this._events == null) {
+            this._events = Event.loadAll()
+        }
+
+        return this._events!
+    }
+
+    /// All ABCI event attributes in the current context
+    public get eventAttributes(): EventAttribute[] {
+        if (this._eventAttributes == null) {
+            this._eventAttributes = EventAttribute.loadAll()
+        }
+
+        return this._eventAttributes!
+    }
+}
@@ -0,0 +1 @@
+// Tests only crate
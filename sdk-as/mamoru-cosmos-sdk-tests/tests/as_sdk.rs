@@ -0,0 +1,159 @@
+use test_log::test;
+
+use mamoru_core::{BlockchainData, BlockchainDataBuilder};
+use mamoru_core_test_utils::assembly_script::{
+    AssemblyScriptModule, AS_COSMOS_SDK_PATH, AS_SDK_PATH,
+};
+use mamoru_core_test_utils::test_daemon;
+use mamoru_cosmos_types::{Block, CosmosCtx, Event, EventAttribute, Message, Transaction};
+
+fn cosmos_ctx() -> BlockchainData<CosmosCtx> {
+    let mut builder: BlockchainDataBuilder<CosmosCtx> = BlockchainDataBuilder::new();
+
+    builder.data_mut().set_block(Block {
+        height: 1,
+        hash: "some-hash".to_string(),
+        chain_id: "some-chain".to_string(),
+        timestamp: 2,
+        proposer_address: "some-proposer".to_string(),
+        app_hash: "some-app-hash".to_string(),
+    });
+
+    builder.data_mut().transactions.extend(vec![
+        Transaction {
+            index: 3,
+            hash: "some-tx-hash".to_string(),
+            block_height: 1,
+            gas_wanted: 4,
+            gas_used: 5,
+            code: 0,
+            codespace: "".to_string(),
+            fee: "500uatom".to_string(),
+            memo: "some-memo".to_string(),
+        },
+        Transaction {
+            index: 6,
+            hash: "another-tx-hash".to_string(),
+            block_height: 1,
+            gas_wanted: 7,
+            gas_used: 8,
+            code: 5,
+            codespace: "sdk".to_string(),
+            fee: "".to_string(),
+            memo: "".to_string(),
+        },
+    ]);
+
+    builder.data_mut().messages.push(Message {
+        tx_hash: "some-tx-hash".to_string(),
+        index: 0,
+        type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+        json: r#"{"from_address":"cosmos1a","to_address":"cosmos1b","amount":[{"denom":"uatom","amount":"10"}]}"#.to_string(),
+    });
+
+    builder.data_mut().events.extend(vec![
+        Event {
+            seq: 0,
+            tx_hash: None,
+            typ: "mint".to_string(),
+        },
+        Event {
+            seq: 1,
+            tx_hash: Some("some-tx-hash".to_string()),
+            typ: "transfer".to_string(),
+        },
+    ]);
+
+    builder.data_mut().event_attributes.extend(vec![
+        EventAttribute {
+            event_seq: 1,
+            key: "recipient".to_string(),
+            value: "cosmos1b".to_string(),
+        },
+        EventAttribute {
+            event_seq: 1,
+            key: "amount".to_string(),
+            value: "10uatom".to_string(),
+        },
+    ]);
+
+    builder.set_tx_data("test_tx", "test_digest");
+    builder.build().unwrap()
+}
+
+#[test(tokio::test)]
+async fn smoke() {
+    let ctx = cosmos_ctx();
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {assert} from "@mamoru-ai/mamoru-sdk-as/assembly";
+        import {CosmosCtx} from "@mamoru-ai/mamoru-cosmos-sdk-as/assembly";
+
+        export function main(): void {
+            const ctx = CosmosCtx.load();
+
+            const block = ctx.block;
+
+            assert(block.height == 1, "block.height == 1");
+            assert(block.hash == "some-hash", "block.hash == \"some-hash\"");
+            assert(block.chainId == "some-chain", "block.chain_id == \"some-chain\"");
+            assert(block.timestamp == 2, "block.timestamp == 2");
+            assert(block.proposerAddress == "some-proposer", "block.proposer_address == \"some-proposer\"");
+            assert(block.appHash == "some-app-hash", "block.app_hash == \"some-app-hash\"");
+
+            const tx1 = ctx.txs[0];
+
+            assert(tx1.index == 3, "tx1.index == 3");
+            assert(tx1.hash == "some-tx-hash", "tx1.hash == \"some-tx-hash\"");
+            assert(tx1.blockHeight == 1, "tx1.block_height == 1");
+            assert(tx1.gasWanted == 4, "tx1.gas_wanted == 4");
+            assert(tx1.gasUsed == 5, "tx1.gas_used == 5");
+            assert(tx1.code == 0, "tx1.code == 0");
+            assert(tx1.codespace == "", "tx1.codespace == \"\"");
+            assert(tx1.fee == "500uatom", "tx1.fee == \"500uatom\"");
+            assert(tx1.memo == "some-memo", "tx1.memo == \"some-memo\"");
+
+            const tx2 = ctx.txs[1];
+
+            assert(tx2.index == 6, "tx2.index == 6");
+            assert(tx2.hash == "another-tx-hash", "tx2.hash == \"another-tx-hash\"");
+            assert(tx2.code == 5, "tx2.code == 5");
+            assert(tx2.codespace == "sdk", "tx2.codespace == \"sdk\"");
+
+            const message = ctx.messages[0];
+
+            assert(message.txHash == "some-tx-hash", "message.tx_hash == \"some-tx-hash\"");
+            assert(message.index == 0, "message.index == 0");
+            assert(message.typeUrl == "/cosmos.bank.v1beta1.MsgSend", "message.type_url == \"/cosmos.bank.v1beta1.MsgSend\"");
+            assert(message.json.includes("\"to_address\":\"cosmos1b\""), "message.json contains to_address");
+
+            const event1 = ctx.events[0];
+
+            assert(event1.seq == 0, "event1.seq == 0");
+            assert(event1.txHash == null, "event1.tx_hash == null");
+            assert(event1.typ == "mint", "event1.typ == \"mint\"");
+
+            const event2 = ctx.events[1];
+
+            assert(event2.seq == 1, "event2.seq == 1");
+            assert(event2.txHash! == "some-tx-hash", "event2.tx_hash == \"some-tx-hash\"");
+            assert(event2.typ == "transfer", "event2.typ == \"transfer\"");
+
+            assert(ctx.eventAttributes.length == 2, "eventAttributes.length == 2");
+
+            const attribute = ctx.eventAttributes[1];
+
+            assert(attribute.eventSeq == 1, "attribute.event_seq == 1");
+            assert(attribute.key == "amount", "attribute.key == \"amount\"");
+            assert(attribute.value == "10uatom", "attribute.value == \"10uatom\"");
+        }
+    """#,
+        &[AS_SDK_PATH, AS_COSMOS_SDK_PATH],
+    );
+
+    test_daemon(&module)
+        .verify(&ctx)
+        .await
+        .expect("Assertion failed");
+}
@@ -1,76 +1,51 @@
-import { _mamoru_get_events } from "./imports";
-import { msgPackReadUint8Array, readMemory, unpackValues } from "@mamoru-ai/mamoru-sdk-as/assembly/util";
+import { readMemory, unpackValues } from "@mamoru-ai/mamoru-sdk-as/assembly/util";
 import { Decoder } from "@wapc/as-msgpack/assembly";
+import { encode } from "as-base64/assembly"
+import { Event as EventRow } from "./generated";
+import { _mamoru_get_events, _mamoru_parse_event } from "./imports";
+import { Value } from "./value";
+
+export class Event extends EventRow {
+    constructor(decoder: Decoder) {
+        super(decoder)
+    }
 
-export class Event {
-    public readonly index: u32
-    public readonly txIndex: u32
-    public readonly txHash: string
-    public readonly blockNumber: u64
-    public readonly blockHash: string
-    public readonly address: string
-    public readonly topic0: Uint8Array
-    public readonly topic1: Uint8Array
-    public readonly topic2: Uint8Array
-    public readonly topic3: Uint8Array
-    public readonly topic4: Uint8Array
-    public readonly data: Uint8Array
-
-    private constructor(
-        index: u32,
-        tx_index: u32,
-        tx_hash: string,
-        block_number: u64,
-        block_hash: string,
-        address: string,
-        topic0: Uint8Array,
-        topic1: Uint8Array,
-        topic2: Uint8Array,
-        topic3: Uint8Array,
-        topic4: Uint8Array,
-        data: Uint8Array
-    ) {
-        this.index = index
-        this.txIndex = tx_index
-        this.txHash = tx_hash
-        this.blockNumber = block_number
-        this.blockHash = block_hash
-        this.address = address
-        this.topic0 = topic0
-        this.topic1 = topic1
-        this.topic2 = topic2
-        this.topic3 = topic3
-        this.topic4 = topic4
-        this.data = data
+    // Parse the event topics and data using the provided ABI,
+    // deprecated, use `parseEvent` instead.
+    public parse(abi: string): Value[] | null {
+        return parseEvent(this, abi);
     }
 
-    public static loadAll(): Event[] {
-        let ptr_len = unpackValues(_mamoru_get_events());
+    public static load(): Event[] {
+        const ptr_len = unpackValues(_mamoru_get_events());
+        const decoder = new Decoder(readMemory(ptr_len[0], ptr_len[1]).buffer);
 
-        return Event.fromHost(ptr_len[0], ptr_len[1]);
+        return decoder.readArray<Event>((decoder: Decoder) => new Event(decoder));
     }
+}
 
-    private static fromHost(ptr: u32, len: u32): Event[] {
-        const decoder = new Decoder(readMemory(ptr, len).buffer);
-
-        return decoder.readArray<Event>((decoder: Decoder) => {
-            // consume array size (we can't parse data otherwise)
-            let _ = decoder.readArraySize();
+// Parse the event topics and data using the provided ABI.
+// The ABI format is "Transfer(address indexed,address indexed,uint256)".
+// Returns values in the order of the ABI parameters.
+// Indexed strings, bytes, arrays and tuples are returned as their keccak256 hashes.
+// Returns null if the event does not match the ABI.
+// Fails if the ABI is invalid.
+export function parseEvent(event: EventRow, abi: string): Value[] | null {
+    const result = _mamoru_parse_event(
+        abi,
+        encode(event.topic0),
+        encode(event.topic1),
+        encode(event.topic2),
+        encode(event.topic3),
+        encode(event.data),
+    );
+
+    if (result == 0) {
+        return null;
+    }
 
-            let index = decoder.readUInt32();
-            let tx_index = decoder.readUInt32();
-            let tx_hash = decoder.readString();
-            let block_number = decoder.readUInt64();
-            let block_hash = decoder.readString();
-            let address = decoder.readString();
-            let topic0 = msgPackReadUint8Array(decoder);
-            let topic1 = msgPackReadUint8Array(decoder);
-            let topic2 = msgPackReadUint8Array(decoder);
-            let topic3 = msgPackReadUint8Array(decoder);
-            let topic4 = msgPackReadUint8Array(decoder);
-            let data = msgPackReadUint8Array(decoder);
+    const ptr_len = unpackValues(result);
+    const buffer = readMemory(ptr_len[0], ptr_len[1]).buffer;
 
-            return new Event(index, tx_index, tx_hash, block_number, block_hash, address, topic0, topic1, topic2, topic3, topic4, data);
-        });
-    }
+    return Value.fromBytes(buffer);
 }
@@ -0,0 +1,499 @@
+// This file is generated from the Rust types by `mamoru_core::generate_as_sdk`, do not edit it.
+// Run the SDK tests with `UPDATE_EXPECT=1` to update it.
+import { _mamoru_get_blocks, _mamoru_get_transactions, _mamoru_get_call_traces, _mamoru_get_events, _mamoru_get_token_transfers, _mamoru_get_approvals, _mamoru_get_storage_changes, _mamoru_get_balance_changes } from "./imports";
+import { msgPackReadUint8Array, readMemory, unpackValues } from "@mamoru-ai/mamoru-sdk-as/assembly/util";
+import { TxInput, u256 } from "./types";
+import { Decoder } from "@wapc/as-msgpack/assembly";
+
+export class Block {
+    public readonly blockIndex: u64
+    public readonly hash: string
+    public readonly parentHash: string
+    public readonly stateRoot: string
+    // A nonce is a 64-bit hash which proves (combined with the mix-hash) that a sufficient amount of computation has been carried out on a block.
+    public readonly nonce: u64
+    public readonly status: string
+    public readonly timestamp: u64
+    public readonly blockReward: Uint8Array
+    public readonly feeRecipient: string
+    public readonly totalDifficulty: u64
+    public readonly size: f64
+    public readonly gasUsed: u64
+    public readonly gasLimit: u64
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let block_index = decoder.readUInt64();
+        let hash = decoder.readString();
+        let parent_hash = decoder.readString();
+        let state_root = decoder.readString();
+        let nonce = decoder.readUInt64();
+        let status = decoder.readString();
+        let timestamp = decoder.readUInt64();
+        let block_reward = msgPackReadUint8Array(decoder);
+        let fee_recipient = decoder.readString();
+        let total_difficulty = decoder.readUInt64();
+        let size = decoder.readFloat64();
+        let gas_used = decoder.readUInt64();
+        let gas_limit = decoder.readUInt64();
+
+        this.blockIndex = block_index
+        this.hash = hash
+        this.parentHash = parent_hash
+        this.stateRoot = state_root
+        this.nonce = nonce
+        this.status = status
+        this.timestamp = timestamp
+        this.blockReward = block_reward
+        this.feeRecipient = fee_recipient
+        this.totalDifficulty = total_difficulty
+        this.size = size
+        this.gasUsed = gas_used
+        this.gasLimit = gas_limit
+    }
+
+    public static loadAll(): Block[] {
+        let ptr_len = unpackValues(_mamoru_get_blocks());
+
+        return Block.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Block[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Block>((decoder: Decoder) => new Block(decoder));
+    }
+}
+
+// Transaction is an Ethereum transaction.
+export class Transaction {
+    public readonly txIndex: u32
+    // tx_hash the transaction hash.
+    public readonly txHash: string
+    // Type returns the transaction type.
+    public readonly type: u8
+    // Nonce returns the sender account nonce of the transaction.
+    public readonly nonce: u64
+    public readonly status: u64
+    public readonly blockIndex: u64
+    public readonly from: string
+    // To returns the recipient address of the transaction. For contract-creation transactions, To returns nil.
+    public readonly to: string | null
+    // Value returns the ether amount of the transaction.
+    public readonly value: u64
+    // GasFeeCap returns the fee cap per gas of the transaction.
+    public readonly fee: u64
+    // GasPrice returns the gas price of the transaction.
+    public readonly gasPrice: u64
+    // Gas returns the gas limit of the transaction.
+    public readonly gasLimit: u64
+    public readonly gasUsed: u64
+    // Data returns the input data of the transaction.
+    public readonly input: TxInput
+    // Size returns the true RLP encoded storage size of the transaction, either by encoding and returning it, or returning a previously cached value.
+    public readonly size: f64
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let tx_index = decoder.readUInt32();
+        let tx_hash = decoder.readString();
+        let type_ = decoder.readUInt8();
+        let nonce = decoder.readUInt64();
+        let status = decoder.readUInt64();
+        let block_index = decoder.readUInt64();
+        let from = decoder.readString();
+        let to: string | null = null;
+        if (!decoder.isNextNil()) {
+            to = decoder.readString();
+        }
+        let value = decoder.readUInt64();
+        let fee = decoder.readUInt64();
+        let gas_price = decoder.readUInt64();
+        let gas_limit = decoder.readUInt64();
+        let gas_used = decoder.readUInt64();
+        let input = new TxInput(msgPackReadUint8Array(decoder));
+        let size = decoder.readFloat64();
+
+        this.txIndex = tx_index
+        this.txHash = tx_hash
+        this.type = type_
+        this.nonce = nonce
+        this.status = status
+        this.blockIndex = block_index
+        this.from = from
+        this.to = to
+        this.value = value
+        this.fee = fee
+        this.gasPrice = gas_price
+        this.gasLimit = gas_limit
+        this.gasUsed = gas_used
+        this.input = input
+        this.size = size
+    }
+
+    // Deprecated, use `type` instead.
+    public get typ(): u8 {
+        return this.type
+    }
+
+    public static loadAll(): Transaction[] {
+        let ptr_len = unpackValues(_mamoru_get_transactions());
+
+        return Transaction.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Transaction[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Transaction>((decoder: Decoder) => new Transaction(decoder));
+    }
+}
+
+export class CallTrace {
+    public readonly seq: u32
+    public readonly txIndex: u32
+    public readonly blockIndex: u64
+    public readonly depth: u32
+    public readonly type: string
+    public readonly from: string
+    public readonly to: string
+    public readonly value: u64
+    public readonly gasLimit: u64
+    public readonly gasUsed: u64
+    public readonly input: TxInput
+    // data returned by the call, or the revert data if the call failed
+    public readonly output: Uint8Array
+    // error reported by the node, e.g. "execution reverted", null if the call succeeded
+    public readonly error: string | null
+    // decoded `Error(string)` or `Panic(uint256)` from `output`, filled on build if the call failed
+    public readonly revertReason: string | null
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt32();
+        let tx_index = decoder.readUInt32();
+        let block_index = decoder.readUInt64();
+        let depth = decoder.readUInt32();
+        let type_ = decoder.readString();
+        let from = decoder.readString();
+        let to = decoder.readString();
+        let value = decoder.readUInt64();
+        let gas_limit = decoder.readUInt64();
+        let gas_used = decoder.readUInt64();
+        let input = new TxInput(msgPackReadUint8Array(decoder));
+        let output = msgPackReadUint8Array(decoder);
+        let error: string | null = null;
+        if (!decoder.isNextNil()) {
+            error = decoder.readString();
+        }
+        let revert_reason: string | null = null;
+        if (!decoder.isNextNil()) {
+            revert_reason = decoder.readString();
+        }
+
+        this.seq = seq
+        this.txIndex = tx_index
+        this.blockIndex = block_index
+        this.depth = depth
+        this.type = type_
+        this.from = from
+        this.to = to
+        this.value = value
+        this.gasLimit = gas_limit
+        this.gasUsed = gas_used
+        this.input = input
+        this.output = output
+        this.error = error
+        this.revertReason = revert_reason
+    }
+
+    // Deprecated, use `type` instead.
+    public get typ(): string {
+        return this.type
+    }
+
+    public static loadAll(): CallTrace[] {
+        let ptr_len = unpackValues(_mamoru_get_call_traces());
+
+        return CallTrace.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): CallTrace[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<CallTrace>((decoder: Decoder) => new CallTrace(decoder));
+    }
+}
+
+// Event represents a contract log event. These events are generated by the LOG opcode and stored/indexed by the node.
+export class Event {
+    // index of the log in the block
+    public readonly index: u32
+    // index of the transaction in the block
+    public readonly txIndex: u32
+    // hash of the transaction
+    public readonly txHash: string
+    // block in which the transaction was included
+    public readonly blockNumber: u64
+    // hash of the block in which the transaction was included
+    public readonly blockHash: string
+    // address of the contract that generated the event
+    public readonly address: string
+    public readonly topic0: Uint8Array
+    public readonly topic1: Uint8Array
+    public readonly topic2: Uint8Array
+    public readonly topic3: Uint8Array
+    public readonly topic4: Uint8Array
+    // supplied by the contract, usually ABI-encoded
+    public readonly data: Uint8Array
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let index = decoder.readUInt32();
+        let tx_index = decoder.readUInt32();
+        let tx_hash = decoder.readString();
+        let block_number = decoder.readUInt64();
+        let block_hash = decoder.readString();
+        let address = decoder.readString();
+        let topic0 = msgPackReadUint8Array(decoder);
+        let topic1 = msgPackReadUint8Array(decoder);
+        let topic2 = msgPackReadUint8Array(decoder);
+        let topic3 = msgPackReadUint8Array(decoder);
+        let topic4 = msgPackReadUint8Array(decoder);
+        let data = msgPackReadUint8Array(decoder);
+
+        this.index = index
+        this.txIndex = tx_index
+        this.txHash = tx_hash
+        this.blockNumber = block_number
+        this.blockHash = block_hash
+        this.address = address
+        this.topic0 = topic0
+        this.topic1 = topic1
+        this.topic2 = topic2
+        this.topic3 = topic3
+        this.topic4 = topic4
+        this.data = data
+    }
+
+    public static loadAll(): Event[] {
+        let ptr_len = unpackValues(_mamoru_get_events());
+
+        return Event.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Event[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Event>((decoder: Decoder) => new Event(decoder));
+    }
+}
+
+// A token movement decoded from a standard ERC-20, ERC-721 or ERC-1155 event.
+export class TokenTransfer {
+    // index of the source event in the block
+    public readonly logIndex: u32
+    // index of the transaction in the block
+    public readonly txIndex: u32
+    // hash of the transaction
+    public readonly txHash: string
+    // address of the token contract
+    public readonly token: string
+    // one of "erc20", "erc721", "erc1155"
+    public readonly standard: string
+    public readonly from: string
+    public readonly to: string
+    // big-endian u256, null for ERC-20
+    public readonly tokenId: u256 | null
+    // big-endian u256, always 1 for ERC-721
+    public readonly amount: u256
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let log_index = decoder.readUInt32();
+        let tx_index = decoder.readUInt32();
+        let tx_hash = decoder.readString();
+        let token = decoder.readString();
+        let standard = decoder.readString();
+        let from = decoder.readString();
+        let to = decoder.readString();
+        let token_id: u256 | null = null;
+        if (!decoder.isNextNil()) {
+            token_id = u256.fromUint8ArrayBE(msgPackReadUint8Array(decoder));
+        }
+        let amount = u256.fromUint8ArrayBE(msgPackReadUint8Array(decoder));
+
+        this.logIndex = log_index
+        this.txIndex = tx_index
+        this.txHash = tx_hash
+        this.token = token
+        this.standard = standard
+        this.from = from
+        this.to = to
+        this.tokenId = token_id
+        this.amount = amount
+    }
+
+    public static loadAll(): TokenTransfer[] {
+        let ptr_len = unpackValues(_mamoru_get_token_transfers());
+
+        return TokenTransfer.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): TokenTransfer[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<TokenTransfer>((decoder: Decoder) => new TokenTransfer(decoder));
+    }
+}
+
+// An allowance decoded from a standard ERC-20 or ERC-721 `Approval` event.
+export class Approval {
+    // index of the source event in the block
+    public readonly logIndex: u32
+    // index of the transaction in the block
+    public readonly txIndex: u32
+    // hash of the transaction
+    public readonly txHash: string
+    // address of the token contract
+    public readonly token: string
+    // one of "erc20", "erc721"
+    public readonly standard: string
+    public readonly owner: string
+    public readonly spender: string
+    // big-endian u256, null for ERC-20
+    public readonly tokenId: u256 | null
+    // big-endian u256, always 1 for ERC-721
+    public readonly amount: u256
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let log_index = decoder.readUInt32();
+        let tx_index = decoder.readUInt32();
+        let tx_hash = decoder.readString();
+        let token = decoder.readString();
+        let standard = decoder.readString();
+        let owner = decoder.readString();
+        let spender = decoder.readString();
+        let token_id: u256 | null = null;
+        if (!decoder.isNextNil()) {
+            token_id = u256.fromUint8ArrayBE(msgPackReadUint8Array(decoder));
+        }
+        let amount = u256.fromUint8ArrayBE(msgPackReadUint8Array(decoder));
+
+        this.logIndex = log_index
+        this.txIndex = tx_index
+        this.txHash = tx_hash
+        this.token = token
+        this.standard = standard
+        this.owner = owner
+        this.spender = spender
+        this.tokenId = token_id
+        this.amount = amount
+    }
+
+    public static loadAll(): Approval[] {
+        let ptr_len = unpackValues(_mamoru_get_approvals());
+
+        return Approval.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Approval[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Approval>((decoder: Decoder) => new Approval(decoder));
+    }
+}
+
+// A contract storage slot write made by a transaction.
+export class StorageChange {
+    // index of the transaction in the block
+    public readonly txIndex: u32
+    // address of the contract which storage was changed
+    public readonly address: string
+    // 32-byte storage key
+    public readonly slot: Uint8Array
+    // 32-byte value before the transaction
+    public readonly previousValue: Uint8Array
+    // 32-byte value after the transaction
+    public readonly newValue: Uint8Array
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let tx_index = decoder.readUInt32();
+        let address = decoder.readString();
+        let slot = msgPackReadUint8Array(decoder);
+        let previous_value = msgPackReadUint8Array(decoder);
+        let new_value = msgPackReadUint8Array(decoder);
+
+        this.txIndex = tx_index
+        this.address = address
+        this.slot = slot
+        this.previousValue = previous_value
+        this.newValue = new_value
+    }
+
+    public static loadAll(): StorageChange[] {
+        let ptr_len = unpackValues(_mamoru_get_storage_changes());
+
+        return StorageChange.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): StorageChange[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<StorageChange>((decoder: Decoder) => new StorageChange(decoder));
+    }
+}
+
+// A native balance change of an account made by a transaction.
+export class BalanceChange {
+    // index of the transaction in the block
+    public readonly txIndex: u32
+    public readonly address: string
+    // big-endian u256, in wei
+    public readonly previousBalance: u256
+    // big-endian u256, in wei
+    public readonly newBalance: u256
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let tx_index = decoder.readUInt32();
+        let address = decoder.readString();
+        let previous_balance = u256.fromUint8ArrayBE(msgPackReadUint8Array(decoder));
+        let new_balance = u256.fromUint8ArrayBE(msgPackReadUint8Array(decoder));
+
+        this.txIndex = tx_index
+        this.address = address
+        this.previousBalance = previous_balance
+        this.newBalance = new_balance
+    }
+
+    public static loadAll(): BalanceChange[] {
+        let ptr_len = unpackValues(_mamoru_get_balance_changes());
+
+        return BalanceChange.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): BalanceChange[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<BalanceChange>((decoder: Decoder) => new BalanceChange(decoder));
+    }
+}
@@ -10,5 +10,20 @@ export declare function _mamoru_get_call_traces(): u64
 @external("mamoru_evm", "get_events")
 export declare function _mamoru_get_events(): u64
 
+@external("mamoru_evm", "get_token_transfers")
+export declare function _mamoru_get_token_transfers(): u64
+
+@external("mamoru_evm", "get_approvals")
+export declare function _mamoru_get_approvals(): u64
+
+@external("mamoru_evm", "get_storage_changes")
+export declare function _mamoru_get_storage_changes(): u64
+
+@external("mamoru_evm", "get_balance_changes")
+export declare function _mamoru_get_balance_changes(): u64
+
 @external("mamoru_evm", "parse_tx_input")
 export declare function _mamoru_parse_tx_input(abi: string, data: string): i64
+
+@external("mamoru_evm", "parse_event")
+export declare function _mamoru_parse_event(abi: string, topic0: string, topic1: string, topic2: string, topic3: string, data: string): i64
@@ -1,7 +1,5 @@
-import { Transaction } from "./tx";
-import { Event } from "./event";
-import { CallTrace } from "./call_trace";
-import { Block } from "./block";
+import { Approval, BalanceChange, Block, CallTrace, StorageChange, TokenTransfer, Transaction } from "./generated";
+import { Event, parseEvent } from "./event";
 import { Value } from "./value";
 import {TxInput} from "./tx_input";
 
@@ -10,8 +8,13 @@ export {
     TxInput,
     Value,
     Event,
+    parseEvent,
     CallTrace,
     Block,
+    TokenTransfer,
+    Approval,
+    StorageChange,
+    BalanceChange,
 }
 
 export class EvmCtx {
@@ -19,12 +22,20 @@ export class EvmCtx {
     private _txs: Transaction[] | null
     private _events: Event[] | null
     private _callTraces: CallTrace[] | null
+    private _tokenTransfers: TokenTransfer[] | null
+    private _approvals: Approval[] | null
+    private _storageChanges: StorageChange[] | null
+    private _balanceChanges: BalanceChange[] | null
 
     private constructor() {
         this._block = null
         this._txs = null
         this._events = null
         this._callTraces = null
+        this._tokenTransfers = null
+        this._approvals = null
+        this._storageChanges = null
+        this._balanceChanges = null
     }
 
     public static load(): EvmCtx {
@@ -51,7 +62,7 @@ export class EvmCtx {
 
     public get events(): Event[] {
         if (this._events == null) {
-            this._events = Event.loadAll()
+            this._events = Event.load()
         }
 
         return this._events!
@@ -64,4 +75,36 @@ export class EvmCtx {
 
         return this._callTraces!
     }
+
+    public get tokenTransfers(): TokenTransfer[] {
+        if (this._tokenTransfers == null) {
+            this._tokenTransfers = TokenTransfer.loadAll()
+        }
+
+        return this._tokenTransfers!
+    }
+
+    public get approvals(): Approval[] {
+        if (this._approvals == null) {
+            this._approvals = Approval.loadAll()
+        }
+
+        return this._approvals!
+    }
+
+    public get storageChanges(): StorageChange[] {
+        if (this._storageChanges == null) {
+            this._storageChanges = StorageChange.loadAll()
+        }
+
+        return this._storageChanges!
+    }
+
+    public get balanceChanges(): BalanceChange[] {
+        if (this._balanceChanges == null) {
+            this._balanceChanges = BalanceChange.loadAll()
+        }
+
+        return this._balanceChanges!
+    }
 }
@@ -0,0 +1,3 @@
+// The types of the generated columns converted with `#[schema(as_type = ...)]`.
+export { u256 } from "@mamoru-ai/mamoru-sdk-as/assembly";
+export { TxInput } from "./tx_input";
@@ -3,7 +3,9 @@ use test_log::test;
 use mamoru_core::{BlockchainData, BlockchainDataBuilder};
 use mamoru_core_test_utils::assembly_script::{AssemblyScriptModule, AS_EVM_SDK_PATH, AS_SDK_PATH};
 use mamoru_core_test_utils::test_daemon;
-use mamoru_evm_types::{Block, CallTrace, Event, EvmCtx, Transaction};
+use mamoru_evm_types::{
+    BalanceChange, Block, CallTrace, Event, EvmCtx, StorageChange, Transaction,
+};
 
 // mint(address,uint256) (0x55fe002aeff02f77364de339a1292923a15844b8,1200000000)
 const TX1_INPUT: &str = "40c10f1900000000000000000000000055fe002aeff02f77364de339a1292923a15844b80000000000000000000000000000000000000000000000000000000047868c00";
@@ -108,6 +110,9 @@ fn evm_ctx() -> BlockchainData<EvmCtx> {
             gas_limit: 85,
             gas_used: 86,
             input: vec![87, 88, 89],
+            output: vec![100, 101, 102],
+            error: None,
+            revert_reason: None,
         },
         CallTrace {
             seq: 90,
@@ -121,6 +126,9 @@ fn evm_ctx() -> BlockchainData<EvmCtx> {
             gas_limit: 95,
             gas_used: 96,
             input: vec![97, 98, 99],
+            output: vec![103, 104, 105],
+            error: Some("execution reverted".to_string()),
+            revert_reason: None,
         },
     ]);
 
@@ -244,6 +252,9 @@ async fn smoke() {
             assert(callTrace1.gasLimit == 85, "callTrace1.gas_limit == 85");
             assert(callTrace1.gasUsed == 86, "callTrace1.gas_used == 86");
             assert(callTrace1.input.data.toString() == "87,88,89", "callTrace1.input == [87, 88, 89]");
+            assert(callTrace1.output.toString() == "100,101,102", "callTrace1.output == [100, 101, 102]");
+            assert(callTrace1.error == null, "callTrace1.error == null");
+            assert(callTrace1.revertReason == null, "callTrace1.revert_reason == null");
 
             const callTrace2 = ctx.callTraces[1];
 
@@ -258,6 +269,221 @@ async fn smoke() {
             assert(callTrace2.gasLimit == 95, "callTrace2.gas_limit == 95");
             assert(callTrace2.gasUsed == 96, "callTrace2.gas_used == 96");
             assert(callTrace2.input.data.toString() == "97,98,99", "callTrace2.input == [97, 98, 99]");
+            assert(callTrace2.output.toString() == "103,104,105", "callTrace2.output == [103, 104, 105]");
+            assert(callTrace2.error! == "execution reverted", "callTrace2.error == \"execution reverted\"");
+            assert(callTrace2.revertReason == null, "callTrace2.revert_reason == null");
+        }
+    """#,
+        &[AS_SDK_PATH, AS_EVM_SDK_PATH],
+    );
+
+    test_daemon(&module)
+        .verify(&ctx)
+        .await
+        .expect("Assertion failed");
+}
+
+#[test(tokio::test)]
+async fn parse_event() {
+    let mut builder: BlockchainDataBuilder<EvmCtx> = BlockchainDataBuilder::new();
+
+    // Transfer(address indexed,address indexed,uint256) (0x55fe002aeff02f77364de339a1292923a15844b8,0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48,1200000000)
+    builder.data_mut().events.push(Event {
+        index: 0,
+        tx_index: 0,
+        tx_hash: "some-tx-hash".to_string(),
+        block_number: 0,
+        block_hash: "some-block-hash".to_string(),
+        address: "some-address".to_string(),
+        topic0: hex::decode("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+            .unwrap(),
+        topic1: hex::decode("00000000000000000000000055fe002aeff02f77364de339a1292923a15844b8")
+            .unwrap(),
+        topic2: hex::decode("000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+            .unwrap(),
+        topic3: vec![],
+        topic4: vec![],
+        data: hex::decode("0000000000000000000000000000000000000000000000000000000047868c00")
+            .unwrap(),
+    });
+
+    let ctx = builder.build().unwrap();
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {assert, u256} from "@mamoru-ai/mamoru-sdk-as/assembly";
+        import {EvmCtx} from "@mamoru-ai/mamoru-evm-sdk-as/assembly";
+
+        export function main(): void {
+            const ctx = EvmCtx.load();
+            const event = ctx.events[0];
+
+            const approval = event.parse("Approval(address indexed,address indexed,uint256)");
+            assert(approval == null, "approval == null");
+
+            const transfer = event.parse("Transfer(address indexed,address indexed,uint256)");
+
+            if (transfer == null) {
+                assert(false, "transfer == null");
+                return;
+            }
+
+            assert(transfer[0].asAddress() == "0x55fe002aeff02f77364de339a1292923a15844b8", "from == \"0x55fe002aeff02f77364de339a1292923a15844b8\"");
+            assert(transfer[1].asAddress() == "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", "to == \"0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48\"");
+            assert(transfer[2].asUint() == u256.fromU64(1200000000), "value == 1200000000");
+        }
+    """#,
+        &[AS_SDK_PATH, AS_EVM_SDK_PATH],
+    );
+
+    test_daemon(&module)
+        .verify(&ctx)
+        .await
+        .expect("Assertion failed");
+}
+
+#[test(tokio::test)]
+async fn token_transfers() {
+    let mut builder: BlockchainDataBuilder<EvmCtx> = BlockchainDataBuilder::new();
+    builder.data_mut().set_derive_token_tables(true);
+
+    // ERC-20 Transfer (0x55fe002aeff02f77364de339a1292923a15844b8,0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48,1200000000)
+    // and ERC-721 Approval (0x55fe002aeff02f77364de339a1292923a15844b8,0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48,7)
+    builder.data_mut().events.extend(vec![
+        Event {
+            index: 1,
+            tx_index: 2,
+            tx_hash: "some-tx-hash".to_string(),
+            block_number: 0,
+            block_hash: "some-block-hash".to_string(),
+            address: "some-erc20".to_string(),
+            topic0: hex::decode("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+                .unwrap(),
+            topic1: hex::decode("00000000000000000000000055fe002aeff02f77364de339a1292923a15844b8")
+                .unwrap(),
+            topic2: hex::decode("000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+                .unwrap(),
+            topic3: vec![],
+            topic4: vec![],
+            data: hex::decode("0000000000000000000000000000000000000000000000000000000047868c00")
+                .unwrap(),
+        },
+        Event {
+            index: 3,
+            tx_index: 2,
+            tx_hash: "some-tx-hash".to_string(),
+            block_number: 0,
+            block_hash: "some-block-hash".to_string(),
+            address: "some-erc721".to_string(),
+            topic0: hex::decode("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925")
+                .unwrap(),
+            topic1: hex::decode("00000000000000000000000055fe002aeff02f77364de339a1292923a15844b8")
+                .unwrap(),
+            topic2: hex::decode("000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+                .unwrap(),
+            topic3: hex::decode("0000000000000000000000000000000000000000000000000000000000000007")
+                .unwrap(),
+            topic4: vec![],
+            data: vec![],
+        },
+    ]);
+
+    let ctx = builder.build().unwrap();
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {assert, u256} from "@mamoru-ai/mamoru-sdk-as/assembly";
+        import {EvmCtx} from "@mamoru-ai/mamoru-evm-sdk-as/assembly";
+
+        export function main(): void {
+            const ctx = EvmCtx.load();
+
+            assert(ctx.tokenTransfers.length == 1, "tokenTransfers.length == 1");
+            const transfer = ctx.tokenTransfers[0];
+
+            assert(transfer.logIndex == 1, "logIndex == 1");
+            assert(transfer.txIndex == 2, "txIndex == 2");
+            assert(transfer.txHash == "some-tx-hash", "txHash == \"some-tx-hash\"");
+            assert(transfer.token == "some-erc20", "token == \"some-erc20\"");
+            assert(transfer.standard == "erc20", "standard == \"erc20\"");
+            assert(transfer.from == "0x55fe002aeff02f77364de339a1292923a15844b8", "from == \"0x55fe002aeff02f77364de339a1292923a15844b8\"");
+            assert(transfer.to == "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", "to == \"0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48\"");
+            assert(transfer.tokenId == null, "tokenId == null");
+            assert(transfer.amount == u256.fromU64(1200000000), "amount == 1200000000");
+
+            assert(ctx.approvals.length == 1, "approvals.length == 1");
+            const approval = ctx.approvals[0];
+
+            assert(approval.logIndex == 3, "logIndex == 3");
+            assert(approval.token == "some-erc721", "token == \"some-erc721\"");
+            assert(approval.standard == "erc721", "standard == \"erc721\"");
+            assert(approval.owner == "0x55fe002aeff02f77364de339a1292923a15844b8", "owner == \"0x55fe002aeff02f77364de339a1292923a15844b8\"");
+            assert(approval.spender == "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", "spender == \"0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48\"");
+            assert(approval.tokenId! == u256.fromU64(7), "tokenId == 7");
+            assert(approval.amount == u256.fromU64(1), "amount == 1");
+        }
+    """#,
+        &[AS_SDK_PATH, AS_EVM_SDK_PATH],
+    );
+
+    test_daemon(&module)
+        .verify(&ctx)
+        .await
+        .expect("Assertion failed");
+}
+
+#[test(tokio::test)]
+async fn state_changes() {
+    let mut builder: BlockchainDataBuilder<EvmCtx> = BlockchainDataBuilder::new();
+
+    builder.data_mut().storage_changes.push(StorageChange {
+        tx_index: 1,
+        address: "some-contract".to_string(),
+        slot: vec![2; 32],
+        previous_value: vec![3; 32],
+        new_value: vec![4; 32],
+    });
+
+    // 1 ether -> 2 ether
+    builder.data_mut().balance_changes.push(BalanceChange {
+        tx_index: 5,
+        address: "some-account".to_string(),
+        previous_balance: hex::decode(
+            "0000000000000000000000000000000000000000000000000de0b6b3a7640000",
+        )
+        .unwrap(),
+        new_balance: hex::decode(
+            "0000000000000000000000000000000000000000000000001bc16d674ec80000",
+        )
+        .unwrap(),
+    });
+
+    let ctx = builder.build().unwrap();
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {assert, u256} from "@mamoru-ai/mamoru-sdk-as/assembly";
+        import {EvmCtx} from "@mamoru-ai/mamoru-evm-sdk-as/assembly";
+
+        export function main(): void {
+            const ctx = EvmCtx.load();
+
+            assert(ctx.storageChanges.length == 1, "storageChanges.length == 1");
+            const storageChange = ctx.storageChanges[0];
+
+            assert(storageChange.txIndex == 1, "txIndex == 1");
+            assert(storageChange.address == "some-contract", "address == \"some-contract\"");
+            assert(storageChange.slot.length == 32 && storageChange.slot[0] == 2, "slot == [2; 32]");
+            assert(storageChange.previousValue.length == 32 && storageChange.previousValue[0] == 3, "previousValue == [3; 32]");
+            assert(storageChange.newValue.length == 32 && storageChange.newValue[0] == 4, "newValue == [4; 32]");
+
+            assert(ctx.balanceChanges.length == 1, "balanceChanges.length == 1");
+            const balanceChange = ctx.balanceChanges[0];
+
+            assert(balanceChange.txIndex == 5, "txIndex == 5");
+            assert(balanceChange.address == "some-account", "address == \"some-account\"");
+            assert(balanceChange.previousBalance == u256.fromU64(1000000000000000000), "previousBalance == 1 ether");
+            assert(balanceChange.newBalance == u256.fromU64(2000000000000000000), "newBalance == 2 ether");
         }
     """#,
         &[AS_SDK_PATH, AS_EVM_SDK_PATH],
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use test_log::test;
+
+use mamoru_core::{
+    BlockchainData, BlockchainDataBuilder, Daemon, DataError, IncidentData, IncidentSeverity,
+};
+use mamoru_evm_types::{Event, EvmCtx};
+
+// ERC-20 Transfer (0x55fe002aeff02f77364de339a1292923a15844b8,0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48,1200000000)
+// and ERC-721 Approval (0x55fe002aeff02f77364de339a1292923a15844b8,0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48,7)
+fn token_ctx(mut builder: BlockchainDataBuilder<EvmCtx>) -> BlockchainData<EvmCtx> {
+    builder.data_mut().events.extend(vec![
+        Event {
+            index: 1,
+            tx_index: 2,
+            tx_hash: "some-tx-hash".to_string(),
+            block_number: 0,
+            block_hash: "some-block-hash".to_string(),
+            address: "some-erc20".to_string(),
+            topic0: hex::decode("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+                .unwrap(),
+            topic1: hex::decode("00000000000000000000000055fe002aeff02f77364de339a1292923a15844b8")
+                .unwrap(),
+            topic2: hex::decode("000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+                .unwrap(),
+            topic3: vec![],
+            topic4: vec![],
+            data: hex::decode("0000000000000000000000000000000000000000000000000000000047868c00")
+                .unwrap(),
+        },
+        Event {
+            index: 3,
+            tx_index: 2,
+            tx_hash: "some-tx-hash".to_string(),
+            block_number: 0,
+            block_hash: "some-block-hash".to_string(),
+            address: "some-erc721".to_string(),
+            topic0: hex::decode("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925")
+                .unwrap(),
+            topic1: hex::decode("00000000000000000000000055fe002aeff02f77364de339a1292923a15844b8")
+                .unwrap(),
+            topic2: hex::decode("000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")
+                .unwrap(),
+            topic3: hex::decode("0000000000000000000000000000000000000000000000000000000000000007")
+                .unwrap(),
+            topic4: vec![],
+            data: vec![],
+        },
+    ]);
+
+    builder.build().unwrap()
+}
+
+fn sql_daemon(expression: &str) -> Daemon {
+    Daemon::new_sql(
+        "dummy".to_string(),
+        expression,
+        IncidentData {
+            message: "dummy".to_string(),
+            severity: IncidentSeverity::Info,
+        },
+        HashMap::new(),
+        HashMap::from([(
+            Daemon::MAMORU_VERSION_KEY.to_string(),
+            "0.1.0".parse().unwrap(),
+        )]),
+    )
+    .expect("Failed to create daemon.")
+}
+
+const TOKEN_TRANSFERS_QUERY: &str = r#"
+    SELECT report(tt.tx_hash, 'info', concat(tt.standard, ' ', tt."from", ' ', tt."to"))
+    FROM token_transfers tt
+    WHERE tt.token = 'some-erc20'
+        AND tt.token_id IS NULL
+        AND tt.amount = hex_to_bytes('0x0000000000000000000000000000000000000000000000000000000047868c00')
+"#;
+
+const APPROVALS_QUERY: &str = r#"
+    SELECT report(a.tx_hash, 'info', concat(a.standard, ' ', a.owner, ' ', a.spender))
+    FROM approvals a
+    WHERE a.token = 'some-erc721'
+        AND a.token_id = hex_to_bytes('0x0000000000000000000000000000000000000000000000000000000000000007')
+"#;
+
+#[test(tokio::test)]
+async fn token_transfers_are_queryable() -> Result<(), DataError> {
+    let mut builder: BlockchainDataBuilder<EvmCtx> = BlockchainDataBuilder::new();
+    builder.data_mut().set_derive_token_tables(true);
+
+    let ctx = token_ctx(builder);
+
+    let data = sql_daemon(TOKEN_TRANSFERS_QUERY).verify(&ctx).await?;
+
+    assert_eq!(data.incidents.len(), 1);
+    assert_eq!(data.incidents[0].tx_hash, "some-tx-hash");
+    assert_eq!(
+        data.incidents[0].message,
+        "erc20 0x55fe002aeff02f77364de339a1292923a15844b8 0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+    );
+
+    let data = sql_daemon(APPROVALS_QUERY).verify(&ctx).await?;
+
+    assert_eq!(data.incidents.len(), 1);
+    assert_eq!(
+        data.incidents[0].message,
+        "erc721 0x55fe002aeff02f77364de339a1292923a15844b8 0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+    );
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn token_tables_are_empty_by_default() -> Result<(), DataError> {
+    let ctx = token_ctx(BlockchainDataBuilder::<EvmCtx>::new());
+
+    assert!(sql_daemon(TOKEN_TRANSFERS_QUERY)
+        .verify(&ctx)
+        .await?
+        .incidents
+        .is_empty());
+    assert!(sql_daemon(APPROVALS_QUERY)
+        .verify(&ctx)
+        .await?
+        .incidents
+        .is_empty());
+
+    Ok(())
+}
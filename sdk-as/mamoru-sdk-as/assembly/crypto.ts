@@ -0,0 +1,58 @@
+import { _mamoru_blake2b, _mamoru_ecrecover, _mamoru_keccak256, _mamoru_sha256 } from "./imports";
+import { readMemory, unpackValues } from "./util";
+
+/**
+ * Hashes the data with Keccak-256, e.g. to compute EVM selectors, event topics or storage slots.
+ *
+ * @param data
+ */
+export function keccak256(data: Uint8Array): Uint8Array {
+    return readPacked(_mamoru_keccak256(changetype<u32>(data.dataStart), data.byteLength));
+}
+
+/**
+ * Hashes the data with SHA-256.
+ *
+ * @param data
+ */
+export function sha256(data: Uint8Array): Uint8Array {
+    return readPacked(_mamoru_sha256(changetype<u32>(data.dataStart), data.byteLength));
+}
+
+/**
+ * Hashes the data with BLAKE2b-256, e.g. to compute Sui addresses.
+ *
+ * @param data
+ */
+export function blake2b(data: Uint8Array): Uint8Array {
+    return readPacked(_mamoru_blake2b(changetype<u32>(data.dataStart), data.byteLength));
+}
+
+/**
+ * Recovers the EVM address that signed the hash.
+ *
+ * @param hash 32 bytes message hash
+ * @param signature 65 bytes `r || s || v` signature
+ *
+ * @returns {Uint8Array | null} 20 bytes address or `null` if the signature is invalid
+ */
+export function ecrecover(hash: Uint8Array, signature: Uint8Array): Uint8Array | null {
+    const packed = _mamoru_ecrecover(
+        changetype<u32>(hash.dataStart),
+        hash.byteLength,
+        changetype<u32>(signature.dataStart),
+        signature.byteLength,
+    );
+
+    if (packed == 0) {
+        return null;
+    }
+
+    return readPacked(packed);
+}
+
+function readPacked(packed: u64): Uint8Array {
+    const ptr_len = unpackValues(packed);
+
+    return readMemory(ptr_len[0], ptr_len[1]);
+}
@@ -4,14 +4,32 @@ export declare function _mamoru_http(request: string): string
 @external("mamoru", "query")
 export declare function _mamoru_query(query: string): string
 
+@external("mamoru", "query_v2")
+export declare function _mamoru_query_v2(query: string, format: u32): u64
+
 @external("mamoru", "parameter")
 export declare function _mamoru_parameter(key: string): string
 
 @external("mamoru", "report")
 export declare function _mamoru_report(incident: string): void
 
+@external("mamoru", "log")
+export declare function _mamoru_log(level: u32, message: string): void
+
 @external("mamoru", "u256_from_str")
 export declare function _mamoru_u256_from_str(payload: string): u64
 
+@external("mamoru", "keccak256")
+export declare function _mamoru_keccak256(ptr: u32, len: u32): u64
+
+@external("mamoru", "sha256")
+export declare function _mamoru_sha256(ptr: u32, len: u32): u64
+
+@external("mamoru", "blake2b")
+export declare function _mamoru_blake2b(ptr: u32, len: u32): u64
+
+@external("mamoru", "ecrecover")
+export declare function _mamoru_ecrecover(hash_ptr: u32, hash_len: u32, signature_ptr: u32, signature_len: u32): u64
+
 @external("env", "assert")
 export declare function _env_assert(condition: i32, message: string): void
@@ -1,4 +1,4 @@
-import { JSONEncoder } from "assemblyscript-json/assembly";
+import { JSON, JSONEncoder } from "assemblyscript-json/assembly";
 import { encode } from "as-base64/assembly";
 
 export class Incident {
@@ -7,6 +7,12 @@ export class Incident {
     message: string
     data: Uint8Array | null
     address: string
+    title: string = ""
+    addresses: IncidentAddress[] = []
+    tags: string[] = []
+    confidence: f64 = -1
+    details: JSON.Obj | null = null
+    fingerprint: string = ""
 
     public constructor(txHash: string, severity: IncidentSeverity, message: string, data: Uint8Array | null = null, address: string = "") {
         this.txHash = txHash;
@@ -16,6 +22,58 @@ export class Incident {
         this.address = address;
     }
 
+    /**
+     * A short summary, the message keeps the description.
+     */
+    withTitle(title: string): Incident {
+        this.title = title;
+
+        return this;
+    }
+
+    /**
+     * Adds an address related to the incident, e.g. "attacker" or "victim".
+     */
+    withAddress(address: string, role: string): Incident {
+        this.addresses.push(new IncidentAddress(address, role));
+
+        return this;
+    }
+
+    withTag(tag: string): Incident {
+        this.tags.push(tag);
+
+        return this;
+    }
+
+    /**
+     * How sure the daemon is, from 0.0 to 1.0.
+     */
+    withConfidence(confidence: f64): Incident {
+        this.confidence = confidence;
+
+        return this;
+    }
+
+    /**
+     * Structured data readable by triage tools.
+     */
+    withDetails(details: JSON.Obj): Incident {
+        this.details = details;
+
+        return this;
+    }
+
+    /**
+     * Identifies the same incident reported multiple times,
+     * the runtime hashes the incident fields if it's not set.
+     */
+    withFingerprint(fingerprint: string): Incident {
+        this.fingerprint = fingerprint;
+
+        return this;
+    }
+
     toJSON(): string {
         let encoder = new JSONEncoder();
 
@@ -35,10 +93,63 @@ export class Incident {
                 encoder.setString("data", encode(data));
             }
 
+            if (this.title != "") {
+                encoder.setString("title", this.title);
+            }
+
+            if (this.addresses.length > 0) {
+                encoder.pushArray("addresses");
+
+                for (let i = 0; i < this.addresses.length; i++) {
+                    encoder.pushObject(null);
+                    encoder.setString("address", this.addresses[i].address);
+                    encoder.setString("role", this.addresses[i].role);
+                    encoder.popObject();
+                }
+
+                encoder.popArray();
+            }
+
+            if (this.tags.length > 0) {
+                encoder.pushArray("tags");
+
+                for (let i = 0; i < this.tags.length; i++) {
+                    encoder.setString(null, this.tags[i]);
+                }
+
+                encoder.popArray();
+            }
+
+            if (this.confidence >= 0) {
+                encoder.setFloat("confidence", this.confidence);
+            }
+
+            if (this.fingerprint != "") {
+                encoder.setString("fingerprint", this.fingerprint);
+            }
+
             encoder.popObject();
         }
 
-        return encoder.toString();
+        const json = encoder.toString();
+        const details = this.details;
+
+        // `JSONEncoder` can't embed an encoded object
+        if (details != null) {
+            return json.slice(0, json.length - 1) + ",\"details\":" + details.stringify() + "}";
+        }
+
+        return json;
+    }
+}
+
+export class IncidentAddress {
+    address: string
+    role: string
+
+    public constructor(address: string, role: string) {
+        this.address = address;
+        this.role = role;
     }
 }
 
@@ -1,14 +1,25 @@
 // The entry file of your WebAssembly module.
 import { JSON } from "assemblyscript-json/assembly";
 
-import { _env_assert, _mamoru_http, _mamoru_parameter, _mamoru_query, _mamoru_report, } from "./imports";
+import {
+    _env_assert,
+    _mamoru_http,
+    _mamoru_log,
+    _mamoru_parameter,
+    _mamoru_query,
+    _mamoru_query_v2,
+    _mamoru_report,
+} from "./imports";
 
 import { HttpMethod, HttpRequest, HttpResponse } from "./http";
-import { Incident, IncidentSeverity, } from "./incident";
+import { Incident, IncidentAddress, IncidentSeverity, } from "./incident";
 import { Value } from "./value";
+import { QueryColumn, QueryFormat, QueryRow, QueryRows } from "./query";
+import { readMemory, unpackValues } from "./util";
 import { i128, i256Safe, u128, u256 } from "as-bignum/assembly";
 
 export * from "./util";
+export * from "./crypto";
 
 export {
     i128,
@@ -19,8 +30,13 @@ export {
     HttpMethod,
     HttpRequest,
     HttpResponse,
+    Incident,
+    IncidentAddress,
     IncidentSeverity,
     Value,
+    QueryColumn,
+    QueryRow,
+    QueryRows,
 };
 
 /**
@@ -70,6 +86,39 @@ export function query(query: string): JSON.Obj[] {
     return json.valueOf().map((value: JSON.Value) => value as JSON.Obj);
 }
 
+/**
+ * Queries the daemon context, keeping the column types.
+ * Binary and 64-bit integer columns are returned as is, unlike `query`.
+ * @param query Valid SQL query for the network.
+ *
+ * @example
+ * let result = queryRows("SELECT t.gas_used, t.input FROM transactions t");
+ *
+ * result.rows.forEach(row => {
+ *     let gas_used = row.getUInt("gas_used");
+ *     let input = row.getBytes("input");
+ * });
+ */
+export function queryRows(query: string): QueryRows {
+    return QueryRows.fromBytes(queryV2(query, QueryFormat.MessagePack).buffer);
+}
+
+/**
+ * Queries the daemon context.
+ * @param query Valid SQL query for the network.
+ *
+ * @return {Uint8Array} The result as an Arrow IPC stream.
+ */
+export function queryArrow(query: string): Uint8Array {
+    return queryV2(query, QueryFormat.ArrowIpc);
+}
+
+function queryV2(query: string, format: QueryFormat): Uint8Array {
+    const ptr_len = unpackValues(_mamoru_query_v2(query, format));
+
+    return readMemory(ptr_len[0], ptr_len[1]);
+}
+
 /**
  * Reports an incident to Validation Chain.
  *
@@ -87,6 +136,47 @@ export function report(
     _mamoru_report((new Incident(txHash, severity, message, data, address)).toJSON())
 }
 
+/**
+ * Reports an incident with the triage fields to Validation Chain.
+ * Requires the runtime supporting SDK 0.11.0.
+ *
+ * @example
+ *
+ * reportIncident(
+ *     new Incident(tx.txHash, IncidentSeverity.Alert, "Pool drained")
+ *         .withTitle("Exploit")
+ *         .withAddress(tx.from, "attacker")
+ *         .withTag("defi")
+ *         .withConfidence(0.9)
+ * );
+ */
+export function reportIncident(incident: Incident): void {
+    _mamoru_report(incident.toJSON())
+}
+
+/**
+ * The level of a message passed to `log`.
+ */
+export enum LogLevel {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+    Debug = 3,
+    Trace = 4,
+}
+
+/**
+ * Logs a diagnostic message.
+ * The messages over the per-run limit are dropped.
+ *
+ * @example
+ *
+ * log(LogLevel.Debug, "Transactions: " + rows.length.toString());
+ */
+export function log(level: LogLevel, message: string): void {
+    _mamoru_log(level, message)
+}
+
 /**
  * Retrieve a parameter value from the daemon configuration.
  *
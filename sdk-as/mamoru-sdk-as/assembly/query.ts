@@ -0,0 +1,168 @@
+import { Decoder } from "@wapc/as-msgpack/assembly";
+import { msgPackReadUint8Array } from "./util";
+
+/**
+ * The result format of `_mamoru_query_v2`.
+ */
+export enum QueryFormat {
+    MessagePack = 0,
+    ArrowIpc = 1,
+}
+
+const BOOL_KIND: string = "bool";
+const INT_KIND: string = "int";
+const UINT_KIND: string = "uint";
+const FLOAT_KIND: string = "float";
+const STRING_KIND: string = "string";
+const BINARY_KIND: string = "binary";
+
+export class QueryColumn {
+    public readonly name: string;
+    /**
+     * One of `bool`, `int`, `uint`, `float`, `string` or `binary`.
+     * The columns of other SQL types are returned as `string`.
+     */
+    public readonly kind: string;
+
+    public constructor(name: string, kind: string) {
+        this.name = name;
+        this.kind = kind;
+    }
+}
+
+class QueryValue {
+    public readonly isNull: boolean;
+    public boolValue: bool = false;
+    public intValue: i64 = 0;
+    public uintValue: u64 = 0;
+    public floatValue: f64 = 0;
+    public stringValue: string = "";
+    public bytesValue: Uint8Array = new Uint8Array(0);
+
+    public constructor(isNull: boolean) {
+        this.isNull = isNull;
+    }
+
+    public static fromDecoder(decoder: Decoder, kind: string): QueryValue {
+        // consumes the nil value
+        if (decoder.isNextNil()) {
+            return new QueryValue(true);
+        }
+
+        const value = new QueryValue(false);
+
+        if (kind == BOOL_KIND) {
+            value.boolValue = decoder.readBool();
+        } else if (kind == INT_KIND) {
+            value.intValue = decoder.readInt64();
+        } else if (kind == UINT_KIND) {
+            value.uintValue = decoder.readUInt64();
+        } else if (kind == FLOAT_KIND) {
+            value.floatValue = decoder.readFloat64();
+        } else if (kind == STRING_KIND) {
+            value.stringValue = decoder.readString();
+        } else if (kind == BINARY_KIND) {
+            value.bytesValue = msgPackReadUint8Array(decoder);
+        } else {
+            throw new Error("unknown query column kind: " + kind);
+        }
+
+        return value;
+    }
+}
+
+export class QueryRow {
+    private readonly _columns: QueryColumn[];
+    private readonly _values: QueryValue[];
+
+    public constructor(columns: QueryColumn[], values: QueryValue[]) {
+        this._columns = columns;
+        this._values = values;
+    }
+
+    public isNull(column: string): boolean {
+        return this.value(column, null).isNull;
+    }
+
+    public getBool(column: string): bool {
+        return this.value(column, BOOL_KIND).boolValue;
+    }
+
+    public getInt(column: string): i64 {
+        return this.value(column, INT_KIND).intValue;
+    }
+
+    public getUInt(column: string): u64 {
+        return this.value(column, UINT_KIND).uintValue;
+    }
+
+    public getFloat(column: string): f64 {
+        return this.value(column, FLOAT_KIND).floatValue;
+    }
+
+    public getString(column: string): string {
+        return this.value(column, STRING_KIND).stringValue;
+    }
+
+    public getBytes(column: string): Uint8Array {
+        return this.value(column, BINARY_KIND).bytesValue;
+    }
+
+    private value(column: string, kind: string | null): QueryValue {
+        for (let i = 0; i < this._columns.length; i++) {
+            if (this._columns[i].name != column) {
+                continue;
+            }
+
+            if (kind != null && this._columns[i].kind != kind) {
+                throw new Error("column \"" + column + "\" is " + this._columns[i].kind + ", not " + kind);
+            }
+
+            return this._values[i];
+        }
+
+        throw new Error("unknown query column: " + column);
+    }
+}
+
+/**
+ * The typed rows returned by `queryRows`.
+ */
+export class QueryRows {
+    public readonly columns: QueryColumn[];
+    public readonly rows: QueryRow[];
+
+    private constructor(columns: QueryColumn[], rows: QueryRow[]) {
+        this.columns = columns;
+        this.rows = rows;
+    }
+
+    public static fromBytes(data: ArrayBuffer): QueryRows {
+        const decoder = new Decoder(data);
+        const _ = decoder.readArraySize();
+
+        const columns = decoder.readArray<QueryColumn>((decoder: Decoder) => {
+            const _ = decoder.readArraySize();
+            const name = decoder.readString();
+            const kind = decoder.readString();
+
+            return new QueryColumn(name, kind);
+        });
+
+        const rowsSize = decoder.readArraySize();
+        let rows = new Array<QueryRow>();
+
+        for (let i: u32 = 0; i < rowsSize; i++) {
+            const size = decoder.readArraySize();
+            let values = new Array<QueryValue>();
+
+            for (let j: u32 = 0; j < size; j++) {
+                values.push(QueryValue.fromDecoder(decoder, columns[j].kind));
+            }
+
+            rows.push(new QueryRow(columns, values));
+        }
+
+        return new QueryRows(columns, rows);
+    }
+}
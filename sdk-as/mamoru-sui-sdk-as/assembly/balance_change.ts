@@ -0,0 +1,17 @@
+import { i128 } from "@mamoru-ai/mamoru-sdk-as/assembly";
+
+// Decodes `BalanceChange.amount`, the host sends the two's complement i128 as 16 big-endian bytes.
+export function i128FromBE(bytes: Uint8Array): i128 {
+    let hi: i64 = 0;
+    let lo: u64 = 0;
+
+    for (let i = 0; i < 8; i++) {
+        hi = (hi << 8) | <i64>bytes[i];
+    }
+
+    for (let i = 8; i < 16; i++) {
+        lo = (lo << 8) | <u64>bytes[i];
+    }
+
+    return new i128(lo, hi);
+}
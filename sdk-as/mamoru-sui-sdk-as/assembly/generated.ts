@@ -0,0 +1,369 @@
+// This file is generated from the Rust types by `mamoru_core::generate_as_sdk`, do not edit it.
+// Run the SDK tests with `UPDATE_EXPECT=1` to update it.
+import { _mamoru_get_checkpoints, _mamoru_get_transactions, _mamoru_get_call_traces, _mamoru_get_call_trace_type_args, _mamoru_get_call_trace_args, _mamoru_get_call_trace_arg_by_id, _mamoru_get_events, _mamoru_get_object_changes, _mamoru_get_balance_changes } from "./imports";
+import { msgPackReadUint8Array, readMemory, unpackValues } from "@mamoru-ai/mamoru-sdk-as/assembly/util";
+import { Value } from "@mamoru-ai/mamoru-sdk-as/assembly";
+import { i128, i128FromBE } from "./types";
+import { Decoder } from "@wapc/as-msgpack/assembly";
+
+export class Checkpoint {
+    public readonly seq: u64
+    public readonly digest: string
+    // null for the genesis checkpoint
+    public readonly previousDigest: string | null
+    public readonly epoch: u64
+    public readonly time: i64
+    // total number of transactions committed on the network, including this checkpoint
+    public readonly networkTotalTransactions: u64
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt64();
+        let digest = decoder.readString();
+        let previous_digest: string | null = null;
+        if (!decoder.isNextNil()) {
+            previous_digest = decoder.readString();
+        }
+        let epoch = decoder.readUInt64();
+        let time = decoder.readInt64();
+        let network_total_transactions = decoder.readUInt64();
+
+        this.seq = seq
+        this.digest = digest
+        this.previousDigest = previous_digest
+        this.epoch = epoch
+        this.time = time
+        this.networkTotalTransactions = network_total_transactions
+    }
+
+    public static loadAll(): Checkpoint[] {
+        let ptr_len = unpackValues(_mamoru_get_checkpoints());
+
+        return Checkpoint.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Checkpoint[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Checkpoint>((decoder: Decoder) => new Checkpoint(decoder));
+    }
+}
+
+export class Transaction {
+    public readonly seq: u64
+    public readonly digest: string
+    public readonly time: i64
+    public readonly gasUsed: u64
+    public readonly gasComputationCost: u64
+    public readonly gasStorageCost: u64
+    public readonly gasBudget: u64
+    public readonly sender: string
+    public readonly kind: string
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt64();
+        let digest = decoder.readString();
+        let time = decoder.readInt64();
+        let gas_used = decoder.readUInt64();
+        let gas_computation_cost = decoder.readUInt64();
+        let gas_storage_cost = decoder.readUInt64();
+        let gas_budget = decoder.readUInt64();
+        let sender = decoder.readString();
+        let kind = decoder.readString();
+
+        this.seq = seq
+        this.digest = digest
+        this.time = time
+        this.gasUsed = gas_used
+        this.gasComputationCost = gas_computation_cost
+        this.gasStorageCost = gas_storage_cost
+        this.gasBudget = gas_budget
+        this.sender = sender
+        this.kind = kind
+    }
+
+    public static loadAll(): Transaction[] {
+        let ptr_len = unpackValues(_mamoru_get_transactions());
+
+        return Transaction.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Transaction[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Transaction>((decoder: Decoder) => new Transaction(decoder));
+    }
+}
+
+export class CallTrace {
+    public readonly seq: u64
+    public readonly txSeq: u64
+    public readonly depth: u32
+    public readonly callType: u8
+    public readonly gasUsed: u64
+    public readonly transactionModule: string | null
+    public readonly function: string
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt64();
+        let tx_seq = decoder.readUInt64();
+        let depth = decoder.readUInt32();
+        let call_type = decoder.readUInt8();
+        let gas_used = decoder.readUInt64();
+        let transaction_module: string | null = null;
+        if (!decoder.isNextNil()) {
+            transaction_module = decoder.readString();
+        }
+        let function_ = decoder.readString();
+
+        this.seq = seq
+        this.txSeq = tx_seq
+        this.depth = depth
+        this.callType = call_type
+        this.gasUsed = gas_used
+        this.transactionModule = transaction_module
+        this.function = function_
+    }
+
+    // Deprecated, use `function` instead.
+    public get func(): string {
+        return this.function
+    }
+
+    public static loadAll(): CallTrace[] {
+        let ptr_len = unpackValues(_mamoru_get_call_traces());
+
+        return CallTrace.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): CallTrace[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<CallTrace>((decoder: Decoder) => new CallTrace(decoder));
+    }
+}
+
+export class CallTraceTypeArg {
+    public readonly seq: u64
+    public readonly callTraceSeq: u64
+    public readonly arg: string
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt64();
+        let call_trace_seq = decoder.readUInt64();
+        let arg = decoder.readString();
+
+        this.seq = seq
+        this.callTraceSeq = call_trace_seq
+        this.arg = arg
+    }
+
+    public static loadAll(): CallTraceTypeArg[] {
+        let ptr_len = unpackValues(_mamoru_get_call_trace_type_args());
+
+        return CallTraceTypeArg.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): CallTraceTypeArg[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<CallTraceTypeArg>((decoder: Decoder) => new CallTraceTypeArg(decoder));
+    }
+}
+
+export class CallTraceArg {
+    public readonly seq: u64
+    public readonly callTraceSeq: u64
+
+    private _arg: Value | null = null
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let seq = decoder.readUInt64();
+        let call_trace_seq = decoder.readUInt64();
+
+        this.seq = seq
+        this.callTraceSeq = call_trace_seq
+    }
+
+    public get arg(): Value {
+        if (this._arg == null) {
+            const ptr_len = unpackValues(_mamoru_get_call_trace_arg_by_id(this.seq));
+            const buffer = readMemory(ptr_len[0], ptr_len[1]).buffer;
+
+            this._arg = Value.fromBytes(buffer);
+        }
+
+        return this._arg!;
+    }
+
+    // Deprecated, use `arg` instead.
+    public get value(): Value {
+        return this.arg
+    }
+
+    public static loadAll(): CallTraceArg[] {
+        let ptr_len = unpackValues(_mamoru_get_call_trace_args());
+
+        return CallTraceArg.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): CallTraceArg[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<CallTraceArg>((decoder: Decoder) => new CallTraceArg(decoder));
+    }
+}
+
+export class Event {
+    public readonly txSeq: u64
+    public readonly packageId: string
+    public readonly transactionModule: string
+    public readonly sender: string
+    public readonly type: string
+    public readonly contents: Uint8Array
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let tx_seq = decoder.readUInt64();
+        let package_id = decoder.readString();
+        let transaction_module = decoder.readString();
+        let sender = decoder.readString();
+        let type_ = decoder.readString();
+        let contents = msgPackReadUint8Array(decoder);
+
+        this.txSeq = tx_seq
+        this.packageId = package_id
+        this.transactionModule = transaction_module
+        this.sender = sender
+        this.type = type_
+        this.contents = contents
+    }
+
+    // Deprecated, use `type` instead.
+    public get typ(): string {
+        return this.type
+    }
+
+    public static loadAll(): Event[] {
+        let ptr_len = unpackValues(_mamoru_get_events());
+
+        return Event.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): Event[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<Event>((decoder: Decoder) => new Event(decoder));
+    }
+}
+
+// An object created, mutated or removed by the transaction.
+export class ObjectChange {
+    public readonly txSeq: u64
+    public readonly objectId: string
+    public readonly type: string
+    // null if the object is created by the transaction
+    public readonly ownerBefore: string | null
+    // null if the object is deleted or wrapped by the transaction
+    public readonly ownerAfter: string | null
+    // object version after the transaction
+    public readonly version: u64
+    // one of `created`, `mutated`, `transferred`, `deleted`, `wrapped`, `unwrapped`, `published`
+    public readonly kind: string
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let tx_seq = decoder.readUInt64();
+        let object_id = decoder.readString();
+        let type_ = decoder.readString();
+        let owner_before: string | null = null;
+        if (!decoder.isNextNil()) {
+            owner_before = decoder.readString();
+        }
+        let owner_after: string | null = null;
+        if (!decoder.isNextNil()) {
+            owner_after = decoder.readString();
+        }
+        let version = decoder.readUInt64();
+        let kind = decoder.readString();
+
+        this.txSeq = tx_seq
+        this.objectId = object_id
+        this.type = type_
+        this.ownerBefore = owner_before
+        this.ownerAfter = owner_after
+        this.version = version
+        this.kind = kind
+    }
+
+    // Deprecated, use `type` instead.
+    public get typ(): string {
+        return this.type
+    }
+
+    public static loadAll(): ObjectChange[] {
+        let ptr_len = unpackValues(_mamoru_get_object_changes());
+
+        return ObjectChange.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): ObjectChange[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<ObjectChange>((decoder: Decoder) => new ObjectChange(decoder));
+    }
+}
+
+// A coin balance change of an owner made by the transaction.
+export class BalanceChange {
+    public readonly txSeq: u64
+    public readonly owner: string
+    public readonly coinType: string
+    // negative if the owner spent the coins
+    public readonly amount: i128
+
+    constructor(decoder: Decoder) {
+        // consume array size (we can't parse data otherwise)
+        let _ = decoder.readArraySize();
+
+        let tx_seq = decoder.readUInt64();
+        let owner = decoder.readString();
+        let coin_type = decoder.readString();
+        let amount = i128FromBE(msgPackReadUint8Array(decoder));
+
+        this.txSeq = tx_seq
+        this.owner = owner
+        this.coinType = coin_type
+        this.amount = amount
+    }
+
+    public static loadAll(): BalanceChange[] {
+        let ptr_len = unpackValues(_mamoru_get_balance_changes());
+
+        return BalanceChange.fromHost(ptr_len[0], ptr_len[1]);
+    }
+
+    private static fromHost(ptr: u32, len: u32): BalanceChange[] {
+        const decoder = new Decoder(readMemory(ptr, len).buffer);
+
+        return decoder.readArray<BalanceChange>((decoder: Decoder) => new BalanceChange(decoder));
+    }
+}
@@ -15,3 +15,12 @@ export declare function _mamoru_get_events(): u64
 
 @external("mamoru_sui", "get_call_trace_arg_by_id")
 export declare function _mamoru_get_call_trace_arg_by_id(id: u64): u64
+
+@external("mamoru_sui", "get_object_changes")
+export declare function _mamoru_get_object_changes(): u64
+
+@external("mamoru_sui", "get_balance_changes")
+export declare function _mamoru_get_balance_changes(): u64
+
+@external("mamoru_sui", "get_checkpoints")
+export declare function _mamoru_get_checkpoints(): u64
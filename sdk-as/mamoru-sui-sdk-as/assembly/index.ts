@@ -1,43 +1,80 @@
-import { Transaction } from "./tx";
-import { Event } from "./event";
-import { CallTrace, CallTraceArg, CallTraceTypeArg } from "./call_trace";
+import { Value } from "@mamoru-ai/mamoru-sdk-as/assembly";
+import {
+    BalanceChange,
+    CallTraceArg,
+    CallTraceTypeArg,
+    Checkpoint,
+    Event,
+    ObjectChange,
+    Transaction,
+} from "./generated";
+import { CallTrace } from "./call_trace";
+import { i128FromBE } from "./balance_change";
 
 export {
     Transaction,
+    Checkpoint,
     Event,
     CallTrace,
     CallTraceArg,
     CallTraceTypeArg,
+    ObjectChange,
+    BalanceChange,
+    i128FromBE,
 }
 
 export class SuiCtx {
-    private _tx: Transaction | null
+    private _checkpoint: Checkpoint | null
+    private _checkpointLoaded: bool
+    private _txs: Transaction[] | null
     private _events: Event[] | null
     private _callTraces: CallTrace[] | null
     private _callTraceTypeArgs: CallTraceTypeArg[] | null
     private _callTraceArgs: CallTraceArg[] | null
+    private _objectChanges: ObjectChange[] | null
+    private _balanceChanges: BalanceChange[] | null
 
     private constructor() {
-        this._tx = null
+        this._checkpoint = null
+        this._checkpointLoaded = false
+        this._txs = null
         this._events = null
         this._callTraces = null
         this._callTraceTypeArgs = null
         this._callTraceArgs = null
+        this._objectChanges = null
+        this._balanceChanges = null
     }
 
     public static load(): SuiCtx {
         return new SuiCtx()
     }
 
-    /// The transaction of the current context
+    /// The checkpoint of the current context, null in the single transaction mode
+    public get checkpoint(): Checkpoint | null {
+        if (!this._checkpointLoaded) {
+            const checkpoints = Checkpoint.loadAll();
+
+            this._checkpoint = checkpoints.length > 0 ? checkpoints[0] : null
+            this._checkpointLoaded = true
+        }
+
+        return this._checkpoint
+    }
+
+    /// The transaction of the current context,
+    /// the first transaction of the checkpoint in the checkpoint mode
     public get tx(): Transaction {
-        if (this._tx == null) {
-            const txs = Transaction.loadAll();
+        return this.txs[0]
+    }
 
-            this._tx = txs[0]
+    /// All transactions in the current context
+    public get txs(): Transaction[] {
+        if (this._txs == null) {
+            this._txs = Transaction.loadAll()
         }
 
-        return this._tx!
+        return this._txs!
     }
 
     /// All events in the current context
@@ -52,7 +89,7 @@ export class SuiCtx {
     /// All call traces in the current context
     public get callTraces(): CallTrace[] {
         if (this._callTraces == null) {
-            this._callTraces = CallTrace.loadAll(this)
+            this._callTraces = CallTrace.loadWith(this)
         }
 
         return this._callTraces!
@@ -75,4 +112,52 @@ export class SuiCtx {
 
         return this._callTraceArgs!
     }
+
+    /// All object changes in the current context
+    public get objectChanges(): ObjectChange[] {
+        if (this._objectChanges == null) {
+            this._objectChanges = ObjectChange.loadAll()
+        }
+
+        return this._objectChanges!
+    }
+
+    /// All coin balance changes in the current context
+    public get balanceChanges(): BalanceChange[] {
+        if (this._balanceChanges == null) {
+            this._balanceChanges = BalanceChange.loadAll()
+        }
+
+        return this._balanceChanges!
+    }
+
+    /// All arguments of the given call trace
+    public argsOf(callTrace: CallTrace): Value[] {
+        const args = new Array<Value>();
+
+        for (let i = 0; i < this.callTraceArgs.length; i++) {
+            const arg = this.callTraceArgs[i];
+
+            if (arg.callTraceSeq == callTrace.seq) {
+                args.push(arg.arg);
+            }
+        }
+
+        return args
+    }
+
+    /// All type arguments of the given call trace
+    public typeArgsOf(callTrace: CallTrace): string[] {
+        const typeArgs = new Array<string>();
+
+        for (let i = 0; i < this.callTraceTypeArgs.length; i++) {
+            const arg = this.callTraceTypeArgs[i];
+
+            if (arg.callTraceSeq == callTrace.seq) {
+                typeArgs.push(arg.arg);
+            }
+        }
+
+        return typeArgs
+    }
 }
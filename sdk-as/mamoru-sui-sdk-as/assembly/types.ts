@@ -0,0 +1,3 @@
+// The types of the generated columns converted with `#[schema(as_type = ...)]`.
+export { i128 } from "@mamoru-ai/mamoru-sdk-as/assembly";
+export { i128FromBE } from "./balance_change";
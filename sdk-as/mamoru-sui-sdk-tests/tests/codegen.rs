@@ -0,0 +1,20 @@
+use expect_test::expect_file;
+
+use mamoru_core::{generate_as_sdk, generate_rust_sdk};
+use mamoru_sui_types::SuiCtx;
+
+/// Fails if `assembly/generated.ts` is out of date, run with `UPDATE_EXPECT=1` to update it.
+#[test]
+fn generated_sdk_is_up_to_date() {
+    let generated = generate_as_sdk::<SuiCtx>().expect("Failed to generate AssemblyScript SDK");
+
+    expect_file!["../../mamoru-sui-sdk-as/assembly/generated.ts"].assert_eq(&generated);
+}
+
+/// Fails if the `mamoru-daemon-sdk` module is out of date, run with `UPDATE_EXPECT=1` to update it.
+#[test]
+fn generated_rust_sdk_is_up_to_date() {
+    let generated = generate_rust_sdk::<SuiCtx>().expect("Failed to generate Rust SDK");
+
+    expect_file!["../../../sdk-rs/mamoru-daemon-sdk/src/chain/sui.rs"].assert_eq(&generated);
+}
@@ -1,9 +1,15 @@
 use test_log::test;
 
-use mamoru_core::{BlockchainData, BlockchainDataBuilder, Value, ValueData};
+use mamoru_core::{
+    serialize_data, BlockchainCtx, BlockchainData, BlockchainDataBuilder, CtxImportFn, Value,
+    ValueData,
+};
 use mamoru_core_test_utils::assembly_script::{AssemblyScriptModule, AS_SDK_PATH, AS_SUI_SDK_PATH};
 use mamoru_core_test_utils::test_daemon;
-use mamoru_sui_types::{CallTrace, CallTraceArg, CallTraceTypeArg, Event, SuiCtx, Transaction};
+use mamoru_sui_types::{
+    BalanceChange, CallTrace, CallTraceArg, CallTraceTypeArg, Checkpoint, Event, ObjectChange,
+    SuiCtx, Transaction,
+};
 
 fn sui_ctx() -> BlockchainData<SuiCtx> {
     let mut builder: BlockchainDataBuilder<SuiCtx> = BlockchainDataBuilder::new();
@@ -86,10 +92,88 @@ fn sui_ctx() -> BlockchainData<SuiCtx> {
         },
     ]);
 
+    builder.data_mut().object_changes.extend(vec![
+        ObjectChange {
+            tx_seq: 42,
+            object_id: "0x1".to_string(),
+            typ: "0x2::coin::Coin<0x2::sui::SUI>".to_string(),
+            owner_before: None,
+            owner_after: Some("some-owner".to_string()),
+            version: 7,
+            kind: "created".to_string(),
+        },
+        ObjectChange {
+            tx_seq: 42,
+            object_id: "0x2".to_string(),
+            typ: "0x2::coin::Coin<0x2::sui::SUI>".to_string(),
+            owner_before: Some("some-owner".to_string()),
+            owner_after: None,
+            version: 8,
+            kind: "deleted".to_string(),
+        },
+    ]);
+
+    builder.data_mut().balance_changes.extend(vec![
+        BalanceChange {
+            tx_seq: 42,
+            owner: "some-owner".to_string(),
+            coin_type: "0x2::sui::SUI".to_string(),
+            amount: 1000,
+        },
+        BalanceChange {
+            tx_seq: 42,
+            owner: "some-sender".to_string(),
+            coin_type: "0x2::sui::SUI".to_string(),
+            amount: -1000,
+        },
+    ]);
+
     builder.set_tx_data("test_tx", "test_digest");
     builder.build().unwrap()
 }
 
+fn sui_checkpoint_ctx() -> BlockchainData<SuiCtx> {
+    let mut builder: BlockchainDataBuilder<SuiCtx> = BlockchainDataBuilder::new();
+
+    let transactions = (0..2)
+        .map(|seq| Transaction {
+            seq,
+            digest: format!("digest-{}", seq),
+            time: 43,
+            gas_used: 44,
+            gas_computation_cost: 45,
+            gas_storage_cost: 46,
+            gas_budget: 47,
+            sender: "some-sender".to_string(),
+            kind: "some-kind".to_string(),
+        })
+        .collect();
+
+    builder.data_mut().set_checkpoint(
+        Checkpoint {
+            seq: 100,
+            digest: "checkpoint-digest".to_string(),
+            previous_digest: Some("previous-checkpoint-digest".to_string()),
+            epoch: 5,
+            time: 43,
+            network_total_transactions: 1000,
+        },
+        transactions,
+    );
+
+    builder.data_mut().events.extend((0..2).map(|tx_seq| Event {
+        tx_seq,
+        package_id: "some-package-id".to_string(),
+        transaction_module: "some-module".to_string(),
+        sender: "some-sender".to_string(),
+        typ: "some-typ".to_string(),
+        contents: vec![],
+    }));
+
+    builder.set_block_data("100", "checkpoint-digest");
+    builder.build().unwrap()
+}
+
 #[test(tokio::test)]
 async fn smoke() {
     let ctx = sui_ctx();
@@ -187,3 +271,210 @@ async fn smoke() {
         .await
         .expect("Assertion failed");
 }
+
+#[test(tokio::test)]
+async fn column_names_and_deprecated_aliases() {
+    let ctx = sui_ctx();
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {assert} from "@mamoru-ai/mamoru-sdk-as/assembly";
+        import {SuiCtx} from "@mamoru-ai/mamoru-sui-sdk-as/assembly";
+
+        export function main(): void {
+            const ctx = SuiCtx.load();
+
+            const event = ctx.events[0];
+
+            assert(event.type == event.typ, "event.type == event.typ");
+
+            const callTrace = ctx.callTraces[0];
+
+            assert(callTrace.function == callTrace.func, "callTrace.function == callTrace.func");
+            assert(ctx.argsOf(callTrace).length == callTrace.args.length, "ctx.argsOf(callTrace) == callTrace.args");
+            assert(ctx.typeArgsOf(callTrace).length == callTrace.typeArgs.length, "ctx.typeArgsOf(callTrace) == callTrace.typeArgs");
+
+            const callTraceArg = ctx.callTraceArgs[0];
+
+            assert(callTraceArg.arg.asU64() == callTraceArg.value.asU64(), "callTraceArg.arg == callTraceArg.value");
+        }
+    """#,
+        &[AS_SDK_PATH, AS_SUI_SDK_PATH],
+    );
+
+    test_daemon(&module)
+        .verify(&ctx)
+        .await
+        .expect("Assertion failed");
+}
+
+#[test(tokio::test)]
+async fn object_and_balance_changes() {
+    let ctx = sui_ctx();
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {assert, i128} from "@mamoru-ai/mamoru-sdk-as/assembly";
+        import {SuiCtx} from "@mamoru-ai/mamoru-sui-sdk-as/assembly";
+
+        export function main(): void {
+            const ctx = SuiCtx.load();
+
+            assert(ctx.objectChanges.length == 2, "ctx.objectChanges.length == 2");
+
+            const created = ctx.objectChanges[0];
+
+            assert(created.txSeq == 42, "created.tx_seq == 42");
+            assert(created.objectId == "0x1", "created.object_id == \"0x1\"");
+            assert(created.typ == "0x2::coin::Coin<0x2::sui::SUI>", "created.typ == \"0x2::coin::Coin<0x2::sui::SUI>\"");
+            assert(created.ownerBefore == null, "created.owner_before == null");
+            assert(created.ownerAfter == "some-owner", "created.owner_after == \"some-owner\"");
+            assert(created.version == 7, "created.version == 7");
+            assert(created.kind == "created", "created.kind == \"created\"");
+
+            const deleted = ctx.objectChanges[1];
+
+            assert(deleted.ownerBefore == "some-owner", "deleted.owner_before == \"some-owner\"");
+            assert(deleted.ownerAfter == null, "deleted.owner_after == null");
+            assert(deleted.kind == "deleted", "deleted.kind == \"deleted\"");
+
+            assert(ctx.balanceChanges.length == 2, "ctx.balanceChanges.length == 2");
+
+            const received = ctx.balanceChanges[0];
+
+            assert(received.txSeq == 42, "received.tx_seq == 42");
+            assert(received.owner == "some-owner", "received.owner == \"some-owner\"");
+            assert(received.coinType == "0x2::sui::SUI", "received.coin_type == \"0x2::sui::SUI\"");
+            assert(received.amount == i128.fromI64(1000), "received.amount == 1000");
+
+            const spent = ctx.balanceChanges[1];
+
+            assert(spent.owner == "some-sender", "spent.owner == \"some-sender\"");
+            assert(spent.amount == i128.fromI64(-1000), "spent.amount == -1000");
+            assert(spent.amount.isNeg(), "spent.amount < 0");
+        }
+    """#,
+        &[AS_SDK_PATH, AS_SUI_SDK_PATH],
+    );
+
+    test_daemon(&module)
+        .verify(&ctx)
+        .await
+        .expect("Assertion failed");
+}
+
+#[test(tokio::test)]
+async fn checkpoint() {
+    let ctx = sui_checkpoint_ctx();
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {assert, query} from "@mamoru-ai/mamoru-sdk-as/assembly";
+        import {SuiCtx} from "@mamoru-ai/mamoru-sui-sdk-as/assembly";
+
+        export function main(): void {
+            const ctx = SuiCtx.load();
+
+            const checkpoint = ctx.checkpoint!;
+
+            assert(checkpoint.seq == 100, "checkpoint.seq == 100");
+            assert(checkpoint.digest == "checkpoint-digest", "checkpoint.digest == \"checkpoint-digest\"");
+            assert(checkpoint.previousDigest == "previous-checkpoint-digest", "checkpoint.previous_digest == \"previous-checkpoint-digest\"");
+            assert(checkpoint.epoch == 5, "checkpoint.epoch == 5");
+            assert(checkpoint.time == 43, "checkpoint.time == 43");
+            assert(checkpoint.networkTotalTransactions == 1000, "checkpoint.network_total_transactions == 1000");
+
+            assert(ctx.txs.length == 2, "ctx.txs.length == 2");
+            assert(ctx.txs[1].digest == "digest-1", "ctx.txs[1].digest == \"digest-1\"");
+            assert(ctx.tx.digest == "digest-0", "ctx.tx.digest == \"digest-0\"");
+
+            const rows = query("SELECT t.digest FROM transactions t JOIN events e ON e.tx_seq = t.seq");
+
+            assert(rows.length == 2, "rows.length == 2");
+        }
+    """#,
+        &[AS_SDK_PATH, AS_SUI_SDK_PATH],
+    );
+
+    test_daemon(&module)
+        .verify(&ctx)
+        .await
+        .expect("Assertion failed");
+}
+
+#[test(tokio::test)]
+async fn single_tx_has_no_checkpoint() {
+    let ctx = sui_ctx();
+
+    let module = AssemblyScriptModule::with_deps(
+        r#"""
+        import {assert} from "@mamoru-ai/mamoru-sdk-as/assembly";
+        import {SuiCtx} from "@mamoru-ai/mamoru-sui-sdk-as/assembly";
+
+        export function main(): void {
+            const ctx = SuiCtx.load();
+
+            assert(ctx.checkpoint == null, "ctx.checkpoint == null");
+            assert(ctx.txs.length == 1, "ctx.txs.length == 1");
+        }
+    """#,
+        &[AS_SDK_PATH, AS_SUI_SDK_PATH],
+    );
+
+    test_daemon(&module)
+        .verify(&ctx)
+        .await
+        .expect("Assertion failed");
+}
+
+#[test]
+fn single_tx_serializes_no_checkpoints() {
+    let ctx = sui_ctx();
+
+    let checkpoints = match SuiCtx::imports().get("get_checkpoints") {
+        Some(CtxImportFn::NoArgs(f)) => f(ctx.data()),
+        _ => panic!("get_checkpoints is not a `NoArgs` import"),
+    };
+
+    assert_eq!(checkpoints, serialize_data(&Vec::<Checkpoint>::new()));
+}
+
+#[test]
+fn set_tx_and_set_checkpoint_replace_each_other() {
+    let tx = |seq| Transaction {
+        seq,
+        digest: format!("digest-{}", seq),
+        time: 43,
+        gas_used: 44,
+        gas_computation_cost: 45,
+        gas_storage_cost: 46,
+        gas_budget: 47,
+        sender: "some-sender".to_string(),
+        kind: "some-kind".to_string(),
+    };
+    let checkpoint = Checkpoint {
+        seq: 100,
+        digest: "checkpoint-digest".to_string(),
+        previous_digest: None,
+        epoch: 5,
+        time: 43,
+        network_total_transactions: 1000,
+    };
+
+    let mut builder: BlockchainDataBuilder<SuiCtx> = BlockchainDataBuilder::new();
+
+    builder.data_mut().set_tx(tx(1));
+    builder
+        .data_mut()
+        .set_checkpoint(checkpoint, vec![tx(2), tx(3)]);
+
+    let data = builder.data_mut();
+    assert!(data.tx.is_none());
+    assert!(data.checkpoint.is_some());
+    assert_eq!(data.transactions.len(), 2);
+
+    data.set_tx(tx(4));
+    assert_eq!(data.tx.as_ref().map(|tx| tx.seq), Some(4));
+    assert!(data.checkpoint.is_none());
+    assert!(data.transactions.is_empty());
+}
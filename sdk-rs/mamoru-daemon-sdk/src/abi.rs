@@ -0,0 +1,59 @@
+//! The plain ABI shared with the Mamoru runtime:
+//! strings and bytes are passed to the host as `ptr, len` pairs,
+//! the host returns data allocated via [`mamoru_alloc`] as packed `ptr << 32 | len` values.
+
+use serde::de::DeserializeOwned;
+
+/// Declares the ABI explicitly, so the runtime doesn't have to guess it from the exports.
+#[used]
+#[link_section = "mamoru_abi"]
+static MAMORU_ABI: [u8; 5] = *b"plain";
+
+#[link(wasm_import_module = "mamoru")]
+extern "C" {
+    pub(crate) fn query(ptr: *const u8, len: u32) -> u64;
+    pub(crate) fn query_v2(ptr: *const u8, len: u32, format: u32) -> u64;
+    pub(crate) fn report(ptr: *const u8, len: u32);
+    pub(crate) fn log(level: u32, ptr: *const u8, len: u32);
+    pub(crate) fn parameter(ptr: *const u8, len: u32) -> u64;
+    pub(crate) fn keccak256(ptr: *const u8, len: u32) -> u64;
+    pub(crate) fn sha256(ptr: *const u8, len: u32) -> u64;
+    pub(crate) fn blake2b(ptr: *const u8, len: u32) -> u64;
+    pub(crate) fn ecrecover(
+        hash_ptr: *const u8,
+        hash_len: u32,
+        signature_ptr: *const u8,
+        signature_len: u32,
+    ) -> u64;
+}
+
+/// The allocator used by the host to pass data to the module.
+/// The memory is owned by the module, see [`take_packed`].
+#[no_mangle]
+pub extern "C" fn mamoru_alloc(size: u32) -> *mut u8 {
+    let mut buf = Vec::<u8>::with_capacity(size as usize);
+    let ptr = buf.as_mut_ptr();
+    std::mem::forget(buf);
+
+    ptr
+}
+
+/// Takes ownership of the data the host allocated via [`mamoru_alloc`].
+pub(crate) fn take_packed(packed: u64) -> Vec<u8> {
+    let ptr = (packed >> 32) as usize as *mut u8;
+    let len = (packed & u32::MAX as u64) as usize;
+
+    if len == 0 {
+        return Vec::new();
+    }
+
+    // SAFETY: the host allocated exactly `len` bytes via `mamoru_alloc`.
+    unsafe { Vec::from_raw_parts(ptr, len, len) }
+}
+
+/// Decodes MessagePack data returned by the host, e.g. the rows returned by the chain getters.
+pub(crate) fn decode_packed<T: DeserializeOwned>(packed: u64) -> T {
+    let data = take_packed(packed);
+
+    rmp_serde::from_slice(&data).expect("BUG: failed to decode the host data.")
+}
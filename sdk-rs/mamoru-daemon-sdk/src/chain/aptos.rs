@@ -0,0 +1,163 @@
+// This file is generated from the Rust types by `mamoru_core::generate_rust_sdk`, do not edit it.
+// Run the SDK tests with `UPDATE_EXPECT=1` to update it.
+use serde::Deserialize;
+
+#[link(wasm_import_module = "mamoru_aptos")]
+extern "C" {
+    fn get_blocks() -> u64;
+    fn get_transactions() -> u64;
+    fn get_events() -> u64;
+    fn get_call_traces() -> u64;
+    fn get_call_trace_type_args() -> u64;
+    fn get_call_trace_args() -> u64;
+    fn get_call_trace_arg_by_id(id: u64) -> u64;
+    fn get_write_set_changes() -> u64;
+    fn get_write_set_change_data_by_id(id: u64) -> u64;
+    fn get_coin_balance_changes() -> u64;
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Block {
+    pub hash: String,
+    pub epoch: u64,
+    pub timestamp_usecs: u64,
+}
+
+impl Block {
+    /// Loads all rows of the `blocks` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_blocks() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Transaction {
+    pub seq: u64,
+    pub block_hash: String,
+    pub hash: String,
+    pub event_root_hash: String,
+    pub state_change_hash: String,
+    pub gas_used: u64,
+    pub max_gas_amount: u64,
+    pub gas_unit_price: u64,
+    pub expiration_timestamp_secs: u64,
+    pub status: u64,
+    pub sender: String,
+    pub sequence_number: u64,
+}
+
+impl Transaction {
+    /// Loads all rows of the `transactions` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_transactions() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Event {
+    pub tx_seq: u64,
+    pub key: String,
+    pub sequence_number: u64,
+    pub r#type: String,
+    pub data: Vec<u8>,
+}
+
+impl Event {
+    /// Loads all rows of the `events` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_events() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CallTrace {
+    pub seq: u64,
+    pub tx_seq: u64,
+    pub depth: u32,
+    pub call_type: u8,
+    pub gas_used: u64,
+    pub transaction_module: Option<String>,
+    pub function: String,
+}
+
+impl CallTrace {
+    /// Loads all rows of the `call_traces` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_call_traces() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CallTraceTypeArg {
+    pub seq: u64,
+    pub call_trace_seq: u64,
+    pub arg: String,
+}
+
+impl CallTraceTypeArg {
+    /// Loads all rows of the `call_trace_type_args` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_call_trace_type_args() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CallTraceArg {
+    pub seq: u64,
+    pub call_trace_seq: u64,
+}
+
+impl CallTraceArg {
+    /// Loads all rows of the `call_trace_args` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_call_trace_args() })
+    }
+
+    pub fn arg(&self) -> crate::ChainValue {
+        crate::abi::decode_packed(unsafe { get_call_trace_arg_by_id(self.seq) })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WriteSetChange {
+    pub seq: u64,
+    pub tx_seq: u64,
+    pub address: String,
+    pub state_key_hash: String,
+    /// one of `write_resource`, `delete_resource`, `write_module`, `delete_module`, `write_table_item`, `delete_table_item`
+    pub change_type: String,
+    /// null for module and table item changes
+    pub resource_type: Option<String>,
+}
+
+impl WriteSetChange {
+    /// Loads all rows of the `write_set_changes` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_write_set_changes() })
+    }
+
+    /// null for deletions
+    pub fn data(&self) -> Option<crate::ChainValue> {
+        crate::abi::decode_packed(unsafe { get_write_set_change_data_by_id(self.seq) })
+    }
+}
+
+/// A `0x1::coin::CoinStore<T>` balance written by a transaction.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CoinBalanceChange {
+    /// `seq` of the write set change it is decoded from
+    pub write_set_change_seq: u64,
+    pub tx_seq: u64,
+    pub address: String,
+    /// `T` of the `CoinStore<T>`, e.g. `0x1::aptos_coin::AptosCoin`
+    pub coin_type: String,
+    /// balance after the write
+    pub balance: u64,
+}
+
+impl CoinBalanceChange {
+    /// Loads all rows of the `coin_balance_changes` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_coin_balance_changes() })
+    }
+}
@@ -0,0 +1,106 @@
+// This file is generated from the Rust types by `mamoru_core::generate_rust_sdk`, do not edit it.
+// Run the SDK tests with `UPDATE_EXPECT=1` to update it.
+use serde::Deserialize;
+
+#[link(wasm_import_module = "mamoru_cosmos")]
+extern "C" {
+    fn get_blocks() -> u64;
+    fn get_transactions() -> u64;
+    fn get_messages() -> u64;
+    fn get_events() -> u64;
+    fn get_event_attributes() -> u64;
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Block {
+    pub height: u64,
+    pub hash: String,
+    pub chain_id: String,
+    /// unix timestamp in seconds
+    pub timestamp: u64,
+    pub proposer_address: String,
+    pub app_hash: String,
+}
+
+impl Block {
+    /// Loads all rows of the `blocks` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_blocks() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Transaction {
+    /// index of the transaction in the block
+    pub index: u32,
+    pub hash: String,
+    pub block_height: u64,
+    pub gas_wanted: u64,
+    pub gas_used: u64,
+    /// ABCI result code, 0 means success
+    pub code: u32,
+    /// namespace of the `code`, empty on success
+    pub codespace: String,
+    /// fee coins, e.g. "5000uatom"
+    pub fee: String,
+    pub memo: String,
+}
+
+impl Transaction {
+    /// Loads all rows of the `transactions` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_transactions() })
+    }
+}
+
+/// A message included into a transaction body.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Message {
+    /// hash of the transaction
+    pub tx_hash: String,
+    /// index of the message in the transaction
+    pub index: u32,
+    /// protobuf type URL, e.g. "/cosmos.bank.v1beta1.MsgSend"
+    pub type_url: String,
+    /// the message decoded to JSON
+    pub json: String,
+}
+
+impl Message {
+    /// Loads all rows of the `messages` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_messages() })
+    }
+}
+
+/// An ABCI event emitted by a transaction or by the block itself.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Event {
+    /// index of the event in the block
+    pub seq: u32,
+    /// hash of the transaction, null for `BeginBlock`/`EndBlock` events
+    pub tx_hash: Option<String>,
+    /// e.g. "transfer"
+    pub r#type: String,
+}
+
+impl Event {
+    /// Loads all rows of the `events` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_events() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EventAttribute {
+    pub event_seq: u32,
+    pub key: String,
+    pub value: String,
+}
+
+impl EventAttribute {
+    /// Loads all rows of the `event_attributes` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_event_attributes() })
+    }
+}
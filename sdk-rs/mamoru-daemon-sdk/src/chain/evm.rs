@@ -0,0 +1,233 @@
+// This file is generated from the Rust types by `mamoru_core::generate_rust_sdk`, do not edit it.
+// Run the SDK tests with `UPDATE_EXPECT=1` to update it.
+use serde::Deserialize;
+
+#[link(wasm_import_module = "mamoru_evm")]
+extern "C" {
+    fn get_blocks() -> u64;
+    fn get_transactions() -> u64;
+    fn get_call_traces() -> u64;
+    fn get_events() -> u64;
+    fn get_token_transfers() -> u64;
+    fn get_approvals() -> u64;
+    fn get_storage_changes() -> u64;
+    fn get_balance_changes() -> u64;
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Block {
+    pub block_index: u64,
+    pub hash: String,
+    pub parent_hash: String,
+    pub state_root: String,
+    /// A nonce is a 64-bit hash which proves (combined with the mix-hash) that a sufficient amount of computation has been carried out on a block.
+    pub nonce: u64,
+    pub status: String,
+    pub timestamp: u64,
+    pub block_reward: Vec<u8>,
+    pub fee_recipient: String,
+    pub total_difficulty: u64,
+    pub size: f64,
+    pub gas_used: u64,
+    pub gas_limit: u64,
+}
+
+impl Block {
+    /// Loads all rows of the `blocks` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_blocks() })
+    }
+}
+
+/// Transaction is an Ethereum transaction.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Transaction {
+    pub tx_index: u32,
+    /// tx_hash the transaction hash.
+    pub tx_hash: String,
+    /// Type returns the transaction type.
+    pub r#type: u8,
+    /// Nonce returns the sender account nonce of the transaction.
+    pub nonce: u64,
+    pub status: u64,
+    pub block_index: u64,
+    pub from: String,
+    /// To returns the recipient address of the transaction. For contract-creation transactions, To returns nil.
+    pub to: Option<String>,
+    /// Value returns the ether amount of the transaction.
+    pub value: u64,
+    /// GasFeeCap returns the fee cap per gas of the transaction.
+    pub fee: u64,
+    /// GasPrice returns the gas price of the transaction.
+    pub gas_price: u64,
+    /// Gas returns the gas limit of the transaction.
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    /// Data returns the input data of the transaction.
+    pub input: Vec<u8>,
+    /// Size returns the true RLP encoded storage size of the transaction, either by encoding and returning it, or returning a previously cached value.
+    pub size: f64,
+}
+
+impl Transaction {
+    /// Loads all rows of the `transactions` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_transactions() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CallTrace {
+    pub seq: u32,
+    pub tx_index: u32,
+    pub block_index: u64,
+    pub depth: u32,
+    pub r#type: String,
+    pub from: String,
+    pub to: String,
+    pub value: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub input: Vec<u8>,
+    /// data returned by the call, or the revert data if the call failed
+    pub output: Vec<u8>,
+    /// error reported by the node, e.g. "execution reverted", null if the call succeeded
+    pub error: Option<String>,
+    /// decoded `Error(string)` or `Panic(uint256)` from `output`, filled on build if the call failed
+    pub revert_reason: Option<String>,
+}
+
+impl CallTrace {
+    /// Loads all rows of the `call_traces` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_call_traces() })
+    }
+}
+
+/// Event represents a contract log event. These events are generated by the LOG opcode and stored/indexed by the node.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Event {
+    /// index of the log in the block
+    pub index: u32,
+    /// index of the transaction in the block
+    pub tx_index: u32,
+    /// hash of the transaction
+    pub tx_hash: String,
+    /// block in which the transaction was included
+    pub block_number: u64,
+    /// hash of the block in which the transaction was included
+    pub block_hash: String,
+    /// address of the contract that generated the event
+    pub address: String,
+    pub topic0: Vec<u8>,
+    pub topic1: Vec<u8>,
+    pub topic2: Vec<u8>,
+    pub topic3: Vec<u8>,
+    pub topic4: Vec<u8>,
+    /// supplied by the contract, usually ABI-encoded
+    pub data: Vec<u8>,
+}
+
+impl Event {
+    /// Loads all rows of the `events` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_events() })
+    }
+}
+
+/// A token movement decoded from a standard ERC-20, ERC-721 or ERC-1155 event.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TokenTransfer {
+    /// index of the source event in the block
+    pub log_index: u32,
+    /// index of the transaction in the block
+    pub tx_index: u32,
+    /// hash of the transaction
+    pub tx_hash: String,
+    /// address of the token contract
+    pub token: String,
+    /// one of "erc20", "erc721", "erc1155"
+    pub standard: String,
+    pub from: String,
+    pub to: String,
+    /// big-endian u256, null for ERC-20
+    pub token_id: Option<Vec<u8>>,
+    /// big-endian u256, always 1 for ERC-721
+    pub amount: Vec<u8>,
+}
+
+impl TokenTransfer {
+    /// Loads all rows of the `token_transfers` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_token_transfers() })
+    }
+}
+
+/// An allowance decoded from a standard ERC-20 or ERC-721 `Approval` event.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Approval {
+    /// index of the source event in the block
+    pub log_index: u32,
+    /// index of the transaction in the block
+    pub tx_index: u32,
+    /// hash of the transaction
+    pub tx_hash: String,
+    /// address of the token contract
+    pub token: String,
+    /// one of "erc20", "erc721"
+    pub standard: String,
+    pub owner: String,
+    pub spender: String,
+    /// big-endian u256, null for ERC-20
+    pub token_id: Option<Vec<u8>>,
+    /// big-endian u256, always 1 for ERC-721
+    pub amount: Vec<u8>,
+}
+
+impl Approval {
+    /// Loads all rows of the `approvals` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_approvals() })
+    }
+}
+
+/// A contract storage slot write made by a transaction.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StorageChange {
+    /// index of the transaction in the block
+    pub tx_index: u32,
+    /// address of the contract which storage was changed
+    pub address: String,
+    /// 32-byte storage key
+    pub slot: Vec<u8>,
+    /// 32-byte value before the transaction
+    pub previous_value: Vec<u8>,
+    /// 32-byte value after the transaction
+    pub new_value: Vec<u8>,
+}
+
+impl StorageChange {
+    /// Loads all rows of the `storage_changes` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_storage_changes() })
+    }
+}
+
+/// A native balance change of an account made by a transaction.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BalanceChange {
+    /// index of the transaction in the block
+    pub tx_index: u32,
+    pub address: String,
+    /// big-endian u256, in wei
+    pub previous_balance: Vec<u8>,
+    /// big-endian u256, in wei
+    pub new_balance: Vec<u8>,
+}
+
+impl BalanceChange {
+    /// Loads all rows of the `balance_changes` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_balance_changes() })
+    }
+}
@@ -0,0 +1,6 @@
+//! The chain tables passed to WASM, see `load_all` of each struct.
+
+pub mod aptos;
+pub mod cosmos;
+pub mod evm;
+pub mod sui;
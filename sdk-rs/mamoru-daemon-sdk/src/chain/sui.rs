@@ -0,0 +1,161 @@
+// This file is generated from the Rust types by `mamoru_core::generate_rust_sdk`, do not edit it.
+// Run the SDK tests with `UPDATE_EXPECT=1` to update it.
+use serde::Deserialize;
+
+#[link(wasm_import_module = "mamoru_sui")]
+extern "C" {
+    fn get_checkpoints() -> u64;
+    fn get_transactions() -> u64;
+    fn get_call_traces() -> u64;
+    fn get_call_trace_type_args() -> u64;
+    fn get_call_trace_args() -> u64;
+    fn get_call_trace_arg_by_id(id: u64) -> u64;
+    fn get_events() -> u64;
+    fn get_object_changes() -> u64;
+    fn get_balance_changes() -> u64;
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Checkpoint {
+    pub seq: u64,
+    pub digest: String,
+    /// null for the genesis checkpoint
+    pub previous_digest: Option<String>,
+    pub epoch: u64,
+    pub time: i64,
+    /// total number of transactions committed on the network, including this checkpoint
+    pub network_total_transactions: u64,
+}
+
+impl Checkpoint {
+    /// Loads all rows of the `checkpoints` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_checkpoints() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Transaction {
+    pub seq: u64,
+    pub digest: String,
+    pub time: i64,
+    pub gas_used: u64,
+    pub gas_computation_cost: u64,
+    pub gas_storage_cost: u64,
+    pub gas_budget: u64,
+    pub sender: String,
+    pub kind: String,
+}
+
+impl Transaction {
+    /// Loads all rows of the `transactions` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_transactions() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CallTrace {
+    pub seq: u64,
+    pub tx_seq: u64,
+    pub depth: u32,
+    pub call_type: u8,
+    pub gas_used: u64,
+    pub transaction_module: Option<String>,
+    pub function: String,
+}
+
+impl CallTrace {
+    /// Loads all rows of the `call_traces` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_call_traces() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CallTraceTypeArg {
+    pub seq: u64,
+    pub call_trace_seq: u64,
+    pub arg: String,
+}
+
+impl CallTraceTypeArg {
+    /// Loads all rows of the `call_trace_type_args` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_call_trace_type_args() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CallTraceArg {
+    pub seq: u64,
+    pub call_trace_seq: u64,
+}
+
+impl CallTraceArg {
+    /// Loads all rows of the `call_trace_args` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_call_trace_args() })
+    }
+
+    pub fn arg(&self) -> crate::ChainValue {
+        crate::abi::decode_packed(unsafe { get_call_trace_arg_by_id(self.seq) })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Event {
+    pub tx_seq: u64,
+    pub package_id: String,
+    pub transaction_module: String,
+    pub sender: String,
+    pub r#type: String,
+    pub contents: Vec<u8>,
+}
+
+impl Event {
+    /// Loads all rows of the `events` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_events() })
+    }
+}
+
+/// An object created, mutated or removed by the transaction.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ObjectChange {
+    pub tx_seq: u64,
+    pub object_id: String,
+    pub r#type: String,
+    /// null if the object is created by the transaction
+    pub owner_before: Option<String>,
+    /// null if the object is deleted or wrapped by the transaction
+    pub owner_after: Option<String>,
+    /// object version after the transaction
+    pub version: u64,
+    /// one of `created`, `mutated`, `transferred`, `deleted`, `wrapped`, `unwrapped`, `published`
+    pub kind: String,
+}
+
+impl ObjectChange {
+    /// Loads all rows of the `object_changes` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_object_changes() })
+    }
+}
+
+/// A coin balance change of an owner made by the transaction.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BalanceChange {
+    pub tx_seq: u64,
+    pub owner: String,
+    pub coin_type: String,
+    /// negative if the owner spent the coins
+    pub amount: i128,
+}
+
+impl BalanceChange {
+    /// Loads all rows of the `balance_changes` table.
+    pub fn load_all() -> Vec<Self> {
+        crate::abi::decode_packed(unsafe { get_balance_changes() })
+    }
+}
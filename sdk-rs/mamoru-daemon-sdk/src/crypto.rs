@@ -0,0 +1,55 @@
+//! Hash functions and signature recovery implemented by the host.
+
+use crate::abi;
+
+/// Keccak-256 hash, e.g. of EVM event signatures.
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    to_hash(abi::take_packed(unsafe {
+        abi::keccak256(data.as_ptr(), data.len() as u32)
+    }))
+}
+
+/// SHA-256 hash.
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    to_hash(abi::take_packed(unsafe {
+        abi::sha256(data.as_ptr(), data.len() as u32)
+    }))
+}
+
+/// BLAKE2b-256 hash, e.g. of Sui public keys.
+pub fn blake2b(data: &[u8]) -> [u8; 32] {
+    to_hash(abi::take_packed(unsafe {
+        abi::blake2b(data.as_ptr(), data.len() as u32)
+    }))
+}
+
+/// Recovers the EVM address that signed the 32 bytes `hash`
+/// with the 65 bytes `r || s || v` signature.
+///
+/// Returns `None` if the signature is invalid.
+pub fn ecrecover(hash: &[u8], signature: &[u8]) -> Option<[u8; 20]> {
+    let packed = unsafe {
+        abi::ecrecover(
+            hash.as_ptr(),
+            hash.len() as u32,
+            signature.as_ptr(),
+            signature.len() as u32,
+        )
+    };
+
+    if packed == 0 {
+        return None;
+    }
+
+    let address = abi::take_packed(packed);
+
+    Some(
+        address
+            .try_into()
+            .expect("BUG: the address is not 20 bytes."),
+    )
+}
+
+fn to_hash(data: Vec<u8>) -> [u8; 32] {
+    data.try_into().expect("BUG: the hash is not 32 bytes.")
+}
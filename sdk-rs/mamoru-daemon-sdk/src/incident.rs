@@ -0,0 +1,129 @@
+use base64::prelude::{Engine as _, BASE64_STANDARD};
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentSeverity {
+    Info,
+    Warning,
+    Error,
+    Alert,
+}
+
+/// The incident reported by a Daemon, see [`crate::report`].
+#[derive(Debug, Clone, Serialize)]
+pub struct Incident {
+    severity: IncidentSeverity,
+    message: String,
+    tx_hash: String,
+    address: String,
+    #[serde(serialize_with = "serialize_base64")]
+    data: Vec<u8>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    title: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    addresses: Vec<IncidentAddress>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confidence: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<Value>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    fingerprint: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct IncidentAddress {
+    address: String,
+    role: String,
+}
+
+impl Incident {
+    pub fn new(severity: IncidentSeverity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            tx_hash: String::new(),
+            address: String::new(),
+            data: Vec::new(),
+            title: String::new(),
+            addresses: Vec::new(),
+            tags: Vec::new(),
+            confidence: None,
+            details: None,
+            fingerprint: String::new(),
+        }
+    }
+
+    pub fn tx_hash(mut self, tx_hash: impl Into<String>) -> Self {
+        self.tx_hash = tx_hash.into();
+
+        self
+    }
+
+    pub fn address(mut self, address: impl Into<String>) -> Self {
+        self.address = address.into();
+
+        self
+    }
+
+    /// Arbitrary data attached to the incident.
+    pub fn data(mut self, data: impl Into<Vec<u8>>) -> Self {
+        self.data = data.into();
+
+        self
+    }
+
+    /// A short summary, the message keeps the description.
+    ///
+    /// The triage fields require the runtime supporting `mamoru_rust` 0.2.0.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+
+        self
+    }
+
+    /// Adds an address related to the incident, e.g. `attacker` or `victim`.
+    pub fn related_address(mut self, address: impl Into<String>, role: impl Into<String>) -> Self {
+        self.addresses.push(IncidentAddress {
+            address: address.into(),
+            role: role.into(),
+        });
+
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+
+        self
+    }
+
+    /// How sure the daemon is, from `0.0` to `1.0`.
+    pub fn confidence(mut self, confidence: f64) -> Self {
+        self.confidence = Some(confidence);
+
+        self
+    }
+
+    /// Structured data readable by triage tools.
+    pub fn details(mut self, details: Value) -> Self {
+        self.details = Some(details);
+
+        self
+    }
+
+    /// Identifies the same incident reported multiple times,
+    /// the runtime hashes the incident fields if it's not set.
+    pub fn fingerprint(mut self, fingerprint: impl Into<String>) -> Self {
+        self.fingerprint = fingerprint.into();
+
+        self
+    }
+}
+
+fn serialize_base64<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&BASE64_STANDARD.encode(data))
+}
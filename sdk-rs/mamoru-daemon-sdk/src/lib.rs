@@ -0,0 +1,124 @@
+//! Rust SDK for Mamoru Daemons.
+//!
+//! A daemon is a `cdylib` built for `wasm32-unknown-unknown` exporting `main`:
+//!
+//! ```ignore
+//! use mamoru_daemon_sdk::{chain::evm::Transaction, report, Incident, IncidentSeverity};
+//!
+//! #[no_mangle]
+//! pub extern "C" fn main() {
+//!     for tx in Transaction::load_all() {
+//!         if tx.gas_used > 1_000_000 {
+//!             report(Incident::new(IncidentSeverity::Alert, "High gas usage").tx_hash(tx.tx_hash));
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The SDK exports `mamoru_alloc` and the `mamoru_abi` custom section,
+//! so the runtime uses the plain ABI instead of the AssemblyScript one.
+
+use serde::de::DeserializeOwned;
+
+pub use abi::mamoru_alloc;
+pub use incident::{Incident, IncidentSeverity};
+pub use query::{QueryColumn, QueryRows, QueryValue};
+pub use serde_json::{Map, Value};
+pub use value::{ChainStruct, ChainValue};
+
+use query::QueryFormat;
+
+mod abi;
+pub mod chain;
+pub mod crypto;
+mod incident;
+mod query;
+mod value;
+
+/// A row returned by [`query`], the keys are the selected columns.
+pub type Row = Map<String, Value>;
+
+/// Queries the daemon context.
+///
+/// ```ignore
+/// let rows = query("SELECT t.gas_used FROM transactions t WHERE t.tx_hash = 'DUMMY_HASH'");
+///
+/// for row in rows {
+///     let gas_used = row["gas_used"].as_u64();
+/// }
+/// ```
+pub fn query(sql: &str) -> Vec<Row> {
+    query_as(sql)
+}
+
+/// Queries the daemon context, the rows are deserialized into `T`.
+///
+/// ```ignore
+/// #[derive(Deserialize)]
+/// struct GasUsed {
+///     gas_used: u64,
+/// }
+///
+/// let rows: Vec<GasUsed> = query_as("SELECT t.gas_used FROM transactions t");
+/// ```
+pub fn query_as<T: DeserializeOwned>(sql: &str) -> Vec<T> {
+    let result = abi::take_packed(unsafe { abi::query(sql.as_ptr(), sql.len() as u32) });
+
+    serde_json::from_slice(&result).expect("Failed to deserialize the query result.")
+}
+
+/// Queries the daemon context, keeping the column types.
+/// Binary and 64-bit integer columns are returned as is, unlike [`query`].
+///
+/// ```ignore
+/// let result = query_rows("SELECT t.gas_used, t.input FROM transactions t");
+///
+/// for idx in 0..result.rows.len() {
+///     let gas_used = result.get(idx, "gas_used").and_then(QueryValue::as_u64);
+///     let input = result.get(idx, "input").and_then(QueryValue::as_bytes);
+/// }
+/// ```
+pub fn query_rows(sql: &str) -> QueryRows {
+    let rows: QueryRows = abi::decode_packed(query_v2(sql, QueryFormat::MessagePack));
+
+    rows.normalize()
+}
+
+/// Queries the daemon context, the result is an Arrow IPC stream.
+pub fn query_arrow(sql: &str) -> Vec<u8> {
+    abi::take_packed(query_v2(sql, QueryFormat::ArrowIpc))
+}
+
+fn query_v2(sql: &str, format: QueryFormat) -> u64 {
+    unsafe { abi::query_v2(sql.as_ptr(), sql.len() as u32, format as u32) }
+}
+
+/// Reports an incident to Validation Chain.
+pub fn report(incident: Incident) {
+    let incident = serde_json::to_vec(&incident).expect("BUG: Incident serialization failed.");
+
+    unsafe { abi::report(incident.as_ptr(), incident.len() as u32) }
+}
+
+/// The level of a message passed to [`log`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum LogLevel {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+    Debug = 3,
+    Trace = 4,
+}
+
+/// Logs a diagnostic message, the runtime drops the messages over the per-run limit.
+pub fn log(level: LogLevel, message: &str) {
+    unsafe { abi::log(level as u32, message.as_ptr(), message.len() as u32) }
+}
+
+/// Returns the daemon parameter, the execution fails if there is no such parameter.
+pub fn parameter(key: &str) -> String {
+    let value = abi::take_packed(unsafe { abi::parameter(key.as_ptr(), key.len() as u32) });
+
+    String::from_utf8(value).expect("BUG: the parameter is not valid UTF-8.")
+}
@@ -0,0 +1,234 @@
+use std::fmt;
+
+use serde::de::{Deserializer, Error, SeqAccess, Visitor};
+use serde::Deserialize;
+
+/// The result format of the `query_v2` import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub(crate) enum QueryFormat {
+    MessagePack = 0,
+    ArrowIpc = 1,
+}
+
+/// The result of [`crate::query_rows`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct QueryRows {
+    /// Empty if the query returns no rows.
+    pub columns: Vec<QueryColumn>,
+    /// The values are in the order of [`QueryRows::columns`].
+    pub rows: Vec<Vec<QueryValue>>,
+}
+
+impl QueryRows {
+    /// The value of `column` in the row `row`, `None` if there is no such row or column.
+    pub fn get(&self, row: usize, column: &str) -> Option<&QueryValue> {
+        let idx = self.columns.iter().position(|c| c.name == column)?;
+
+        self.rows.get(row)?.get(idx)
+    }
+
+    /// Restores the signed values MessagePack passes as unsigned, see [`QueryColumn::kind`].
+    pub(crate) fn normalize(mut self) -> Self {
+        for (idx, column) in self.columns.iter().enumerate() {
+            if column.kind != "int" {
+                continue;
+            }
+
+            for row in &mut self.rows {
+                if let Some(value) = row.get_mut(idx) {
+                    if let QueryValue::UInt(uint) = *value {
+                        *value = QueryValue::Int(uint as i64);
+                    }
+                }
+            }
+        }
+
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct QueryColumn {
+    pub name: String,
+    /// One of `bool`, `int`, `uint`, `float`, `string` or `binary`.
+    /// The columns of other types are passed as `string`.
+    pub kind: String,
+}
+
+/// A value of [`QueryRows`], the variant is defined by the column kind.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Binary(Vec<u8>),
+}
+
+impl QueryValue {
+    pub fn is_null(&self) -> bool {
+        matches!(self, QueryValue::Null)
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            QueryValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            QueryValue::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            QueryValue::UInt(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            QueryValue::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            QueryValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            QueryValue::Binary(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+// The host passes binary values as arrays of bytes, the variant is picked by the visitor.
+impl<'de> Deserialize<'de> for QueryValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(QueryValueVisitor)
+    }
+}
+
+struct QueryValueVisitor;
+
+impl<'de> Visitor<'de> for QueryValueVisitor {
+    type Value = QueryValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a query value")
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<QueryValue, E> {
+        Ok(QueryValue::Null)
+    }
+
+    fn visit_none<E: Error>(self) -> Result<QueryValue, E> {
+        Ok(QueryValue::Null)
+    }
+
+    fn visit_bool<E: Error>(self, value: bool) -> Result<QueryValue, E> {
+        Ok(QueryValue::Bool(value))
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<QueryValue, E> {
+        Ok(QueryValue::Int(value))
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<QueryValue, E> {
+        Ok(QueryValue::UInt(value))
+    }
+
+    fn visit_f64<E: Error>(self, value: f64) -> Result<QueryValue, E> {
+        Ok(QueryValue::Float(value))
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<QueryValue, E> {
+        Ok(QueryValue::String(value.to_string()))
+    }
+
+    fn visit_string<E: Error>(self, value: String) -> Result<QueryValue, E> {
+        Ok(QueryValue::String(value))
+    }
+
+    fn visit_bytes<E: Error>(self, value: &[u8]) -> Result<QueryValue, E> {
+        Ok(QueryValue::Binary(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E: Error>(self, value: Vec<u8>) -> Result<QueryValue, E> {
+        Ok(QueryValue::Binary(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<QueryValue, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+
+        Ok(QueryValue::Binary(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use super::*;
+
+    /// The `query_v2` result as serialized by the host.
+    #[derive(Serialize)]
+    struct HostRows {
+        columns: Vec<(&'static str, &'static str)>,
+        rows: Vec<(i64, u64, Option<String>, Vec<u8>)>,
+    }
+
+    #[test]
+    fn decodes_typed_rows() {
+        let data = rmp_serde::to_vec(&HostRows {
+            columns: vec![
+                ("seq", "int"),
+                ("gas_used", "uint"),
+                ("memo", "string"),
+                ("input", "binary"),
+            ],
+            rows: vec![
+                (1, 21000, Some("transfer".to_string()), vec![1, 2, 3]),
+                (-1, 0, None, vec![]),
+            ],
+        })
+        .unwrap();
+
+        let rows = rmp_serde::from_slice::<QueryRows>(&data)
+            .unwrap()
+            .normalize();
+
+        assert_eq!(rows.get(0, "seq"), Some(&QueryValue::Int(1)));
+        assert_eq!(rows.get(0, "gas_used"), Some(&QueryValue::UInt(21000)));
+        assert_eq!(
+            rows.get(0, "memo").and_then(QueryValue::as_str),
+            Some("transfer")
+        );
+        assert_eq!(
+            rows.get(0, "input").and_then(QueryValue::as_bytes),
+            Some(&[1, 2, 3][..])
+        );
+        assert_eq!(rows.get(1, "seq"), Some(&QueryValue::Int(-1)));
+        assert!(rows.get(1, "memo").unwrap().is_null());
+        assert_eq!(rows.get(2, "seq"), None);
+        assert_eq!(rows.get(0, "unknown"), None);
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// A chain-specific value, e.g. a Move call argument, read by the by-id methods of the chain structs.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum ChainValue {
+    #[serde(rename = "b")]
+    Bool(bool),
+    #[serde(rename = "u64")]
+    U64(u64),
+    #[serde(rename = "s")]
+    String(String),
+    #[serde(rename = "l")]
+    List(Vec<ChainValue>),
+    #[serde(rename = "st")]
+    Struct(ChainStruct),
+}
+
+impl ChainValue {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            ChainValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            ChainValue::U64(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ChainValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[ChainValue]> {
+        match self {
+            ChainValue::List(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_struct(&self) -> Option<&ChainStruct> {
+        match self {
+            ChainValue::Struct(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// A struct value, e.g. a Move resource.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ChainStruct {
+    /// The struct type, e.g. `0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>`.
+    pub ty: String,
+    pub fields: HashMap<String, ChainValue>,
+}
+
+impl ChainStruct {
+    pub fn field(&self, key: &str) -> Option<&ChainValue> {
+        self.fields.get(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use super::*;
+
+    /// The host `Value` serialized into the by-id import result.
+    #[derive(Serialize)]
+    enum HostValue {
+        #[serde(rename = "u64")]
+        U64(u64),
+        #[serde(rename = "st")]
+        Struct {
+            ty: &'static str,
+            fields: HashMap<&'static str, HostValue>,
+        },
+    }
+
+    #[test]
+    fn decodes_struct_value() {
+        let data = rmp_serde::to_vec(&HostValue::Struct {
+            ty: "0x1::coin::CoinStore",
+            fields: HashMap::from([("value", HostValue::U64(1000))]),
+        })
+        .unwrap();
+
+        let value: ChainValue = rmp_serde::from_slice(&data).unwrap();
+        let value = value.as_struct().unwrap();
+
+        assert_eq!(value.ty, "0x1::coin::CoinStore");
+        assert_eq!(
+            value.field("value").and_then(ChainValue::as_u64),
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn decodes_missing_value_as_none() {
+        let data = rmp_serde::to_vec(&Option::<HostValue>::None).unwrap();
+
+        assert_eq!(
+            rmp_serde::from_slice::<Option<ChainValue>>(&data).unwrap(),
+            None
+        );
+    }
+}